@@ -0,0 +1,207 @@
+use crate::utils::{
+    get_trait_def_id, implements_trait, is_direct_expn_of, match_def_path, match_function_call, match_type, paths,
+    snippet_with_applicability, span_lint, span_lint_and_sugg, trait_ref_of_method, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Expr, ExprKind, ImplItem, ImplItemKind, MatchSource, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::TypeckResults;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `to_string()` and `format!` calls in `Display` and `Debug`
+    /// implementations whose result is only written to the formatter, like
+    /// `write!(f, "{}", self.name.to_string())` or `f.write_str(&format!(..))`.
+    ///
+    /// **Why is this bad?** The temporary `String` is allocated every time the value is
+    /// formatted, although the values could be written to the formatter directly.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// struct Person {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// impl fmt::Display for Person {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.write_str(&format!("{} ({})", self.name, self.age.to_string()))
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// struct Person {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// impl fmt::Display for Person {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "{} ({})", self.name, self.age)
+    ///     }
+    /// }
+    /// ```
+    pub ALLOCATION_IN_FORMAT_IMPL,
+    perf,
+    "allocating a `String` only to write it to the formatter in a `fmt` implementation"
+}
+
+declare_lint_pass!(AllocationInFormatImpl => [ALLOCATION_IN_FORMAT_IMPL]);
+
+impl<'tcx> LateLintPass<'tcx> for AllocationInFormatImpl {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx ImplItem<'_>) {
+        if_chain! {
+            if !impl_item.span.from_expansion();
+            if impl_item.ident.name == sym!(fmt);
+            if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
+            if let Some(trait_ref) = trait_ref_of_method(cx, impl_item.hir_id);
+            if let Some(trait_id) = trait_ref.trait_def_id();
+            if match_def_path(cx, trait_id, &paths::DISPLAY_TRAIT) || match_def_path(cx, trait_id, &paths::DEBUG_TRAIT);
+            then {
+                let mut visitor = AllocationVisitor {
+                    cx,
+                    typeck_results: cx.tcx.typeck_body(body_id),
+                };
+                visitor.visit_body(cx.tcx.hir().body(body_id));
+            }
+        }
+    }
+}
+
+struct AllocationVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck_results: &'tcx TypeckResults<'tcx>,
+}
+
+impl<'a, 'tcx> AllocationVisitor<'a, 'tcx> {
+    /// Checks `f.write_str(&format!(..))`, which can be written as `write!(f, ..)`.
+    fn check_write_str(&self, expr: &Expr<'_>, receiver: &Expr<'_>, arg: &Expr<'_>) {
+        if_chain! {
+            if match_type(self.cx, walk_ptrs_ty(self.typeck_results.expr_ty(receiver)), &paths::FORMATTER);
+            if let ExprKind::AddrOf(_, _, inner) = arg.kind;
+            if let Some(format_span) = is_direct_expn_of(inner.span, "format");
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let receiver = snippet_with_applicability(self.cx, receiver.span, "..", &mut applicability);
+                let format = snippet_with_applicability(self.cx, format_span, "..", &mut applicability);
+                let args = match format.find('(') {
+                    Some(start) if format.ends_with(')') => &format[start + 1..format.len() - 1],
+                    _ => return,
+                };
+                span_lint_and_sugg(
+                    self.cx,
+                    ALLOCATION_IN_FORMAT_IMPL,
+                    expr.span,
+                    "`format!` used to write to the formatter",
+                    "use `write!` instead",
+                    format!("write!({}, {})", receiver, args),
+                    applicability,
+                );
+            }
+        }
+    }
+
+    /// Checks the arguments of the `match` of a `format_args!` expansion without formatting
+    /// options, e.g. the `{}` in `write!(f, "{}", self.name.to_string())`.
+    fn check_format_args(&self, expr: &Expr<'_>) {
+        if_chain! {
+            if let ExprKind::Match(scrutinee, arms, MatchSource::Normal) = expr.kind;
+            if let ExprKind::Tup(values) = scrutinee.kind;
+            if let [arm] = arms;
+            if let PatKind::Tuple(pats, None) = arm.pat.kind;
+            if let ExprKind::Array(args) = arm.body.kind;
+            then {
+                for arg in args {
+                    if_chain! {
+                        // `ArgumentV1::new(argN, Display::fmt)`
+                        if let Some([value, fmt]) = match_function_call(self.cx, arg, &paths::FMT_ARGUMENTV1_NEW);
+                        if let ExprKind::Path(QPath::Resolved(None, value_path)) = value.kind;
+                        if let Res::Local(binding_id) = value_path.res;
+                        if let Some(index) = pats.iter().position(|pat| pat.hir_id == binding_id);
+                        if let Some(value) = values.get(index);
+                        if let ExprKind::AddrOf(_, _, value) = value.kind;
+                        then {
+                            self.check_format_arg(value, fmt);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_format_arg(&self, value: &Expr<'_>, fmt: &Expr<'_>) {
+        if let Some(format_span) = is_direct_expn_of(value.span, "format") {
+            span_lint(
+                self.cx,
+                ALLOCATION_IN_FORMAT_IMPL,
+                format_span,
+                "`format!` used as an argument when writing to the formatter, \
+                 consider writing its arguments directly",
+            );
+            return;
+        }
+        if_chain! {
+            if !value.span.from_expansion();
+            if let ExprKind::MethodCall(_, _, [receiver], _) = value.kind;
+            if let Some(method_id) = self.typeck_results.type_dependent_def_id(value.hir_id);
+            if match_def_path(self.cx, method_id, &paths::TO_STRING_METHOD);
+            if let ExprKind::Path(ref fmt_path) = fmt.kind;
+            if let Some(fmt_id) = self.cx.qpath_res(fmt_path, fmt.hir_id).opt_def_id();
+            if match_def_path(self.cx, fmt_id, &paths::DISPLAY_FMT_METHOD);
+            if let Some(display_trait_id) = get_trait_def_id(self.cx, &paths::DISPLAY_TRAIT);
+            // `to_string` might be implemented without `Display`
+            if implements_trait(
+                self.cx,
+                walk_ptrs_ty(self.typeck_results.expr_ty(receiver)),
+                display_trait_id,
+                &[],
+            );
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                span_lint_and_sugg(
+                    self.cx,
+                    ALLOCATION_IN_FORMAT_IMPL,
+                    value.span,
+                    "`to_string()` used as an argument when writing to the formatter",
+                    "format the value directly",
+                    snippet_with_applicability(self.cx, receiver.span, "..", &mut applicability).to_string(),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for AllocationVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(path, _, [receiver, arg], _) = expr.kind {
+            if path.ident.name == sym!(write_str) {
+                self.check_write_str(expr, receiver, arg);
+            }
+        } else if let Some([_, args]) = match_function_call(self.cx, expr, &paths::FMT_ARGUMENTS_NEW_V1) {
+            // `Arguments::new_v1_formatted` is used if there are formatting options like the width,
+            // which might be handled differently by the `Display` implementation of the value
+            if let ExprKind::AddrOf(_, _, args) = args.kind {
+                self.check_format_args(args);
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
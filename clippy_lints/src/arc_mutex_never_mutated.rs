@@ -0,0 +1,386 @@
+use crate::utils::{is_type_diagnostic_item, match_def_path, match_type, paths, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{
+    BindingAnnotation, BorrowKind, Expr, ExprKind, HirId, Item, ItemKind, Local, Mutability, Node, PatKind, QPath, UnOp,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Arc<Mutex<T>>` values in private struct fields and local
+    /// variables which are only ever locked to read the contents, never to mutate them.
+    ///
+    /// **Why is this bad?** If the contents are never mutated, the `Mutex` only adds the cost of
+    /// locking and the risk of contention and poisoning. An `Arc<T>` can be shared between
+    /// threads just as well, as long as `T` is `Sync`.
+    ///
+    /// **Known problems:** All uses of the value, including the clones of the `Arc`, have to be
+    /// understood for this lint to fire, so values passed to other functions are never linted.
+    /// Interior mutability of the contents, e.g. through atomics, is not taken into account.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::sync::{Arc, Mutex};
+    /// struct Config {
+    ///     name: Arc<Mutex<String>>,
+    /// }
+    ///
+    /// impl Config {
+    ///     fn name_len(&self) -> usize {
+    ///         self.name.lock().unwrap().len()
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// struct Config {
+    ///     name: Arc<String>,
+    /// }
+    ///
+    /// impl Config {
+    ///     fn name_len(&self) -> usize {
+    ///         self.name.len()
+    ///     }
+    /// }
+    /// ```
+    pub ARC_MUTEX_NEVER_MUTATED,
+    nursery,
+    "`Arc<Mutex<T>>` whose contents are never mutated"
+}
+
+/// The uses of an `Arc<Mutex<_>>` field, collected over the whole crate.
+struct FieldUsage {
+    locked: bool,
+    read_only: bool,
+}
+
+#[derive(Default)]
+pub struct ArcMutexNeverMutated {
+    /// The private `Arc<Mutex<_>>` fields, with the spans of their types.
+    fields: FxHashMap<DefId, Span>,
+    /// The uses of all the `Arc<Mutex<_>>` fields. They are recorded independently of the
+    /// struct definitions, which might be visited after the uses.
+    usages: FxHashMap<DefId, FieldUsage>,
+}
+
+impl_lint_pass!(ArcMutexNeverMutated => [ARC_MUTEX_NEVER_MUTATED]);
+
+impl ArcMutexNeverMutated {
+    fn record_usage(&mut self, field: DefId, locked: bool, read_only: bool) {
+        let usage = self.usages.entry(field).or_insert(FieldUsage {
+            locked: false,
+            read_only: true,
+        });
+        usage.locked |= locked;
+        usage.read_only &= read_only;
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ArcMutexNeverMutated {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Struct(ref data, _) = item.kind {
+            for field in data.fields() {
+                let def_id = cx.tcx.hir().local_def_id(field.hir_id).to_def_id();
+                if !field.vis.node.is_pub() && is_arc_mutex(cx, cx.tcx.type_of(def_id)) {
+                    self.fields.insert(def_id, field.ty.span);
+                }
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Field(base, _) => {
+                if_chain! {
+                    if is_arc_mutex(cx, cx.typeck_results().expr_ty(expr));
+                    if let ty::Adt(adt, _) = cx.typeck_results().expr_ty_adjusted(base).peel_refs().kind;
+                    if adt.is_struct();
+                    let field_index = cx.typeck_results().field_index(expr.hir_id);
+                    if let Some(field) = adt.non_enum_variant().fields.get(field_index);
+                    then {
+                        let mut checker = UsageChecker { cx, locked: false };
+                        let read_only = checker.is_read_only_arc(expr);
+                        self.record_usage(field.did, checker.locked, read_only);
+                    }
+                }
+            },
+            // The `Arc` stored in the field might be shared with other values, unless it is
+            // created right here
+            ExprKind::Struct(_, fields, _) => {
+                if let ty::Adt(adt, _) = cx.typeck_results().expr_ty(expr).kind {
+                    if adt.is_struct() {
+                        for field in fields {
+                            if_chain! {
+                                if is_arc_mutex(cx, cx.typeck_results().expr_ty(field.expr));
+                                let field_index = cx.typeck_results().field_index(field.hir_id);
+                                if let Some(field_def) = adt.non_enum_variant().fields.get(field_index);
+                                then {
+                                    self.record_usage(field_def.did, false, is_new_arc(cx, field.expr));
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if_chain! {
+            if let Some(init) = local.init;
+            if !init.span.from_expansion();
+            if let ExprKind::Call(func, _) = init.kind;
+            if let Some(def_id) = path_def_id(cx, func);
+            // clones are checked together with the value they are cloned from
+            if !is_clone_fn(cx, def_id);
+            if is_arc_mutex(cx, cx.typeck_results().expr_ty(init));
+            then {
+                let mut checker = UsageChecker { cx, locked: false };
+                if checker.is_read_only_arc(init) && checker.locked {
+                    lint(cx, init.span);
+                }
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx rustc_hir::Crate<'_>) {
+        let usages = &self.usages;
+        let mut spans = self
+            .fields
+            .iter()
+            .filter(|(def_id, _)| usages.get(def_id).map_or(false, |usage| usage.read_only && usage.locked))
+            .map(|(_, span)| *span)
+            .collect::<Vec<_>>();
+        spans.sort();
+        for span in spans {
+            lint(cx, span);
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, span: Span) {
+    span_lint_and_help(
+        cx,
+        ARC_MUTEX_NEVER_MUTATED,
+        span,
+        "this `Arc<Mutex<_>>` is only ever locked to read its contents",
+        None,
+        "consider using an `Arc` without the `Mutex`",
+    );
+}
+
+fn is_arc_mutex<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    if_chain! {
+        if match_type(cx, ty, &paths::ARC);
+        if let ty::Adt(_, substs) = ty.kind;
+        then {
+            is_type_diagnostic_item(cx, substs.type_at(0), sym!(mutex_type))
+        } else {
+            false
+        }
+    }
+}
+
+/// Checks whether the expression creates a new `Arc`, which isn't shared with anything else yet.
+fn is_new_arc(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Call(func, _) = expr.kind {
+        path_def_id(cx, func).map_or(false, |def_id| match_def_path(cx, def_id, &paths::ARC_NEW))
+    } else {
+        false
+    }
+}
+
+fn path_def_id(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<DefId> {
+    match expr.kind {
+        ExprKind::Path(ref qpath) => cx.qpath_res(qpath, expr.hir_id).opt_def_id(),
+        _ => None,
+    }
+}
+
+/// Checks whether the function is `Clone::clone` or an implementation of it.
+fn is_clone_fn(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    match_def_path(cx, def_id, &paths::CLONE_TRAIT_METHOD)
+        || cx
+            .tcx
+            .impl_of_method(def_id)
+            .and_then(|impl_id| cx.tcx.trait_id_of_impl(impl_id))
+            .map_or(false, |trait_id| Some(trait_id) == cx.tcx.lang_items().clone_trait())
+}
+
+/// Follows the uses of an `Arc<Mutex<_>>` through clones, lock guards and `let` bindings.
+struct UsageChecker<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    locked: bool,
+}
+
+impl<'a, 'tcx> UsageChecker<'a, 'tcx> {
+    /// Checks whether the `Arc<Mutex<_>>` is only used to read the contents.
+    fn is_read_only_arc(&mut self, expr: &'tcx Expr<'tcx>) -> bool {
+        if let Some(binding) = self.let_binding(expr) {
+            return self.local_uses(binding).into_iter().all(|e| self.is_read_only_arc(e));
+        }
+        let parent = match self.parent_expr(expr) {
+            Some(parent) => parent,
+            None => return false,
+        };
+        match parent.kind {
+            ExprKind::MethodCall(_, _, args, _) if args[0].hir_id == expr.hir_id => {
+                match self.cx.typeck_results().type_dependent_def_id(parent.hir_id) {
+                    Some(def_id) if match_def_path(self.cx, def_id, &paths::MUTEX_LOCK) => {
+                        self.locked = true;
+                        self.is_read_only_lock_result(parent)
+                    },
+                    Some(def_id) if is_clone_fn(self.cx, def_id) => self.is_read_only_arc(parent),
+                    _ => false,
+                }
+            },
+            // `Arc::clone(&arc)`
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) => {
+                if_chain! {
+                    if let Some(call) = self.parent_expr(parent);
+                    if let ExprKind::Call(func, [_]) = call.kind;
+                    if let Some(def_id) = path_def_id(self.cx, func);
+                    if is_clone_fn(self.cx, def_id);
+                    then {
+                        self.is_read_only_arc(call)
+                    } else {
+                        false
+                    }
+                }
+            },
+            _ => false,
+        }
+    }
+
+    /// Checks whether the result of `Mutex::lock` is only unwrapped to read the contents.
+    fn is_read_only_lock_result(&mut self, expr: &'tcx Expr<'tcx>) -> bool {
+        if_chain! {
+            if let Some(parent) = self.parent_expr(expr);
+            if let ExprKind::MethodCall(path, _, args, _) = parent.kind;
+            if args[0].hir_id == expr.hir_id;
+            if path.ident.name == sym!(unwrap) || path.ident.name == sym!(expect);
+            then {
+                self.is_read_only_guard(parent)
+            } else {
+                false
+            }
+        }
+    }
+
+    /// Checks whether the `MutexGuard` is only used to read the contents.
+    fn is_read_only_guard(&mut self, expr: &'tcx Expr<'tcx>) -> bool {
+        if self.is_mutable_deref(expr) {
+            return false;
+        }
+        if let Some(binding) = self.let_binding(expr) {
+            return self.local_uses(binding).into_iter().all(|e| self.is_read_only_guard(e));
+        }
+        match self.cx.tcx.hir().find(self.cx.tcx.hir().get_parent_node(expr.hir_id)) {
+            Some(Node::Expr(parent)) => match parent.kind {
+                ExprKind::MethodCall(_, _, args, _) => args[0].hir_id == expr.hir_id,
+                ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) => {
+                    true
+                },
+                ExprKind::Unary(UnOp::UnDeref, _) => self
+                    .cx
+                    .typeck_results()
+                    .type_dependent_def_id(parent.hir_id)
+                    .map_or(true, |def_id| {
+                        !match_def_path(self.cx, def_id, &paths::DEREF_MUT_TRAIT_METHOD)
+                    }),
+                ExprKind::Call(func, _) => {
+                    path_def_id(self.cx, func).map_or(false, |def_id| match_def_path(self.cx, def_id, &paths::DROP))
+                },
+                _ => false,
+            },
+            // the guard is dropped right away
+            Some(Node::Stmt(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Checks whether the expression is dereferenced mutably by an adjustment, e.g. to call a
+    /// method taking `&mut self` on the contents of a guard.
+    fn is_mutable_deref(&self, expr: &Expr<'_>) -> bool {
+        self.cx
+            .typeck_results()
+            .expr_adjustments(expr)
+            .iter()
+            .any(|adj| match adj.kind {
+                Adjust::Deref(Some(ref deref)) => deref.mutbl == Mutability::Mut,
+                Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. })) => true,
+                _ => false,
+            })
+    }
+
+    fn parent_expr(&self, expr: &Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+        let map = self.cx.tcx.hir();
+        match map.find(map.get_parent_node(expr.hir_id)) {
+            Some(Node::Expr(parent)) => Some(parent),
+            _ => None,
+        }
+    }
+
+    /// Returns the `HirId` of the binding if the expression is the initializer of a `let`
+    /// statement binding it to a single name.
+    fn let_binding(&self, expr: &Expr<'_>) -> Option<HirId> {
+        if_chain! {
+            if let Some(Node::Local(local)) = self.cx.tcx.hir().find(self.cx.tcx.hir().get_parent_node(expr.hir_id));
+            if local.init.map_or(false, |init| init.hir_id == expr.hir_id);
+            if let PatKind::Binding(BindingAnnotation::Unannotated, binding, _, None)
+                | PatKind::Binding(BindingAnnotation::Mutable, binding, _, None) = local.pat.kind;
+            then {
+                Some(binding)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Collects all the uses of a local binding, including the ones in closures.
+    fn local_uses(&self, binding: HirId) -> Vec<&'tcx Expr<'tcx>> {
+        let map = self.cx.tcx.hir();
+        let body = map.body(map.body_owned_by(map.enclosing_body_owner(binding)));
+        let mut visitor = LocalUsesVisitor {
+            cx: self.cx,
+            binding,
+            uses: Vec::new(),
+        };
+        visitor.visit_expr(&body.value);
+        visitor.uses
+    }
+}
+
+struct LocalUsesVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    binding: HirId,
+    uses: Vec<&'tcx Expr<'tcx>>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LocalUsesVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind {
+            if path.res == Res::Local(self.binding) {
+                self.uses.push(expr);
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -0,0 +1,180 @@
+use crate::utils::{in_async_body, span_lint_and_help};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to blocking I/O functions of the standard library, like
+    /// `std::fs::read_to_string` or `std::net::TcpStream::connect`, inside of `async fn`s and
+    /// async blocks.
+    ///
+    /// **Why is this bad?** Blocking I/O blocks the whole thread, so none of the other tasks run
+    /// by the executor on this thread can make progress until the call returns. Async runtimes
+    /// provide non-blocking equivalents for most of these functions, and a way to run blocking
+    /// code on a dedicated thread pool for the rest.
+    ///
+    /// Additional blocking functions can be added with the `blocking-paths` option, e.g.
+    /// `blocking-paths = ["my_crate::sync_client::get"]`. Note that these have to be the paths
+    /// the functions are defined at, not the paths they are re-exported at.
+    ///
+    /// **Known problems:** Only direct calls are detected, calls to functions which in turn do
+    /// blocking I/O are not.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// async fn foo() -> std::io::Result<String> {
+    ///     std::fs::read_to_string("foo.txt")
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn foo() -> std::io::Result<String> {
+    ///     tokio::fs::read_to_string("foo.txt").await
+    /// }
+    /// ```
+    pub BLOCKING_IO_IN_ASYNC,
+    pedantic,
+    "calling blocking I/O functions inside of an async context"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to functions which run a future to completion on the
+    /// current thread, like `futures::executor::block_on` or `tokio::runtime::Runtime::block_on`,
+    /// inside of `async fn`s and async blocks.
+    ///
+    /// **Why is this bad?** The blocked thread is usually a worker thread of the executor, which
+    /// can't make progress on the outer task until the inner future completes. If the inner
+    /// future depends on the executor, this deadlocks. Some runtimes panic instead.
+    ///
+    /// Additional functions can be added with the `block-on-paths` option, e.g.
+    /// `block-on-paths = ["my_crate::runtime::block_on"]`. Note that these have to be the paths
+    /// the functions are defined at, not the paths they are re-exported at.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+    /// async fn foo() -> i32 {
+    ///     futures::executor::block_on(bar())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn foo() -> i32 {
+    ///     bar().await
+    /// }
+    /// ```
+    pub BLOCK_ON_IN_ASYNC,
+    correctness,
+    "calling `block_on` inside of an async context"
+}
+
+/// The blocking I/O functions that are always linted, in addition to the configured ones.
+const DEFAULT_BLOCKING_PATHS: [&[&str]; 17] = [
+    &["std", "fs", "File", "create"],
+    &["std", "fs", "File", "open"],
+    &["std", "fs", "copy"],
+    &["std", "fs", "create_dir"],
+    &["std", "fs", "create_dir_all"],
+    &["std", "fs", "metadata"],
+    &["std", "fs", "read"],
+    &["std", "fs", "read_dir"],
+    &["std", "fs", "read_to_string"],
+    &["std", "fs", "remove_dir"],
+    &["std", "fs", "remove_dir_all"],
+    &["std", "fs", "remove_file"],
+    &["std", "fs", "rename"],
+    &["std", "fs", "write"],
+    &["std", "io", "stdio", "Stdin", "read_line"],
+    &["std", "net", "tcp", "TcpListener", "bind"],
+    &["std", "net", "tcp", "TcpStream", "connect"],
+];
+
+/// The `block_on` functions that are always linted, in addition to the configured ones.
+const DEFAULT_BLOCK_ON_PATHS: [&[&str]; 5] = [
+    &["futures_executor", "local_pool", "block_on"],
+    &["tokio", "runtime", "Runtime", "block_on"],
+    &["tokio", "runtime", "handle", "Handle", "block_on"],
+    &["tokio", "runtime", "runtime", "Runtime", "block_on"],
+    &["async_std", "task", "block_on", "block_on"],
+];
+
+#[derive(Clone, Debug)]
+pub struct BlockingIoInAsync {
+    blocking_paths: Vec<Vec<String>>,
+    block_on_paths: Vec<Vec<String>>,
+}
+
+impl BlockingIoInAsync {
+    pub fn new(blocking_paths: &[String], block_on_paths: &[String]) -> Self {
+        Self {
+            blocking_paths: collect_paths(&DEFAULT_BLOCKING_PATHS, blocking_paths),
+            block_on_paths: collect_paths(&DEFAULT_BLOCK_ON_PATHS, block_on_paths),
+        }
+    }
+}
+
+/// Combines the default paths with the configured ones, which are given as `::` separated
+/// strings.
+fn collect_paths(defaults: &[&[&str]], configured: &[String]) -> Vec<Vec<String>> {
+    let defaults = defaults
+        .iter()
+        .map(|path| path.iter().map(ToString::to_string).collect());
+    let configured = configured
+        .iter()
+        .map(|path| path.split("::").map(ToString::to_string).collect());
+    defaults.chain(configured).collect()
+}
+
+fn matches_any_path(cx: &LateContext<'_>, def_id: DefId, paths: &[Vec<String>]) -> bool {
+    let def_path = cx.get_def_path(def_id);
+    paths
+        .iter()
+        .any(|path| path.len() == def_path.len() && path.iter().zip(&def_path).all(|(a, b)| b.as_str() == a.as_str()))
+}
+
+impl_lint_pass!(BlockingIoInAsync => [BLOCKING_IO_IN_ASYNC, BLOCK_ON_IN_ASYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for BlockingIoInAsync {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() || !in_async_body(cx) {
+            return;
+        }
+        let def_id = match expr.kind {
+            ExprKind::Call(func, _) => match func.kind {
+                ExprKind::Path(ref qpath) => cx.qpath_res(qpath, func.hir_id).opt_def_id(),
+                _ => None,
+            },
+            ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+            _ => None,
+        };
+        if let Some(def_id) = def_id {
+            if matches_any_path(cx, def_id, &self.block_on_paths) {
+                span_lint_and_help(
+                    cx,
+                    BLOCK_ON_IN_ASYNC,
+                    expr.span,
+                    &format!("call to `{}` in an async context", cx.tcx.def_path_str(def_id)),
+                    None,
+                    "this can deadlock the executor, consider awaiting the future instead",
+                );
+            } else if matches_any_path(cx, def_id, &self.blocking_paths) {
+                span_lint_and_help(
+                    cx,
+                    BLOCKING_IO_IN_ASYNC,
+                    expr.span,
+                    &format!(
+                        "call to blocking function `{}` in an async context",
+                        cx.tcx.def_path_str(def_id)
+                    ),
+                    None,
+                    "this blocks the executor thread, consider using the async equivalent of your runtime or running \
+                     the call on a thread pool meant for blocking work",
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{higher, in_constant, in_macro, is_integer_literal, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `if` expressions converting a `bool` to an integer, like
+    /// `if b { 1 } else { 0 }`.
+    ///
+    /// **Why is this bad?** Every integer type implements `From<bool>`, which performs the
+    /// same conversion in a shorter and more explicit way.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let condition = false;
+    /// let number: i32 = if condition { 1 } else { 0 };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let condition = false;
+    /// let number = i32::from(condition);
+    /// ```
+    pub BOOL_TO_INT_WITH_IF,
+    style,
+    "using `if` to convert a `bool` to an integer"
+}
+
+declare_lint_pass!(BoolToIntWithIf => [BOOL_TO_INT_WITH_IF]);
+
+impl<'tcx> LateLintPass<'tcx> for BoolToIntWithIf {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let Some((cond, then, Some(els))) = higher::if_block(expr);
+            if let Some(then_value) = block_int_value(then);
+            if let Some(els_value) = block_int_value(els);
+            if then_value + els_value == 1;
+            let ty = cx.typeck_results().expr_ty(expr);
+            if matches!(ty.kind, ty::Int(_) | ty::Uint(_));
+            // `From::from` can't be called in constants
+            if !in_constant(cx, expr.hir_id);
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let cond = Sugg::hir_with_applicability(cx, cond, "..", &mut applicability);
+                let cond = if then_value == 1 { cond } else { !cond };
+                let mut sugg = format!("{}::from({})", ty, cond);
+                if is_else_clause(cx, expr) {
+                    sugg = format!("{{ {} }}", sugg);
+                }
+                let cond = cond.maybe_par();
+                span_lint_and_then(
+                    cx,
+                    BOOL_TO_INT_WITH_IF,
+                    expr.span,
+                    "boolean to int conversion using if",
+                    |diag| {
+                        diag.span_suggestion(expr.span, "replace with from", sugg, applicability);
+                        diag.note(&format!(
+                            "`{} as {}` or `{}.into()` can also be valid options",
+                            cond, ty, cond
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the value of a block only consisting of the integer literal `0` or `1`.
+fn block_int_value(expr: &Expr<'_>) -> Option<u128> {
+    if_chain! {
+        if let ExprKind::Block(block, None) = expr.kind;
+        if block.stmts.is_empty();
+        if let Some(value) = block.expr;
+        then {
+            if is_integer_literal(value, 0) {
+                Some(0)
+            } else if is_integer_literal(value, 1) {
+                Some(1)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks whether `expr` is the `else if` of another `if` expression.
+fn is_else_clause(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let map = cx.tcx.hir();
+    // the `else` branch is the body of the second arm of the desugared `if`
+    let arm_id = map.get_parent_node(expr.hir_id);
+    if_chain! {
+        if let Some(Node::Arm(_)) = map.find(arm_id);
+        if let Some(Node::Expr(parent)) = map.find(map.get_parent_node(arm_id));
+        if let Some((_, _, Some(els))) = higher::if_block(parent);
+        then {
+            els.hir_id == expr.hir_id
+        } else {
+            false
+        }
+    }
+}
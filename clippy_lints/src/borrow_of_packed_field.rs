@@ -0,0 +1,102 @@
+use crate::utils::{span_lint_and_help, walk_ptrs_ty};
+use rustc_hir::{BorrowKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_target::abi::LayoutOf;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for references to fields of `#[repr(packed)]` structs, which are
+    /// created explicitly with `&` or implicitly by calling a method on the field, when the
+    /// alignment of the field's type is larger than the packing of the struct.
+    ///
+    /// **Why is this bad?** The fields of packed structs may not be properly aligned, and creating
+    /// a reference to an unaligned value is undefined behavior, even if the reference is never
+    /// used. This is only checked by the compiler outside of `unsafe` blocks.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+    /// #[repr(packed)]
+    /// struct Packed {
+    ///     tag: u8,
+    ///     value: u32,
+    /// }
+    ///
+    /// fn value(packed: &Packed) -> String {
+    ///     unsafe { packed.value.to_string() }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn value(packed: &Packed) -> String {
+    ///     let value = packed.value;
+    ///     value.to_string()
+    /// }
+    /// ```
+    pub BORROW_OF_PACKED_FIELD,
+    correctness,
+    "creating a reference to a possibly unaligned field of a packed struct"
+}
+
+declare_lint_pass!(BorrowOfPackedField => [BORROW_OF_PACKED_FIELD]);
+
+impl<'tcx> LateLintPass<'tcx> for BorrowOfPackedField {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let field = match expr.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, _, inner) => inner,
+            ExprKind::Field(..) if is_auto_borrowed(cx, expr) => expr,
+            _ => return,
+        };
+        if is_unaligned_field(cx, field) {
+            span_lint_and_help(
+                cx,
+                BORROW_OF_PACKED_FIELD,
+                expr.span,
+                "reference to a field of a packed struct, which may be unaligned",
+                None,
+                "consider copying the field into a local variable and using that instead",
+            );
+        }
+    }
+}
+
+fn is_auto_borrowed(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results()
+        .expr_adjustments(expr)
+        .iter()
+        .any(|adjust| matches!(adjust.kind, Adjust::Borrow(AutoBorrow::Ref(..))))
+}
+
+/// Checks whether the expression is a field of a packed struct, possibly nested in other fields,
+/// whose alignment is larger than the packing.
+fn is_unaligned_field<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> bool {
+    let align = match cx.layout_of(cx.typeck_results().expr_ty(expr)) {
+        Ok(layout) => layout.align.abi.bytes(),
+        Err(_) => return false,
+    };
+    let mut projection = expr;
+    while let ExprKind::Field(base, _) = projection.kind {
+        if let Some(pack) = packing(walk_ptrs_ty(cx.typeck_results().expr_ty_adjusted(base))) {
+            if align > pack {
+                return true;
+            }
+        }
+        projection = base;
+    }
+    false
+}
+
+/// Returns the packing of the type in bytes, if it is a packed struct.
+fn packing(ty: Ty<'_>) -> Option<u64> {
+    match ty.kind {
+        ty::Adt(def, _) if def.repr.packed() => def.repr.pack.map(|pack| pack.bytes()),
+        _ => None,
+    }
+}
@@ -0,0 +1,119 @@
+use crate::utils::{higher, is_type_diagnostic_item, span_lint_and_then, walk_ptrs_ty};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for slicing a string with a range whose bounds are computed
+    /// from `chars().count()` or `chars().position(..)`.
+    ///
+    /// **Why is this bad?** These methods count characters, but strings are sliced by byte
+    /// indices. As soon as the string contains a character which takes more than one byte in
+    /// UTF-8, the index points to the wrong position or into the middle of a character, which
+    /// panics.
+    ///
+    /// **Known problems:** Strings which only ever contain ASCII characters are linted as well.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s = "héllo";
+    /// let without_last = &s[..s.chars().count() - 1];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = "héllo";
+    /// let without_last = &s[..s.char_indices().last().map_or(0, |(i, _)| i)];
+    /// ```
+    pub CHAR_COUNT_AS_BYTE_INDEX,
+    pedantic,
+    "slicing a string with a character count, which is not a byte index"
+}
+
+declare_lint_pass!(CharCountAsByteIndex => [CHAR_COUNT_AS_BYTE_INDEX]);
+
+impl<'tcx> LateLintPass<'tcx> for CharCountAsByteIndex {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        if let ExprKind::Index(base, index) = expr.kind {
+            if !is_str(cx, base) {
+                return;
+            }
+            if let Some(range) = higher::range(cx, index) {
+                let char_count = range
+                    .start
+                    .into_iter()
+                    .chain(range.end)
+                    .find_map(|bound| find_char_count(cx, bound));
+                if let Some((span, method)) = char_count {
+                    span_lint_and_then(
+                        cx,
+                        CHAR_COUNT_AS_BYTE_INDEX,
+                        expr.span,
+                        "slicing a string with a character count as byte index",
+                        |diag| {
+                            diag.help(
+                                "this panics for multi-byte characters, consider using `char_indices()` to get the \
+                                 byte index of a character instead",
+                            );
+                            diag.span_note(span, &format!("`chars().{}()` counts characters, not bytes", method));
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn is_str(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let ty = walk_ptrs_ty(cx.typeck_results().expr_ty(expr));
+    ty.is_str() || is_type_diagnostic_item(cx, ty, sym!(string_type))
+}
+
+/// Finds a `chars().count()` or `chars().position(..)` call on a string in the expression.
+fn find_char_count<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<(Span, &'static str)> {
+    let mut visitor = CharCountVisitor { cx, found: None };
+    visitor.visit_expr(expr);
+    visitor.found
+}
+
+struct CharCountVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    found: Option<(Span, &'static str)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for CharCountVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.found.is_some() {
+            return;
+        }
+        if let ExprKind::MethodCall(path, _, args, _) = expr.kind {
+            let method = if path.ident.name == sym!(count) {
+                Some("count")
+            } else if path.ident.name == sym!(position) {
+                Some("position")
+            } else {
+                None
+            };
+            if let Some(method) = method {
+                if let ExprKind::MethodCall(chars_path, _, chars_args, _) = args[0].kind {
+                    if chars_path.ident.name == sym!(chars) && is_str(self.cx, &chars_args[0]) {
+                        self.found = Some((expr.span, method));
+                        return;
+                    }
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,94 @@
+use crate::utils::{match_def_path, paths, span_lint_and_help};
+use if_chain::if_chain;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `Condvar::wait` and `Condvar::wait_timeout` which
+    /// are not inside of a loop.
+    ///
+    /// **Why is this bad?** Condition variables are subject to spurious wakeups, so returning
+    /// from `wait` doesn't mean that the condition the thread is waiting for is actually met.
+    /// The condition has to be checked again after every wakeup, which is what `wait_while` and
+    /// `wait_timeout_while` do.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::sync::{Condvar, Mutex};
+    /// # let lock = Mutex::new(true);
+    /// # let cvar = Condvar::new();
+    /// let mut started = lock.lock().unwrap();
+    /// if !*started {
+    ///     started = cvar.wait(started).unwrap();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::{Condvar, Mutex};
+    /// # let lock = Mutex::new(true);
+    /// # let cvar = Condvar::new();
+    /// let started = cvar.wait_while(lock.lock().unwrap(), |started| !*started).unwrap();
+    /// ```
+    pub CONDVAR_WAIT_OUTSIDE_LOOP,
+    correctness,
+    "calling `Condvar::wait` without re-checking the condition in a loop"
+}
+
+declare_lint_pass!(CondvarWaitOutsideLoop => [CONDVAR_WAIT_OUTSIDE_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for CondvarWaitOutsideLoop {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::MethodCall(..) = expr.kind;
+            if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+            if let Some((method, alternative)) = wait_method(cx, def_id);
+            if !in_loop(cx, expr);
+            then {
+                span_lint_and_help(
+                    cx,
+                    CONDVAR_WAIT_OUTSIDE_LOOP,
+                    expr.span,
+                    &format!("`Condvar::{}` called outside of a loop", method),
+                    None,
+                    &format!(
+                        "spurious wakeups are possible, re-check the condition in a `while` loop or use `Condvar::{}`",
+                        alternative
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the name of the `Condvar` wait method and of its variant re-checking the condition.
+fn wait_method(cx: &LateContext<'_>, def_id: DefId) -> Option<(&'static str, &'static str)> {
+    if match_def_path(cx, def_id, &paths::CONDVAR_WAIT) {
+        Some(("wait", "wait_while"))
+    } else if match_def_path(cx, def_id, &paths::CONDVAR_WAIT_TIMEOUT) {
+        Some(("wait_timeout", "wait_timeout_while"))
+    } else {
+        None
+    }
+}
+
+/// Checks whether the expression is inside of a loop of the enclosing function or closure.
+fn in_loop(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(e) => match e.kind {
+                ExprKind::Loop(..) => return true,
+                ExprKind::Closure(..) => return false,
+                _ => {},
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => return false,
+            _ => {},
+        }
+    }
+    false
+}
@@ -0,0 +1,189 @@
+use crate::utils::{
+    get_trait_def_id, implements_trait, in_macro, indent_of, is_automatically_derived, is_integer_literal,
+    last_path_segment, match_def_path, match_qpath, paths, qpath_res, span_lint_and_then,
+};
+use if_chain::if_chain;
+use rustc_ast::ast::{Attribute, LitKind};
+use rustc_errors::Applicability;
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
+use rustc_hir::{Block, Expr, ExprKind, GenericParamKind, ImplItemKind, Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TypeckResults;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Detects manual `std::default::Default` implementations that are
+    /// identical to a derived implementation.
+    ///
+    /// **Why is this bad?** It is less concise.
+    ///
+    /// **Known problems:** The lint only recognizes the default values of the most common types,
+    /// like `0`, `false`, `None`, `Default::default()` and `new()` of the standard library types.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Foo {
+    ///     bar: bool,
+    ///     baz: Vec<u8>,
+    /// }
+    ///
+    /// impl std::default::Default for Foo {
+    ///     fn default() -> Self {
+    ///         Self {
+    ///             bar: false,
+    ///             baz: Vec::new(),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Could be written as:
+    ///
+    /// ```rust
+    /// #[derive(Default)]
+    /// struct Foo {
+    ///     bar: bool,
+    ///     baz: Vec<u8>,
+    /// }
+    /// ```
+    pub DERIVABLE_IMPLS,
+    complexity,
+    "manual implementation of the `Default` trait which is equal to a derive"
+}
+
+declare_lint_pass!(DerivableImpls => [DERIVABLE_IMPLS]);
+
+impl<'tcx> LateLintPass<'tcx> for DerivableImpls {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if_chain! {
+            if let ItemKind::Impl {
+                of_trait: Some(ref trait_ref),
+                items: [child],
+                ref generics,
+                ..
+            } = item.kind;
+            if !in_macro(item.span);
+            if !is_automatically_derived(item.attrs);
+            if let Some(def_id) = trait_ref.trait_def_id();
+            if match_def_path(cx, def_id, &paths::DEFAULT_TRAIT);
+            // the derived impl would require all the type parameters to implement `Default`
+            if generics
+                .params
+                .iter()
+                .all(|param| matches!(param.kind, GenericParamKind::Lifetime { .. }));
+            let impl_item = cx.tcx.hir().impl_item(child.id);
+            if !has_doc(item.attrs) && !has_doc(impl_item.attrs);
+            if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
+            if let ExprKind::Block(block, _) = cx.tcx.hir().body(body_id).value.kind;
+            let self_ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(item.hir_id));
+            if let Some(adt_def) = self_ty.ty_adt_def();
+            if adt_def.is_struct();
+            if is_default_constructor(cx, cx.tcx.typeck_body(body_id), block);
+            if let Some(struct_span) = cx.tcx.hir().span_if_local(adt_def.did);
+            if !in_macro(struct_span);
+            then {
+                span_lint_and_then(
+                    cx,
+                    DERIVABLE_IMPLS,
+                    item.span,
+                    "this `impl` can be derived",
+                    |diag| {
+                        diag.multipart_suggestion(
+                            "try annotating the type with `#[derive(Default)]`",
+                            vec![
+                                (
+                                    struct_span.shrink_to_lo(),
+                                    format!(
+                                        "#[derive(Default)]\n{}",
+                                        " ".repeat(indent_of(cx, struct_span).unwrap_or(0))
+                                    ),
+                                ),
+                                (item.span, String::new()),
+                            ],
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn has_doc(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.doc_str().is_some())
+}
+
+/// Checks whether the block only consists of a struct constructor initializing all the fields
+/// with their default values.
+fn is_default_constructor<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck_results: &TypeckResults<'tcx>,
+    block: &Block<'_>,
+) -> bool {
+    if !block.stmts.is_empty() {
+        return false;
+    }
+    match block.expr.map(|expr| &expr.kind) {
+        Some(ExprKind::Struct(_, fields, None)) => fields
+            .iter()
+            .all(|field| is_default_equivalent(cx, typeck_results, field.expr)),
+        Some(ExprKind::Call(func, args)) => {
+            if_chain! {
+                if let ExprKind::Path(ref qpath) = func.kind;
+                if matches!(
+                    qpath_res(cx, qpath, func.hir_id),
+                    Res::Def(DefKind::Ctor(CtorOf::Struct, CtorKind::Fn), _) | Res::SelfCtor(_)
+                );
+                then {
+                    args.iter().all(|arg| is_default_equivalent(cx, typeck_results, arg))
+                } else {
+                    false
+                }
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Checks whether `expr` is the default value of its type, e.g. `0`, `None` or
+/// `Default::default()`.
+fn is_default_equivalent<'tcx>(cx: &LateContext<'tcx>, typeck_results: &TypeckResults<'tcx>, expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Bool(value) => !value,
+            LitKind::Str(value, _) => value.is_empty(),
+            LitKind::Float(value, _) => value.as_str().parse::<f64>().map_or(false, |value| value == 0.0),
+            _ => is_integer_literal(expr, 0),
+        },
+        ExprKind::Path(ref qpath) => match_qpath(qpath, &paths::OPTION_NONE),
+        ExprKind::Tup(exprs) => exprs.iter().all(|expr| is_default_equivalent(cx, typeck_results, expr)),
+        ExprKind::Call(func, []) => is_default_call(cx, typeck_results, expr, func),
+        _ => false,
+    }
+}
+
+/// Checks for calls like `T::default()`, or `Vec::new()` of a standard library type.
+fn is_default_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck_results: &TypeckResults<'tcx>,
+    expr: &Expr<'_>,
+    func: &Expr<'_>,
+) -> bool {
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = func.kind;
+        if let Some(default_trait_id) = get_trait_def_id(cx, &paths::DEFAULT_TRAIT);
+        if implements_trait(cx, typeck_results.expr_ty(expr), default_trait_id, &[]);
+        then {
+            match &*last_path_segment(qpath).ident.as_str() {
+                "default" => true,
+                // `new` of a user defined type could differ from its `Default` implementation
+                "new" => qpath_res(cx, qpath, func.hir_id).opt_def_id().map_or(false, |def_id| {
+                    ["alloc", "core", "std"].contains(&&*cx.tcx.crate_name(def_id.krate).as_str())
+                }),
+                _ => false,
+            }
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::utils::{get_parent_expr, match_def_path, match_type, paths, span_lint_and_note, SpanlessEq};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, Expr, ExprKind, HirId, MatchSource, PatKind, QPath, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty::subst::GenericArgKind;
+use rustc_middle::ty::Ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Mutex::lock`, `RwLock::read` and `RwLock::write` calls on a
+    /// lock which is already held by a guard that is still alive, like a guard bound with `let`
+    /// earlier in the same block, or a temporary guard created in the scrutinee of a `match`,
+    /// `if let`, `while let` or `for` loop.
+    ///
+    /// **Why is this bad?** The second call waits for the first guard to be dropped, which never
+    /// happens, so the thread deadlocks. The only exception is taking two read locks of the same
+    /// `RwLock`, which is not linted.
+    ///
+    /// `if let` expressions with an `else` branch are covered by the `if_let_mutex` lint instead.
+    ///
+    /// **Known problems:** The locks are compared syntactically, so locks reached through
+    /// different paths are not detected. The guard is assumed to be alive until the end of the
+    /// block, unless it is used by value in between, e.g. by passing it to `drop`.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Mutex;
+    /// # let mutex = Mutex::new(vec![1]);
+    /// let guard = mutex.lock().unwrap();
+    /// let first = guard[0];
+    /// mutex.lock().unwrap().push(first);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # let mutex = Mutex::new(vec![1]);
+    /// let mut guard = mutex.lock().unwrap();
+    /// let first = guard[0];
+    /// guard.push(first);
+    /// ```
+    pub DOUBLE_LOCK,
+    correctness,
+    "locking a lock while a guard of the same lock is still alive"
+}
+
+declare_lint_pass!(DoubleLock => [DOUBLE_LOCK]);
+
+const GUARD_PATHS: [&[&str]; 3] = [
+    &paths::MUTEX_GUARD,
+    &paths::RWLOCK_READ_GUARD,
+    &paths::RWLOCK_WRITE_GUARD,
+];
+
+impl<'tcx> LateLintPass<'tcx> for DoubleLock {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if !stmt.span.from_expansion();
+                if let StmtKind::Local(local) = stmt.kind;
+                if let PatKind::Binding(_, guard_id, _, None) = local.pat.kind;
+                if let Some(init) = local.init;
+                if contains_guard(cx, cx.typeck_results().expr_ty(init));
+                if let Some(&first) = LockVisitor::new(cx, None).find_locks(init).first();
+                then {
+                    let rest = block.stmts[i + 1..]
+                        .iter()
+                        .filter_map(|stmt| match stmt.kind {
+                            StmtKind::Local(local) => local.init,
+                            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                            StmtKind::Item(_) => None,
+                        })
+                        .chain(block.expr);
+                    for expr in rest {
+                        let mut visitor = LockVisitor::new(cx, Some(guard_id));
+                        let locks = visitor.find_locks(expr);
+                        if visitor.guard_used_by_value {
+                            break;
+                        }
+                        if let Some(&second) = locks.iter().find(|second| first.conflicts_with(cx, second)) {
+                            lint(cx, first, second);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Match(scrutinee, arms, source) = expr.kind;
+            if matches!(
+                source,
+                MatchSource::Normal
+                    | MatchSource::IfLetDesugar { contains_else_clause: false }
+                    | MatchSource::WhileLetDesugar
+                    | MatchSource::ForLoopDesugar
+            );
+            then {
+                // temporaries created in the scrutinee live until the end of the `match`
+                for first in LockVisitor::new(cx, None).find_locks(scrutinee) {
+                    for arm in arms {
+                        let locks = LockVisitor::new(cx, None).find_locks(arm.body);
+                        if let Some(&second) = locks.iter().find(|second| first.conflicts_with(cx, second)) {
+                            lint(cx, first, second);
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, first: LockCall<'_>, second: LockCall<'_>) {
+    span_lint_and_note(
+        cx,
+        DOUBLE_LOCK,
+        second.span,
+        &format!(
+            "calling `{}` while a guard of the same lock is still alive causes a deadlock",
+            second.method
+        ),
+        Some(first.span),
+        "the lock is first acquired here",
+    );
+}
+
+fn contains_guard<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.walk().any(|inner| match inner.unpack() {
+        GenericArgKind::Type(inner_ty) => GUARD_PATHS.iter().any(|path| match_type(cx, inner_ty, path)),
+        GenericArgKind::Lifetime(_) | GenericArgKind::Const(_) => false,
+    })
+}
+
+#[derive(Clone, Copy)]
+struct LockCall<'tcx> {
+    /// The expression of the lock, i.e. the receiver of the method call.
+    lock: &'tcx Expr<'tcx>,
+    span: Span,
+    method: &'static str,
+    exclusive: bool,
+}
+
+impl<'tcx> LockCall<'tcx> {
+    fn parse(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Self> {
+        if_chain! {
+            if let ExprKind::MethodCall(_, _, args, _) = expr.kind;
+            if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+            then {
+                let (method, exclusive) = if match_def_path(cx, def_id, &paths::MUTEX_LOCK) {
+                    ("Mutex::lock", true)
+                } else if match_def_path(cx, def_id, &paths::RWLOCK_WRITE) {
+                    ("RwLock::write", true)
+                } else if match_def_path(cx, def_id, &paths::RWLOCK_READ) {
+                    ("RwLock::read", false)
+                } else {
+                    return None;
+                };
+                Some(Self {
+                    lock: &args[0],
+                    span: expr.span,
+                    method,
+                    exclusive,
+                })
+            } else {
+                None
+            }
+        }
+    }
+
+    fn conflicts_with(&self, cx: &LateContext<'_>, other: &Self) -> bool {
+        (self.exclusive || other.exclusive) && SpanlessEq::new(cx).eq_expr(self.lock, other.lock)
+    }
+}
+
+/// Collects the lock calls in an expression, not looking into closures. If a guard is given, also
+/// checks whether the guard is used by value, which may move it out of the scope or drop it.
+struct LockVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    locks: Vec<LockCall<'tcx>>,
+    guard_id: Option<HirId>,
+    guard_used_by_value: bool,
+}
+
+impl<'a, 'tcx> LockVisitor<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'tcx>, guard_id: Option<HirId>) -> Self {
+        Self {
+            cx,
+            locks: Vec::new(),
+            guard_id,
+            guard_used_by_value: false,
+        }
+    }
+
+    fn find_locks(&mut self, expr: &'tcx Expr<'tcx>) -> Vec<LockCall<'tcx>> {
+        self.visit_expr(expr);
+        std::mem::take(&mut self.locks)
+    }
+
+    fn is_guard_used_by_value(&self, expr: &Expr<'_>) -> bool {
+        if_chain! {
+            if let Some(guard_id) = self.guard_id;
+            if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind;
+            if path.res == Res::Local(guard_id);
+            then {
+                match get_parent_expr(self.cx, expr).map(|parent| &parent.kind) {
+                    Some(ExprKind::MethodCall(_, _, args, _)) => args[0].hir_id != expr.hir_id,
+                    Some(ExprKind::Field(..)) | Some(ExprKind::Index(..)) | Some(ExprKind::AddrOf(..)) => false,
+                    Some(ExprKind::Unary(op, _)) => *op != UnOp::UnDeref,
+                    _ => true,
+                }
+            } else {
+                false
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LockVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let Some(lock) = LockCall::parse(self.cx, expr) {
+            self.locks.push(lock);
+        }
+        if self.is_guard_used_by_value(expr) {
+            self.guard_used_by_value = true;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
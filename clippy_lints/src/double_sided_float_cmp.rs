@@ -0,0 +1,127 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_macro, snippet_with_applicability, span_lint_and_sugg, SpanlessEq};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for float comparisons that check the difference of two values
+    /// against a tolerance in both directions, like `(a - b) < eps && (b - a) < eps`.
+    ///
+    /// **Why is this bad?** This is a verbose way of comparing the absolute difference of the
+    /// values against the tolerance, and the duplicated operands make it easy to get one of the
+    /// two halves wrong.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # let a = 0.3f64;
+    /// # let b = 0.1f64 + 0.2;
+    /// // Bad
+    /// if (a - b) < f64::EPSILON && (b - a) < f64::EPSILON {}
+    ///
+    /// // Good
+    /// if (a - b).abs() < f64::EPSILON {}
+    /// ```
+    pub DOUBLE_SIDED_FLOAT_CMP,
+    complexity,
+    "checking the difference of two floats in both directions instead of using `abs()`"
+}
+
+declare_lint_pass!(DoubleSidedFloatCmp => [DOUBLE_SIDED_FLOAT_CMP]);
+
+impl<'tcx> LateLintPass<'tcx> for DoubleSidedFloatCmp {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Binary(ref op, ref lhs, ref rhs) = expr.kind;
+            if let Some(left) = DiffCmp::parse(lhs);
+            if let Some(right) = DiffCmp::parse(rhs);
+            if left.op == right.op;
+            if let Some(op_str) = abs_op_str(op.node, left.op);
+            if cx.typeck_results().expr_ty(left.minuend).is_floating_point();
+            if left.mirrors(cx, &right);
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let diff = Sugg::hir_with_applicability(cx, left.minuend, "..", &mut applicability)
+                    - Sugg::hir_with_applicability(cx, left.subtrahend, "..", &mut applicability);
+                let tolerance = snippet_with_applicability(cx, left.tolerance.span, "..", &mut applicability);
+
+                span_lint_and_sugg(
+                    cx,
+                    DOUBLE_SIDED_FLOAT_CMP,
+                    expr.span,
+                    "this double-sided float comparison can be simplified",
+                    "use `abs()` instead",
+                    format!("{}.abs() {} {}", diff.maybe_par(), op_str, tolerance),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// A comparison of the form `(minuend - subtrahend) op tolerance`. Comparisons with the
+/// difference on the right hand side are flipped, so `op` is always from the point of view of
+/// the difference.
+struct DiffCmp<'tcx> {
+    minuend: &'tcx Expr<'tcx>,
+    subtrahend: &'tcx Expr<'tcx>,
+    tolerance: &'tcx Expr<'tcx>,
+    op: BinOpKind,
+}
+
+impl<'tcx> DiffCmp<'tcx> {
+    fn parse(expr: &'tcx Expr<'tcx>) -> Option<Self> {
+        let (op, lhs, rhs) = match expr.kind {
+            ExprKind::Binary(op, lhs, rhs) => (op.node, lhs, rhs),
+            _ => return None,
+        };
+        let (op, diff, tolerance) = match op {
+            BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge if is_sub(lhs) => (op, lhs, rhs),
+            BinOpKind::Lt if is_sub(rhs) => (BinOpKind::Gt, rhs, lhs),
+            BinOpKind::Le if is_sub(rhs) => (BinOpKind::Ge, rhs, lhs),
+            BinOpKind::Gt if is_sub(rhs) => (BinOpKind::Lt, rhs, lhs),
+            BinOpKind::Ge if is_sub(rhs) => (BinOpKind::Le, rhs, lhs),
+            _ => return None,
+        };
+        if let ExprKind::Binary(_, minuend, subtrahend) = diff.kind {
+            Some(Self {
+                minuend,
+                subtrahend,
+                tolerance,
+                op,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether `other` compares the reversed difference against the same tolerance.
+    fn mirrors(&self, cx: &LateContext<'_>, other: &Self) -> bool {
+        let mut spanless_eq = SpanlessEq::new(cx).ignore_fn();
+        spanless_eq.eq_expr(self.minuend, other.subtrahend)
+            && spanless_eq.eq_expr(self.subtrahend, other.minuend)
+            && spanless_eq.eq_expr(self.tolerance, other.tolerance)
+    }
+}
+
+/// Returns the operator of the equivalent `abs()` comparison, if the two halves of the check
+/// are combined in a way that is equivalent to a single comparison of the absolute difference.
+fn abs_op_str(combinator: BinOpKind, op: BinOpKind) -> Option<&'static str> {
+    match (combinator, op) {
+        (BinOpKind::And, BinOpKind::Lt) => Some("<"),
+        (BinOpKind::And, BinOpKind::Le) => Some("<="),
+        (BinOpKind::Or, BinOpKind::Gt) => Some(">"),
+        (BinOpKind::Or, BinOpKind::Ge) => Some(">="),
+        _ => None,
+    }
+}
+
+fn is_sub(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Binary(ref op, _, _) if op.node == BinOpKind::Sub)
+}
@@ -2,12 +2,13 @@ use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Spanned;
 
 use crate::consts::{constant, Constant};
 use crate::utils::paths;
-use crate::utils::{match_type, snippet_with_applicability, span_lint_and_sugg, walk_ptrs_ty};
+use crate::utils::{match_type, snippet_with_applicability, span_lint_and_help, span_lint_and_sugg, walk_ptrs_ty};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for calculation of subsecond microseconds or milliseconds
@@ -36,10 +37,43 @@ declare_clippy_lint! {
     "checks for calculation of subsecond microseconds or milliseconds"
 }
 
-declare_lint_pass!(DurationSubsec => [DURATION_SUBSEC]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Duration::as_secs()` and `Duration::as_millis()` results
+    /// which are converted to a float or used as the divisor of an integer division.
+    ///
+    /// **Why is this bad?** Both methods truncate the duration to whole seconds or milliseconds,
+    /// so the fractional part is lost before the float arithmetic even starts. When computing a
+    /// rate like bytes per second, a duration shorter than one unit causes a division by zero.
+    /// `Duration::as_secs_f64()` keeps the full precision.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::time::Instant;
+    /// # let start = Instant::now();
+    /// # let bytes = 1024_u64;
+    /// let elapsed = start.elapsed();
+    /// let throughput = bytes as f64 / elapsed.as_secs() as f64;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::time::Instant;
+    /// # let start = Instant::now();
+    /// # let bytes = 1024_u64;
+    /// let elapsed = start.elapsed();
+    /// let throughput = bytes as f64 / elapsed.as_secs_f64();
+    /// ```
+    pub IMPRECISE_DURATION_ARITHMETIC,
+    pedantic,
+    "truncating a `Duration` with `as_secs()` or `as_millis()` before float arithmetic or division"
+}
+
+declare_lint_pass!(DurationSubsec => [DURATION_SUBSEC, IMPRECISE_DURATION_ARITHMETIC]);
 
 impl<'tcx> LateLintPass<'tcx> for DurationSubsec {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        check_imprecise_arithmetic(cx, expr);
         if_chain! {
             if let ExprKind::Binary(Spanned { node: BinOpKind::Div, .. }, ref left, ref right) = expr.kind;
             if let ExprKind::MethodCall(ref method_path, _ , ref args, _) = left.kind;
@@ -69,3 +103,88 @@ impl<'tcx> LateLintPass<'tcx> for DurationSubsec {
         }
     }
 }
+
+fn check_imprecise_arithmetic(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if expr.span.from_expansion() {
+        return;
+    }
+    match expr.kind {
+        ExprKind::Cast(inner, _) => {
+            if_chain! {
+                if let ty::Float(float_ty) = cx.typeck_results().expr_ty(expr).kind;
+                if let Some((receiver, method)) = truncating_duration_method(cx, inner);
+                then {
+                    let msg = format!(
+                        "`{}()` truncates the duration before the conversion to `{}`",
+                        method,
+                        float_ty.name_str()
+                    );
+                    if method == "as_secs" {
+                        let mut applicability = Applicability::MaybeIncorrect;
+                        span_lint_and_sugg(
+                            cx,
+                            IMPRECISE_DURATION_ARITHMETIC,
+                            expr.span,
+                            &msg,
+                            "try",
+                            format!(
+                                "{}.as_secs_{}()",
+                                snippet_with_applicability(cx, receiver.span, "_", &mut applicability),
+                                float_ty.name_str()
+                            ),
+                            applicability,
+                        );
+                    } else {
+                        span_lint_and_help(
+                            cx,
+                            IMPRECISE_DURATION_ARITHMETIC,
+                            expr.span,
+                            &msg,
+                            None,
+                            "consider using `as_secs_f64()` and scaling the result",
+                        );
+                    }
+                }
+            }
+        },
+        ExprKind::Binary(op, _, mut divisor) => {
+            if op.node != BinOpKind::Div || !cx.typeck_results().expr_ty(expr).is_integral() {
+                return;
+            }
+            while let ExprKind::Cast(inner, _) = divisor.kind {
+                divisor = inner;
+            }
+            if let Some((_, method)) = truncating_duration_method(cx, divisor) {
+                span_lint_and_help(
+                    cx,
+                    IMPRECISE_DURATION_ARITHMETIC,
+                    expr.span,
+                    &format!("division by the result of `{}()`, which truncates the duration", method),
+                    None,
+                    "this divides by zero for short durations, consider dividing by `as_secs_f64()` instead",
+                );
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Returns the receiver and the name of the method if the expression is a call to
+/// `Duration::as_secs` or `Duration::as_millis`.
+fn truncating_duration_method<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<(&'a Expr<'a>, &'static str)> {
+    if_chain! {
+        if let ExprKind::MethodCall(ref method_path, _, ref args, _) = expr.kind;
+        if match_type(cx, walk_ptrs_ty(cx.typeck_results().expr_ty(&args[0])), &paths::DURATION);
+        then {
+            if method_path.ident.name == sym!(as_secs) {
+                Some((&args[0], "as_secs"))
+            } else if method_path.ident.name == sym!(as_millis) {
+                Some((&args[0], "as_millis"))
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+}
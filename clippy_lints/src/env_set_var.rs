@@ -0,0 +1,137 @@
+use crate::utils::{is_entrypoint_fn, match_def_path, match_function_call, paths, span_lint_and_help};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, Expr, ExprKind, Node, Stmt};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `std::env::set_var` and `std::env::remove_var`,
+    /// unless they are in one of the statements at the start of `main` which come before the
+    /// first thread is spawned.
+    ///
+    /// **Why is this bad?** Modifying the environment while another thread reads it, e.g. with
+    /// `std::env::var` or through a C library calling `getenv`, is undefined behavior on many
+    /// platforms. Outside of the start of `main`, other threads may already be running.
+    ///
+    /// **Known problems:** Only `std::thread::spawn` and `std::thread::Builder::spawn` are
+    /// detected as spawning threads, so threads spawned by other functions at the start of
+    /// `main` are not taken into account.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn connect() {
+    ///     std::env::set_var("DATABASE_URL", "postgres://localhost");
+    ///     // ...
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn connect(database_url: &str) {
+    ///     // ...
+    /// }
+    /// ```
+    pub ENV_SET_VAR,
+    pedantic,
+    "modifying the environment when other threads may be running"
+}
+
+declare_lint_pass!(EnvSetVar => [ENV_SET_VAR]);
+
+impl<'tcx> LateLintPass<'tcx> for EnvSetVar {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let name = if match_function_call(cx, expr, &paths::ENV_SET_VAR).is_some() {
+            "set_var"
+        } else if match_function_call(cx, expr, &paths::ENV_REMOVE_VAR).is_some() {
+            "remove_var"
+        } else {
+            return;
+        };
+        if !is_at_start_of_main(cx, expr) {
+            span_lint_and_help(
+                cx,
+                ENV_SET_VAR,
+                expr.span,
+                &format!("calling `env::{}` when other threads may be running", name),
+                None,
+                "other threads reading the environment at the same time is undefined behavior on some platforms, \
+                 consider passing the configuration explicitly instead",
+            );
+        }
+    }
+}
+
+/// Checks whether the expression is in a statement of the body of `main`, not inside of a loop or
+/// closure, and no thread is spawned in that or any of the preceding statements.
+fn is_at_start_of_main<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> bool {
+    let map = cx.tcx.hir();
+    let fn_id = map.get_parent_item(expr.hir_id);
+    if !is_entrypoint_fn(cx, map.local_def_id(fn_id).to_def_id()) {
+        return false;
+    }
+    let body = map.body(map.body_owned_by(fn_id));
+    let block = match body.value.kind {
+        ExprKind::Block(block, _) => block,
+        _ => return false,
+    };
+    for (_, node) in map.parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(e) if matches!(e.kind, ExprKind::Loop(..) | ExprKind::Closure(..)) => return false,
+            Node::Stmt(stmt) => {
+                if let Some(index) = block.stmts.iter().position(|s| s.hir_id == stmt.hir_id) {
+                    return !block.stmts[..=index].iter().any(|stmt| spawns_thread(cx, stmt));
+                }
+            },
+            Node::Block(b) if b.hir_id == block.hir_id => return !spawns_thread_in_block(cx, block),
+            _ => {},
+        }
+    }
+    false
+}
+
+fn spawns_thread<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) -> bool {
+    let mut visitor = SpawnVisitor { cx, found: false };
+    visitor.visit_stmt(stmt);
+    visitor.found
+}
+
+fn spawns_thread_in_block<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) -> bool {
+    let mut visitor = SpawnVisitor { cx, found: false };
+    visitor.visit_block(block);
+    visitor.found
+}
+
+struct SpawnVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for SpawnVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if self.found {
+            return;
+        }
+        if match_function_call(self.cx, expr, &paths::THREAD_SPAWN).is_some() {
+            self.found = true;
+            return;
+        }
+        if let ExprKind::MethodCall(..) = expr.kind {
+            if let Some(def_id) = self.cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                if match_def_path(self.cx, def_id, &paths::THREAD_BUILDER_SPAWN) {
+                    self.found = true;
+                    return;
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
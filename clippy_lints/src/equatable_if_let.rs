@@ -0,0 +1,110 @@
+use crate::utils::{implements_trait, in_macro, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, MatchSource, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `if let` expressions whose pattern doesn't bind anything
+    /// and which could be written as an equality comparison.
+    ///
+    /// **Why is this bad?** `x == Foo::Bar` is shorter and reads more naturally than
+    /// `let Foo::Bar = x`, the pattern matching doesn't add anything.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[derive(PartialEq)]
+    /// enum Direction {
+    ///     Left,
+    ///     Right,
+    /// }
+    /// # let direction = Direction::Left;
+    /// if let Direction::Left = direction {
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # #[derive(PartialEq)]
+    /// # enum Direction {
+    /// #     Left,
+    /// #     Right,
+    /// # }
+    /// # let direction = Direction::Left;
+    /// if direction == Direction::Left {
+    ///     // ..
+    /// }
+    /// ```
+    pub EQUATABLE_IF_LET,
+    nursery,
+    "using `if let` where an equality comparison would do"
+}
+
+declare_lint_pass!(EquatableIfLet => [EQUATABLE_IF_LET]);
+
+impl<'tcx> LateLintPass<'tcx> for EquatableIfLet {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Match(scrutinee, [arm, ..], MatchSource::IfLetDesugar { .. }) = expr.kind;
+            if is_unary_pattern(arm.pat);
+            let scrutinee_ty = cx.typeck_results().expr_ty(scrutinee);
+            let pat_ty = cx.typeck_results().pat_ty(arm.pat);
+            if let Some(eq_trait) = cx.tcx.lang_items().eq_trait();
+            if implements_trait(cx, scrutinee_ty, eq_trait, &[pat_ty.into()]);
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let mut pat_snippet =
+                    snippet_with_applicability(cx, arm.pat.span, "..", &mut applicability).into_owned();
+                // struct literals need parentheses in the condition of an `if`
+                if is_struct_pattern(arm.pat) {
+                    pat_snippet = format!("({})", pat_snippet);
+                }
+                span_lint_and_sugg(
+                    cx,
+                    EQUATABLE_IF_LET,
+                    expr.span.with_hi(scrutinee.span.hi()),
+                    "this pattern matching can be expressed using equality",
+                    "try",
+                    format!(
+                        "if {} == {}",
+                        snippet_with_applicability(cx, scrutinee.span, "..", &mut applicability),
+                        pat_snippet,
+                    ),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether the pattern matches exactly one value, i.e. whether it can be turned into an
+/// expression.
+fn is_unary_pattern(pat: &Pat<'_>) -> bool {
+    match pat.kind {
+        PatKind::Path(_) | PatKind::Lit(_) => true,
+        PatKind::Ref(inner, _) => is_unary_pattern(inner),
+        PatKind::Tuple(pats, None) | PatKind::TupleStruct(_, pats, None) => pats.iter().all(is_unary_pattern),
+        PatKind::Struct(_, fields, false) => fields.iter().all(|field| is_unary_pattern(field.pat)),
+        PatKind::Tuple(..)
+        | PatKind::TupleStruct(..)
+        | PatKind::Struct(..)
+        | PatKind::Box(_)
+        | PatKind::Binding(..)
+        | PatKind::Wild
+        | PatKind::Or(_)
+        | PatKind::Range(..)
+        | PatKind::Slice(..) => false,
+    }
+}
+
+fn is_struct_pattern(pat: &Pat<'_>) -> bool {
+    match pat.kind {
+        PatKind::Struct(..) => true,
+        PatKind::Ref(inner, _) => is_struct_pattern(inner),
+        _ => false,
+    }
+}
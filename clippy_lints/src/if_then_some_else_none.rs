@@ -0,0 +1,97 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::usage::contains_return_break_continue_macro;
+use crate::utils::{
+    has_usable_inherent_method, higher, in_constant, in_macro, is_else_clause, match_qpath, meets_msrv, paths,
+    remove_blocks, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use semver::Version;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `if` expressions which evaluate to `Some(x)` if the condition
+    /// holds and to `None` otherwise.
+    ///
+    /// **Why is this bad?** `bool::then` does the same in a more concise way.
+    ///
+    /// **Known problems:** `bool::then_some` would be even more concise if `x` is cheap to
+    /// evaluate, but isn't stable yet. The lint doesn't trigger if `x` contains a `return`,
+    /// `break`, `continue` or macro call, since moving it into a closure can change its meaning.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let count = 3;
+    /// let average = if count > 0 { Some(42 / count) } else { None };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// # let count = 3;
+    /// let average = (count > 0).then(|| 42 / count);
+    /// ```
+    pub IF_THEN_SOME_ELSE_NONE,
+    style,
+    "`if cond { Some(x) } else { None }` which could be written with `bool::then`"
+}
+
+const IF_THEN_SOME_ELSE_NONE_MSRV: Version = Version {
+    major: 1,
+    minor: 50,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+pub struct IfThenSomeElseNone {
+    msrv: Option<Version>,
+}
+
+impl IfThenSomeElseNone {
+    #[must_use]
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(IfThenSomeElseNone => [IF_THEN_SOME_ELSE_NONE]);
+
+impl<'tcx> LateLintPass<'tcx> for IfThenSomeElseNone {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !meets_msrv(self.msrv.as_ref(), &IF_THEN_SOME_ELSE_NONE_MSRV)
+            || in_macro(expr.span)
+            || in_constant(cx, expr.hir_id)
+            || is_else_clause(cx, expr)
+        {
+            return;
+        }
+        if_chain! {
+            if let Some((cond, then, Some(els))) = higher::if_block(expr);
+            if let ExprKind::Call(some_expr, [value]) = remove_blocks(then).kind;
+            if let ExprKind::Path(ref some_path) = some_expr.kind;
+            if match_qpath(some_path, &paths::OPTION_SOME);
+            if let ExprKind::Path(ref none_path) = remove_blocks(els).kind;
+            if match_qpath(none_path, &paths::OPTION_NONE);
+            if !contains_return_break_continue_macro(value);
+            // `bool::then` may not be stable in the standard library yet, even if the MSRV allows it
+            if has_usable_inherent_method(cx, cx.tcx.types.bool, "then");
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let cond_sugg = Sugg::hir_with_applicability(cx, cond, "..", &mut applicability);
+                let value_sugg = Sugg::hir_with_applicability(cx, value, "..", &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    IF_THEN_SOME_ELSE_NONE,
+                    expr.span,
+                    "this could be simplified with `bool::then`",
+                    "try",
+                    format!("{}.then(|| {})", cond_sugg.maybe_par(), value_sugg),
+                    applicability,
+                );
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
@@ -0,0 +1,132 @@
+use crate::utils::{in_macro, snippet, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Field};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::Symbol;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for struct constructors where the order of the field init
+    /// expressions is inconsistent with the order in the struct definition.
+    ///
+    /// **Why is this bad?** Since the order of fields in a constructor doesn't affect the
+    /// resulted instance as the below example indicates,
+    ///
+    /// ```rust
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct Foo {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    /// let x = 1;
+    /// let y = 2;
+    ///
+    /// // This assertion never fails.
+    /// assert_eq!(Foo { x, y }, Foo { y, x });
+    /// ```
+    ///
+    /// inconsistent order means nothing and just decreases readability and consistency. It also
+    /// makes swapped or copy-pasted field values harder to spot in review.
+    ///
+    /// **Known problems:** The lint only checks constructors where all the field init
+    /// expressions are paths or literals, since reordering other expressions could change the
+    /// order of their side effects.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// struct Foo {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    /// let x = 1;
+    /// let y = 2;
+    /// Foo { y, x };
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # struct Foo {
+    /// #     x: i32,
+    /// #     y: i32,
+    /// # }
+    /// # let x = 1;
+    /// # let y = 2;
+    /// Foo { x, y };
+    /// ```
+    pub INCONSISTENT_STRUCT_CONSTRUCTOR,
+    pedantic,
+    "the order of the field init expressions is inconsistent with the order in the struct definition"
+}
+
+declare_lint_pass!(InconsistentStructConstructor => [INCONSISTENT_STRUCT_CONSTRUCTOR]);
+
+impl<'tcx> LateLintPass<'tcx> for InconsistentStructConstructor {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Struct(_, fields, base) = expr.kind;
+            if let Some(first_field) = fields.first();
+            let ty = cx.typeck_results().expr_ty(expr);
+            if let Some(adt_def) = ty.ty_adt_def();
+            if adt_def.is_struct();
+            if let Some(variant) = adt_def.variants.iter().next();
+            if fields.iter().all(|f| is_side_effect_free(f.expr));
+            then {
+                let def_order_map: FxHashMap<Symbol, usize> = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, field)| (field.ident.name, idx))
+                    .collect();
+
+                if is_consistent_order(fields, &def_order_map) {
+                    return;
+                }
+
+                let mut ordered_fields: Vec<_> = fields.iter().collect();
+                ordered_fields.sort_unstable_by_key(|f| def_order_map[&f.ident.name]);
+                let fields_snippet = ordered_fields
+                    .iter()
+                    .map(|f| snippet(cx, f.span, ".."))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let base_snippet = if let Some(base) = base {
+                    format!(", ..{}", snippet(cx, base.span, ".."))
+                } else {
+                    String::new()
+                };
+
+                let path_snippet = snippet(cx, expr.span.until(first_field.span), "..");
+                let path_snippet = path_snippet.trim_end().trim_end_matches('{').trim_end();
+
+                span_lint_and_sugg(
+                    cx,
+                    INCONSISTENT_STRUCT_CONSTRUCTOR,
+                    expr.span,
+                    "struct constructor field order is inconsistent with struct definition field order",
+                    "try",
+                    format!("{} {{ {}{} }}", path_snippet, fields_snippet, base_snippet),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether evaluating `expr` can't have side effects, so that the fields can be
+/// reordered freely.
+fn is_side_effect_free(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Path(_) | ExprKind::Lit(_))
+}
+
+/// Checks whether the order of the fields in the constructor is consistent with the order in
+/// the definition.
+fn is_consistent_order<'tcx>(fields: &'tcx [Field<'tcx>], def_order_map: &FxHashMap<Symbol, usize>) -> bool {
+    fields
+        .windows(2)
+        .all(|w| def_order_map[&w[0].ident.name] < def_order_map[&w[1].ident.name])
+}
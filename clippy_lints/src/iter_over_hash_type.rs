@@ -0,0 +1,135 @@
+use crate::utils::{
+    get_parent_expr, higher, is_type_diagnostic_item, match_type, paths, span_lint_and_help, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::Ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for iteration over a `HashMap` or a `HashSet`, either with a `for`
+    /// loop or with one of the iterator methods like `iter()` or `keys()`. Iterators which are
+    /// directly consumed in a way that doesn't depend on the order of the elements, like with
+    /// `count()`, an integer `sum()` or by collecting them into another map or set, are not linted.
+    ///
+    /// **Why is this bad?** The iteration order of hash based collections is unspecified and
+    /// usually differs between runs of the program, so writing the elements to some output or
+    /// picking the first element makes the program nondeterministic. This is a problem for
+    /// reproducible builds and tests comparing the output.
+    ///
+    /// **Known problems:** Loops whose body doesn't depend on the order of the elements are
+    /// linted as well.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// let map: HashMap<&str, u32> = HashMap::new();
+    /// for (name, score) in &map {
+    ///     println!("{}: {}", name, score);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::BTreeMap;
+    /// let map: BTreeMap<&str, u32> = BTreeMap::new();
+    /// for (name, score) in &map {
+    ///     println!("{}: {}", name, score);
+    /// }
+    /// ```
+    pub ITER_OVER_HASH_TYPE,
+    restriction,
+    "iterating over a `HashMap` or `HashSet`, whose iteration order is unspecified"
+}
+
+declare_lint_pass!(IterOverHashType => [ITER_OVER_HASH_TYPE]);
+
+/// Methods of `HashMap` and `HashSet` returning an iterator over the elements.
+const ITER_METHODS: [&str; 7] = ["drain", "into_iter", "iter", "iter_mut", "keys", "values", "values_mut"];
+
+/// Iterator adapters which keep the order of the elements relevant.
+const ADAPTERS: [&str; 7] = ["cloned", "copied", "filter", "filter_map", "flat_map", "flatten", "map"];
+
+/// Iterator methods whose result doesn't depend on the order of the elements.
+const ORDER_INDEPENDENT: [&str; 3] = ["all", "any", "count"];
+
+/// Iterator methods whose result doesn't depend on the order of the elements if they are integers.
+/// Floating point arithmetic isn't associative, so the result may differ for floats.
+const ORDER_INDEPENDENT_FOR_INTEGERS: [&str; 2] = ["product", "sum"];
+
+impl<'tcx> LateLintPass<'tcx> for IterOverHashType {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some((_, arg, _)) = higher::for_loop(expr) {
+            if_chain! {
+                if !arg.span.from_expansion();
+                if let Some(name) = hash_type_name(cx, cx.typeck_results().expr_ty(arg));
+                then {
+                    lint(cx, arg.span, name);
+                }
+            }
+        } else if let ExprKind::MethodCall(path, _, args, _) = expr.kind {
+            if_chain! {
+                if !expr.span.from_expansion();
+                if ITER_METHODS.contains(&&*path.ident.as_str());
+                if let Some(name) = hash_type_name(cx, cx.typeck_results().expr_ty(&args[0]));
+                if !is_consumed_order_independently(cx, expr);
+                then {
+                    lint(cx, expr.span, name);
+                }
+            }
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, span: Span, name: &str) {
+    span_lint_and_help(
+        cx,
+        ITER_OVER_HASH_TYPE,
+        span,
+        &format!("iteration over a `{}`, whose iteration order is unspecified", name),
+        None,
+        &format!(
+            "the order may differ between runs, consider using a `BTree{}` or sorting the elements if the order matters",
+            &name[4..]
+        ),
+    );
+}
+
+fn hash_type_name(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<&'static str> {
+    let ty = walk_ptrs_ty(ty);
+    if is_type_diagnostic_item(cx, ty, sym!(hashmap_type)) {
+        Some("HashMap")
+    } else if is_type_diagnostic_item(cx, ty, sym!(hashset_type)) {
+        Some("HashSet")
+    } else {
+        None
+    }
+}
+
+/// Checks whether the iterator is consumed by a method like `count()` or an integer `sum()`,
+/// possibly after some adapters like `map()`, or collected into another map or set.
+fn is_consumed_order_independently(cx: &LateContext<'_>, iter: &Expr<'_>) -> bool {
+    let mut child = iter;
+    while let Some(parent) = get_parent_expr(cx, child) {
+        match parent.kind {
+            ExprKind::MethodCall(path, _, args, _) if args[0].hir_id == child.hir_id => {
+                let name = path.ident.as_str();
+                if ADAPTERS.contains(&&*name) {
+                    child = parent;
+                } else if name == "collect" {
+                    let ty = cx.typeck_results().expr_ty(parent);
+                    return hash_type_name(cx, ty).is_some()
+                        || match_type(cx, ty, &paths::BTREEMAP)
+                        || match_type(cx, ty, &paths::BTREESET);
+                } else if ORDER_INDEPENDENT_FOR_INTEGERS.contains(&&*name) {
+                    return cx.typeck_results().expr_ty(parent).is_integral();
+                } else {
+                    return ORDER_INDEPENDENT.contains(&&*name);
+                }
+            },
+            _ => return false,
+        }
+    }
+    false
+}
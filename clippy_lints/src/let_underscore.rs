@@ -5,7 +5,7 @@ use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::subst::GenericArgKind;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
-use crate::utils::{is_must_use_func_call, is_must_use_ty, match_type, paths, span_lint_and_help};
+use crate::utils::{implements_trait, is_must_use_func_call, is_must_use_ty, match_type, paths, span_lint_and_help};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `let _ = <expr>`
@@ -58,7 +58,40 @@ declare_clippy_lint! {
     "non-binding let on a synchronization lock"
 }
 
-declare_lint_pass!(LetUnderscore => [LET_UNDERSCORE_MUST_USE, LET_UNDERSCORE_LOCK]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for `let _ = <expr>`
+    /// where the resulting type of expr implements `Future`
+    ///
+    /// **Why is this bad?** Futures are lazy, they don't do anything unless they
+    /// are awaited or polled, e.g. by spawning them on an executor. Binding a
+    /// future to `_` drops it immediately, so the work it represents never happens.
+    ///
+    /// Futures that are discarded in an expression statement are already caught
+    /// by the `unused_must_use` lint of rustc.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// Bad:
+    /// ```rust,ignore
+    /// async fn send() {}
+    ///
+    /// let _ = send();
+    /// ```
+    ///
+    /// Good:
+    /// ```rust,ignore
+    /// async fn send() {}
+    ///
+    /// send().await;
+    /// ```
+    pub LET_UNDERSCORE_FUTURE,
+    correctness,
+    "non-binding let on a future"
+}
+
+declare_lint_pass!(LetUnderscore => [LET_UNDERSCORE_MUST_USE, LET_UNDERSCORE_LOCK, LET_UNDERSCORE_FUTURE]);
 
 const SYNC_GUARD_PATHS: [&[&str]; 3] = [
     &paths::MUTEX_GUARD,
@@ -84,6 +117,11 @@ impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
 
                     GenericArgKind::Lifetime(_) | GenericArgKind::Const(_) => false,
                 });
+                let is_future = cx
+                    .tcx
+                    .lang_items()
+                    .future_trait()
+                    .map_or(false, |future| implements_trait(cx, init_ty, future, &[]));
                 if contains_sync_guard {
                     span_lint_and_help(
                         cx,
@@ -93,7 +131,17 @@ impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
                         None,
                         "consider using an underscore-prefixed named \
                             binding or dropping explicitly with `std::mem::drop`"
-                    )
+                    );
+                } else if is_future {
+                    span_lint_and_help(
+                        cx,
+                        LET_UNDERSCORE_FUTURE,
+                        local.span,
+                        "non-binding let on a future",
+                        None,
+                        "futures do nothing unless you `.await` or poll them, \
+                            consider awaiting the future or spawning it on an executor"
+                    );
                 } else if is_must_use_ty(cx, cx.typeck_results().expr_ty(init)) {
                     span_lint_and_help(
                         cx,
@@ -102,7 +150,7 @@ impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
                         "non-binding let on an expression with `#[must_use]` type",
                         None,
                         "consider explicitly using expression value"
-                    )
+                    );
                 } else if is_must_use_func_call(cx, init) {
                     span_lint_and_help(
                         cx,
@@ -111,7 +159,7 @@ impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
                         "non-binding let on a result of a `#[must_use]` function",
                         None,
                         "consider explicitly using function result"
-                    )
+                    );
                 }
             }
         }
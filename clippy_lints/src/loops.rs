@@ -4,9 +4,10 @@ use crate::utils::paths;
 use crate::utils::usage::{is_unused, mutated_variables};
 use crate::utils::{
     get_enclosing_block, get_parent_expr, get_trait_def_id, has_iter_method, higher, implements_trait,
-    is_integer_const, is_no_std_crate, is_refutable, last_path_segment, match_trait_method, match_type, match_var,
-    multispan_sugg, snippet, snippet_opt, snippet_with_applicability, span_lint, span_lint_and_help,
-    span_lint_and_sugg, span_lint_and_then, SpanlessEq,
+    is_integer_const, is_no_std_crate, is_refutable, last_path_segment, match_qpath, match_trait_method, match_type,
+    match_var, multispan_sugg, multispan_sugg_with_applicability, snippet, snippet_block_with_applicability,
+    snippet_opt, snippet_with_applicability, span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then,
+    SpanlessEq,
 };
 use crate::utils::{is_type_diagnostic_item, qpath_res, sugg};
 use if_chain::if_chain;
@@ -420,6 +421,36 @@ declare_clippy_lint! {
     "variables used within while expression are not mutated in the body"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `for` loops whose body only consists of an `if let` matching
+    /// the `Some` or `Ok` variant of the loop variable.
+    ///
+    /// **Why is this bad?** Iterating over `iter.flatten()` skips the `None` and `Err` items
+    /// without the extra nesting.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let names = vec![Some("Jane"), None, Some("John")];
+    /// for name in names {
+    ///     if let Some(name) = name {
+    ///         println!("{}", name);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let names = vec![Some("Jane"), None, Some("John")];
+    /// for name in names.into_iter().flatten() {
+    ///     println!("{}", name);
+    /// }
+    /// ```
+    pub MANUAL_FLATTEN,
+    style,
+    "`for` loops with an `if let Some(..)` or `if let Ok(..)` which could iterate over a flattened iterator"
+}
+
 declare_lint_pass!(Loops => [
     MANUAL_MEMCPY,
     NEEDLESS_RANGE_LOOP,
@@ -436,6 +467,7 @@ declare_lint_pass!(Loops => [
     NEVER_LOOP,
     MUT_RANGE_BOUND,
     WHILE_IMMUTABLE_CONDITION,
+    MANUAL_FLATTEN,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -661,7 +693,7 @@ fn never_loop_expr(expr: &Expr<'_>, main_loop_id: HirId) -> NeverLoopResult {
         ExprKind::Binary(_, ref e1, ref e2)
         | ExprKind::Assign(ref e1, ref e2, _)
         | ExprKind::AssignOp(_, ref e1, ref e2)
-        | ExprKind::Index(ref e1, ref e2) => never_loop_expr_all(&mut [&**e1, &**e2].iter().cloned(), main_loop_id),
+        | ExprKind::Index(ref e1, ref e2) => never_loop_expr_all(&mut [&**e1, &**e2].iter().copied(), main_loop_id),
         ExprKind::Loop(ref b, _, _) => {
             // Break can come from the inner loop so remove them.
             absorb_break(&never_loop_block(b, main_loop_id))
@@ -741,6 +773,7 @@ fn check_for_loop<'tcx>(
     check_for_loop_over_map_kv(cx, pat, arg, body, expr);
     check_for_mut_range_bound(cx, arg, body);
     detect_manual_memcpy(cx, pat, arg, body, expr);
+    check_manual_flatten(cx, pat, arg, body, expr);
 }
 
 fn same_var<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, var: HirId) -> bool {
@@ -868,9 +901,9 @@ fn get_assignments<'tcx>(body: &'tcx Expr<'tcx>) -> impl Iterator<Item = Option<
             })
             .chain(expr.into_iter())
             .map(get_assignment)
-            .into()
+            .into();
     } else {
-        iter_b = Some(get_assignment(body))
+        iter_b = Some(get_assignment(body));
     }
 
     iter_a.into_iter().flatten().chain(iter_b.into_iter())
@@ -1235,7 +1268,7 @@ fn lint_iter_method(cx: &LateContext<'_>, args: &[Expr<'_>], arg: &Expr<'_>, met
         "to write this more concisely, try",
         format!("&{}{}", muta, object),
         applicability,
-    )
+    );
 }
 
 fn check_for_loop_arg(cx: &LateContext<'_>, pat: &Pat<'_>, arg: &Expr<'_>, expr: &Expr<'_>) {
@@ -1274,7 +1307,7 @@ fn check_for_loop_arg(cx: &LateContext<'_>, pat: &Pat<'_>, arg: &Expr<'_>, expr:
                         },
                     );
                     if TyS::same_type(receiver_ty_adjusted, ref_receiver_ty) {
-                        lint_iter_method(cx, args, arg, method_name)
+                        lint_iter_method(cx, args, arg, method_name);
                     }
                 }
             } else if method_name == "next" && match_trait_method(cx, arg, &paths::ITERATOR) {
@@ -1486,6 +1519,84 @@ fn check_for_loop_over_map_kv<'tcx>(
     }
 }
 
+/// Checks for the `MANUAL_FLATTEN` lint.
+fn check_manual_flatten<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+) {
+    if_chain! {
+        if let PatKind::Binding(BindingAnnotation::Unannotated, binding_id, _, None) = pat.kind;
+        if let ExprKind::Block(ref block, _) = body.kind;
+        if block.stmts.len() <= 1;
+        if let Some(inner) = extract_first_expr(block);
+        if let ExprKind::Match(ref scrutinee, ref arms, MatchSource::IfLetDesugar { contains_else_clause }) =
+            inner.kind;
+        if same_var(cx, scrutinee, binding_id);
+        if let PatKind::TupleStruct(ref variant_path, [ref inner_pat], _) = arms[0].pat.kind;
+        if !is_refutable(cx, inner_pat);
+        // the `None` or `Err` items would be skipped without running the `else` branch
+        if !contains_else_clause || is_empty_block(&arms[1].body);
+        then {
+            let variant = if match_qpath(variant_path, &paths::OPTION_SOME) {
+                "Some"
+            } else if match_qpath(variant_path, &paths::RESULT_OK) {
+                "Ok"
+            } else {
+                return;
+            };
+            // the loop variable itself is gone after flattening the iterator
+            let mut used_visitor = LocalUsedVisitor {
+                cx,
+                local: binding_id,
+                used: false,
+            };
+            walk_expr(&mut used_visitor, &arms[0].body);
+            if used_visitor.used {
+                return;
+            }
+
+            span_lint_and_then(
+                cx,
+                MANUAL_FLATTEN,
+                expr.span,
+                &format!(
+                    "unnecessary `if let` since only the `{}` variant of the iterator element is used",
+                    variant
+                ),
+                |diag| {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let iterator = make_iterator_snippet(cx, arg, &mut applicability);
+                    let inner_pat_snippet = snippet_with_applicability(cx, inner_pat.span, "..", &mut applicability);
+                    let then_snippet = snippet_block_with_applicability(
+                        cx,
+                        arms[0].body.span,
+                        "..",
+                        Some(expr.span),
+                        &mut applicability,
+                    );
+                    multispan_sugg_with_applicability(
+                        diag,
+                        "try iterating over the flattened iterator",
+                        applicability,
+                        vec![
+                            (pat.span, inner_pat_snippet.into_owned()),
+                            (arg.span, format!("{}.flatten()", iterator)),
+                            (body.span, then_snippet.into_owned()),
+                        ],
+                    );
+                },
+            );
+        }
+    }
+}
+
+fn is_empty_block(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Block(block, _) if block.stmts.is_empty() && block.expr.is_none())
+}
+
 struct MutatePairDelegate<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     hir_id_low: Option<HirId>,
@@ -1501,10 +1612,10 @@ impl<'tcx> Delegate<'tcx> for MutatePairDelegate<'_, 'tcx> {
         if let ty::BorrowKind::MutBorrow = bk {
             if let PlaceBase::Local(id) = cmt.place.base {
                 if Some(id) == self.hir_id_low {
-                    self.span_low = Some(self.cx.tcx.hir().span(cmt.hir_id))
+                    self.span_low = Some(self.cx.tcx.hir().span(cmt.hir_id));
                 }
                 if Some(id) == self.hir_id_high {
-                    self.span_high = Some(self.cx.tcx.hir().span(cmt.hir_id))
+                    self.span_high = Some(self.cx.tcx.hir().span(cmt.hir_id));
                 }
             }
         }
@@ -1513,10 +1624,10 @@ impl<'tcx> Delegate<'tcx> for MutatePairDelegate<'_, 'tcx> {
     fn mutate(&mut self, cmt: &PlaceWithHirId<'tcx>) {
         if let PlaceBase::Local(id) = cmt.place.base {
             if Some(id) == self.hir_id_low {
-                self.span_low = Some(self.cx.tcx.hir().span(cmt.hir_id))
+                self.span_low = Some(self.cx.tcx.hir().span(cmt.hir_id));
             }
             if Some(id) == self.hir_id_high {
-                self.span_high = Some(self.cx.tcx.hir().span(cmt.hir_id))
+                self.span_high = Some(self.cx.tcx.hir().span(cmt.hir_id));
             }
         }
     }
@@ -1977,7 +2088,7 @@ impl<'a, 'tcx> Visitor<'tcx> for IncrementVisitor<'a, 'tcx> {
                     },
                     ExprKind::Assign(ref lhs, _, _) if lhs.hir_id == expr.hir_id => *state = VarState::DontWarn,
                     ExprKind::AddrOf(BorrowKind::Ref, mutability, _) if mutability == Mutability::Mut => {
-                        *state = VarState::DontWarn
+                        *state = VarState::DontWarn;
                     },
                     _ => (),
                 }
@@ -2025,7 +2136,7 @@ impl<'a, 'tcx> Visitor<'tcx> for InitializeVisitor<'a, 'tcx> {
                         } else {
                             VarState::Declared
                         }
-                    })
+                    });
                 }
             }
         }
@@ -2061,7 +2172,7 @@ impl<'a, 'tcx> Visitor<'tcx> for InitializeVisitor<'a, 'tcx> {
                         }
                     },
                     ExprKind::AddrOf(BorrowKind::Ref, mutability, _) if mutability == Mutability::Mut => {
-                        self.state = VarState::DontWarn
+                        self.state = VarState::DontWarn;
                     },
                     _ => (),
                 }
@@ -2210,7 +2321,7 @@ impl<'tcx> Visitor<'tcx> for LoopNestVisitor {
                 return;
             }
         }
-        walk_pat(self, pat)
+        walk_pat(self, pat);
     }
 
     fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
@@ -0,0 +1,114 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{higher, in_macro, is_direct_expn_of, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Block, Expr, ExprKind, Node, Stmt, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Detects `if`-then-`panic!` that can be replaced with `assert!`.
+    ///
+    /// **Why is this bad?** `assert!` is simpler than `if`-then-`panic!`.
+    ///
+    /// **Known problems:** Comments inside the `if` block are lost by the suggestion.
+    ///
+    /// **Example:**
+    /// ```rust,should_panic
+    /// let sad_people: Vec<&str> = vec!["Alice"];
+    /// if !sad_people.is_empty() {
+    ///     panic!("there are sad people: {:?}", sad_people);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,should_panic
+    /// let sad_people: Vec<&str> = vec!["Alice"];
+    /// assert!(sad_people.is_empty(), "there are sad people: {:?}", sad_people);
+    /// ```
+    pub MANUAL_ASSERT,
+    style,
+    "`panic!` and only a `panic!` in `if`-then statement"
+}
+
+declare_lint_pass!(ManualAssert => [MANUAL_ASSERT]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualAssert {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let Some((cond, then, None)) = higher::if_block(expr);
+            if !cond.span.from_expansion();
+            if let ExprKind::Block(block, _) = then.kind;
+            if let Some(panic_expr) = single_expr(block);
+            if let Some(panic_span) = panic_call_span(panic_expr);
+            let map = cx.tcx.hir();
+            let parent = map.find(map.get_parent_node(expr.hir_id));
+            // `else if` clauses and `if`s used as values can't be replaced by `assert!`
+            if matches!(parent, Some(Node::Stmt(_)) | Some(Node::Block(_)));
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let cond = if let ExprKind::Unary(UnOp::UnNot, inner) = cond.kind {
+                    Sugg::hir_with_applicability(cx, inner, "..", &mut applicability)
+                } else {
+                    !Sugg::hir_with_applicability(cx, cond, "..", &mut applicability)
+                };
+                let panic_snippet = snippet_with_applicability(cx, panic_span, "..", &mut applicability);
+                let args = macro_args(&panic_snippet);
+                let mut sugg = if args.is_empty() {
+                    format!("assert!({})", cond)
+                } else {
+                    format!("assert!({}, {})", cond, args)
+                };
+                // the `if` doesn't need a semicolon as a statement, but `assert!(..)` does
+                if !matches!(parent, Some(Node::Stmt(Stmt { kind: StmtKind::Semi(_), .. }))) {
+                    sugg.push(';');
+                }
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_ASSERT,
+                    expr.span,
+                    "only a `panic!` in `if`-then statement",
+                    "try",
+                    sugg,
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the only expression of a block, with or without a trailing semicolon.
+fn single_expr<'tcx>(block: &'tcx Block<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match (block.stmts, block.expr) {
+        ([], Some(expr)) => Some(expr),
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the span of the `panic!` call `expr` is expanded from, unless the call itself comes
+/// from another macro.
+fn panic_call_span(expr: &Expr<'_>) -> Option<Span> {
+    let mut span = is_direct_expn_of(expr.span, "panic")?;
+    // `panic!()` expands to another `panic!(..)` call
+    while let Some(call_site) = is_direct_expn_of(span, "panic") {
+        span = call_site;
+    }
+    if span.from_expansion() {
+        None
+    } else {
+        Some(span)
+    }
+}
+
+/// Returns the arguments of a macro call snippet like `panic!("{}", x)`, without the delimiters.
+fn macro_args(snippet: &str) -> &str {
+    match snippet.find(|c| c == '(' || c == '[' || c == '{') {
+        Some(start) if snippet.len() > start + 1 => snippet[start + 1..snippet.len() - 1].trim(),
+        _ => "",
+    }
+}
@@ -0,0 +1,273 @@
+use crate::consts::{constant, Constant};
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    get_trait_def_id, has_usable_assoc_fn, has_usable_inherent_method, higher, implements_trait, in_constant, in_macro,
+    match_trait_method, meets_msrv, paths, remove_blocks, span_lint_and_then, SpanlessEq,
+};
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, BinOpKind, Expr, ExprKind, Guard, MatchSource, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use semver::Version;
+use std::cmp::Ordering;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for code which restricts a value to a range, like
+    /// `if x < lo { lo } else if x > hi { hi } else { x }`, `x.max(lo).min(hi)` or the equivalent
+    /// `match` with guards.
+    ///
+    /// **Why is this bad?** `clamp` expresses the intent more clearly and is less error-prone than
+    /// spelling out both comparisons.
+    ///
+    /// **Known problems:** Unlike the manual version, `clamp` panics if the lower bound is greater
+    /// than the upper bound. For floats, it also panics if a bound is NaN and returns NaN if the
+    /// input is NaN. The suggestion is only machine applicable for integer constants as bounds.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let input = 42;
+    /// let percent = if input < 0 {
+    ///     0
+    /// } else if input > 100 {
+    ///     100
+    /// } else {
+    ///     input
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// # let input = 42;
+    /// let percent = input.clamp(0, 100);
+    /// ```
+    pub MANUAL_CLAMP,
+    complexity,
+    "manually restricting a value to a range instead of using `clamp`"
+}
+
+const MANUAL_CLAMP_MSRV: Version = Version {
+    major: 1,
+    minor: 50,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+pub struct ManualClamp {
+    msrv: Option<Version>,
+}
+
+impl ManualClamp {
+    #[must_use]
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualClamp => [MANUAL_CLAMP]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualClamp {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !meets_msrv(self.msrv.as_ref(), &MANUAL_CLAMP_MSRV) || in_macro(expr.span) || in_constant(cx, expr.hir_id) {
+            return;
+        }
+        let clamp = get_if_clamp(cx, expr)
+            .or_else(|| get_match_clamp(cx, expr))
+            .or_else(|| get_min_max_clamp(cx, expr));
+        if let Some(clamp) = clamp {
+            lint(cx, expr, &clamp);
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// The value restricted to a range and the bounds of the range.
+struct Clamp<'tcx> {
+    input: &'tcx Expr<'tcx>,
+    lower: &'tcx Expr<'tcx>,
+    upper: &'tcx Expr<'tcx>,
+}
+
+/// A comparison like `x < lo` in a branch which evaluates to the bound.
+struct BoundCheck<'tcx> {
+    input: &'tcx Expr<'tcx>,
+    bound: &'tcx Expr<'tcx>,
+    is_lower: bool,
+}
+
+/// `if x < lo { lo } else if x > hi { hi } else { x }`, in any order of the comparisons
+fn get_if_clamp<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Clamp<'tcx>> {
+    let (first_cond, first_then, first_else) = higher::if_block(expr)?;
+    let (second_cond, second_then, default) = higher::if_block(first_else?)?;
+    let first = check_bound(cx, first_cond, first_then)?;
+    let second = check_bound(cx, second_cond, second_then)?;
+    combine(cx, &first, &second, default?)
+}
+
+/// `match x { _ if x < lo => lo, _ if x > hi => hi, _ => x }`, in any order of the comparisons
+fn get_match_clamp<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Clamp<'tcx>> {
+    if let ExprKind::Match(scrutinee, [first_arm, second_arm, last_arm], MatchSource::Normal) = expr.kind {
+        let first = check_guarded_arm(cx, first_arm)?;
+        let second = check_guarded_arm(cx, second_arm)?;
+        if last_arm.guard.is_some() || !matches!(last_arm.pat.kind, PatKind::Wild) {
+            return None;
+        }
+        let clamp = combine(cx, &first, &second, last_arm.body)?;
+        if SpanlessEq::new(cx).ignore_fn().eq_expr(clamp.input, scrutinee) {
+            return Some(clamp);
+        }
+    }
+    None
+}
+
+/// `x.max(lo).min(hi)` or `x.min(hi).max(lo)`
+fn get_min_max_clamp<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<Clamp<'tcx>> {
+    if let ExprKind::MethodCall(outer_path, _, [inner, outer_bound], _) = expr.kind {
+        if let ExprKind::MethodCall(inner_path, _, [input, inner_bound], _) = inner.kind {
+            let (lower, upper) = match (&*outer_path.ident.as_str(), &*inner_path.ident.as_str()) {
+                ("min", "max") => (inner_bound, outer_bound),
+                ("max", "min") => (outer_bound, inner_bound),
+                _ => return None,
+            };
+            if is_min_max_method(cx, expr) && is_min_max_method(cx, inner) {
+                return Some(Clamp { input, lower, upper });
+            }
+        }
+    }
+    None
+}
+
+fn is_min_max_method(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results().expr_ty(expr).is_floating_point() || match_trait_method(cx, expr, &paths::ORD)
+}
+
+fn check_guarded_arm<'tcx>(cx: &LateContext<'tcx>, arm: &'tcx Arm<'tcx>) -> Option<BoundCheck<'tcx>> {
+    match (&arm.pat.kind, &arm.guard) {
+        (PatKind::Wild, Some(Guard::If(cond))) => check_bound(cx, cond, arm.body),
+        _ => None,
+    }
+}
+
+/// Checks whether `cond` compares a value with the bound `body` evaluates to, e.g. `x < lo` or
+/// `lo > x` with `lo` as `body`.
+fn check_bound<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond: &'tcx Expr<'tcx>,
+    body: &'tcx Expr<'tcx>,
+) -> Option<BoundCheck<'tcx>> {
+    if let ExprKind::Binary(op, left, right) = cond.kind {
+        let is_less = match op.node {
+            BinOpKind::Lt | BinOpKind::Le => true,
+            BinOpKind::Gt | BinOpKind::Ge => false,
+            _ => return None,
+        };
+        let body = remove_blocks(body);
+        let mut eq = SpanlessEq::new(cx).ignore_fn();
+        if eq.eq_expr(right, body) {
+            return Some(BoundCheck {
+                input: left,
+                bound: right,
+                is_lower: is_less,
+            });
+        } else if eq.eq_expr(left, body) {
+            return Some(BoundCheck {
+                input: right,
+                bound: left,
+                is_lower: !is_less,
+            });
+        }
+    }
+    None
+}
+
+/// Combines a check of the lower and of the upper bound of the same value, with the value itself
+/// as `default` if it is in the range.
+fn combine<'tcx>(
+    cx: &LateContext<'tcx>,
+    first: &BoundCheck<'tcx>,
+    second: &BoundCheck<'tcx>,
+    default: &'tcx Expr<'tcx>,
+) -> Option<Clamp<'tcx>> {
+    let (lower, upper) = match (first.is_lower, second.is_lower) {
+        (true, false) => (first.bound, second.bound),
+        (false, true) => (second.bound, first.bound),
+        _ => return None,
+    };
+    let mut eq = SpanlessEq::new(cx).ignore_fn();
+    if eq.eq_expr(first.input, second.input) && eq.eq_expr(first.input, remove_blocks(default)) {
+        Some(Clamp {
+            input: first.input,
+            lower,
+            upper,
+        })
+    } else {
+        None
+    }
+}
+
+fn lint(cx: &LateContext<'_>, expr: &Expr<'_>, clamp: &Clamp<'_>) {
+    let typeck_results = cx.typeck_results();
+    let ty = typeck_results.expr_ty(clamp.input);
+    if typeck_results.expr_ty(clamp.lower) != ty || typeck_results.expr_ty(clamp.upper) != ty {
+        return;
+    }
+    let is_float = ty.is_floating_point();
+    let clamp_usable = if is_float {
+        has_usable_inherent_method(cx, ty, "clamp")
+    } else {
+        get_trait_def_id(cx, &paths::ORD)
+            .map_or(false, |ord| implements_trait(cx, ty, ord, &[]) && has_usable_assoc_fn(cx, ord, "clamp"))
+    };
+    if !clamp_usable {
+        return;
+    }
+    let bounds_ordered = match (
+        constant(cx, typeck_results, clamp.lower),
+        constant(cx, typeck_results, clamp.upper),
+    ) {
+        (Some((lower, _)), Some((upper, _))) => match Constant::partial_cmp(cx.tcx, ty, &lower, &upper) {
+            Some(Ordering::Less | Ordering::Equal) => true,
+            // `clamp` would always panic
+            _ => return,
+        },
+        _ => false,
+    };
+
+    let mut applicability = if bounds_ordered && !is_float {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+    let input = Sugg::hir_with_applicability(cx, clamp.input, "..", &mut applicability);
+    let lower = Sugg::hir_with_applicability(cx, clamp.lower, "..", &mut applicability);
+    let upper = Sugg::hir_with_applicability(cx, clamp.upper, "..", &mut applicability);
+    span_lint_and_then(
+        cx,
+        MANUAL_CLAMP,
+        expr.span,
+        "clamp-like pattern without using `clamp`",
+        |diag| {
+            diag.span_suggestion(
+                expr.span,
+                "replace with clamp",
+                format!("{}.clamp({}, {})", input.maybe_par(), lower, upper),
+                applicability,
+            );
+            match (is_float, bounds_ordered) {
+                (true, true) => {
+                    diag.note("`clamp` returns NaN if the input is NaN");
+                },
+                (true, false) => {
+                    diag.note(
+                        "`clamp` panics if a bound is NaN or the lower bound is greater than the upper bound, \
+                         and returns NaN if the input is NaN",
+                    );
+                },
+                (false, false) => {
+                    diag.note("`clamp` panics if the lower bound is greater than the upper bound");
+                },
+                (false, true) => {},
+            }
+        },
+    );
+}
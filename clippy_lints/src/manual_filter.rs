@@ -0,0 +1,181 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::usage::contains_return_break_continue_macro;
+use crate::utils::{
+    higher, in_constant, in_macro, is_copy, is_type_diagnostic_item, match_qpath, match_var, paths, remove_blocks,
+    snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, BindingAnnotation, BorrowKind, Expr, ExprKind, Guard, MatchSource, Mutability, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::Ident;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `match` expressions and `and_then` calls which keep the value
+    /// of an `Option` if it satisfies a condition and evaluate to `None` otherwise.
+    ///
+    /// **Why is this bad?** `Option::filter` expresses the same more concisely.
+    ///
+    /// **Known problems:** `filter` passes the value to the predicate by reference. The suggestion
+    /// is therefore only machine applicable if the value is `Copy` or only passed by reference
+    /// to a function.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let port: Option<u16> = None;
+    /// let port = match port {
+    ///     Some(port) if port > 1024 => Some(port),
+    ///     _ => None,
+    /// };
+    /// let port = port.and_then(|port| if port != 8080 { Some(port) } else { None });
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let port: Option<u16> = None;
+    /// let port = port.filter(|&port| port > 1024);
+    /// let port = port.filter(|&port| port != 8080);
+    /// ```
+    pub MANUAL_FILTER,
+    complexity,
+    "reimplementing `Option::filter` with a `match` or `and_then`"
+}
+
+declare_lint_pass!(ManualFilter => [MANUAL_FILTER]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualFilter {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if in_macro(expr.span) || in_constant(cx, expr.hir_id) {
+            return;
+        }
+        match expr.kind {
+            // `match o { Some(x) if cond => Some(x), _ => None }`
+            ExprKind::Match(scrutinee, [first, second], MatchSource::Normal) => {
+                let (some_arm, none_arm) = if is_none_arm(first) {
+                    (second, first)
+                } else {
+                    (first, second)
+                };
+                if_chain! {
+                    if is_none_arm(none_arm) && none_arm.guard.is_none();
+                    if is_none_expr(remove_blocks(none_arm.body));
+                    if let Some(Guard::If(cond)) = some_arm.guard;
+                    if let PatKind::TupleStruct(ref path, [inner_pat], _) = some_arm.pat.kind;
+                    if match_qpath(path, &paths::OPTION_SOME);
+                    if let Some(binding) = binding_ident(inner_pat);
+                    if is_some_of(remove_blocks(some_arm.body), binding);
+                    then {
+                        lint(cx, expr, scrutinee, inner_pat, binding, cond);
+                    }
+                }
+            },
+            // `o.and_then(|x| if cond { Some(x) } else { None })`
+            ExprKind::MethodCall(path, _, [recv, closure], _) if path.ident.name == sym!(and_then) => {
+                if_chain! {
+                    if let ExprKind::Closure(_, _, body_id, _, _) = closure.kind;
+                    let body = cx.tcx.hir().body(body_id);
+                    if let [param] = body.params;
+                    if let Some(binding) = binding_ident(param.pat);
+                    if let Some((cond, then, Some(els))) = higher::if_block(remove_blocks(&body.value));
+                    if is_some_of(remove_blocks(then), binding);
+                    if is_none_expr(remove_blocks(els));
+                    then {
+                        lint(cx, expr, recv, param.pat, binding, cond);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, pat: &Pat<'_>, binding: Ident, cond: &Expr<'_>) {
+    if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym!(option_type))
+        || contains_return_break_continue_macro(cond)
+    {
+        return;
+    }
+    let mut applicability = Applicability::MachineApplicable;
+    let predicate = if let Some(func) = fn_shorthand(cx, binding, cond) {
+        snippet_with_applicability(cx, func.span, "..", &mut applicability).into_owned()
+    } else {
+        // the predicate gets a reference to the value, which can be dereferenced in the
+        // closure parameter only for `Copy` types
+        let param = if is_copy(cx, cx.typeck_results().pat_ty(pat)) {
+            "&"
+        } else {
+            applicability = Applicability::MaybeIncorrect;
+            ""
+        };
+        format!(
+            "|{}{}| {}",
+            param,
+            binding,
+            snippet_with_applicability(cx, cond.span, "..", &mut applicability)
+        )
+    };
+    let recv = Sugg::hir_with_applicability(cx, recv, "..", &mut applicability);
+    span_lint_and_sugg(
+        cx,
+        MANUAL_FILTER,
+        expr.span,
+        "manual implementation of `Option::filter`",
+        "try this",
+        format!("{}.filter({})", recv.maybe_par(), predicate),
+        applicability,
+    );
+}
+
+/// Returns the function `f` if the condition is `f(&x)`, such that the closure `|x| f(x)` can be
+/// replaced by `f`.
+fn fn_shorthand<'a>(cx: &LateContext<'_>, binding: Ident, cond: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    if_chain! {
+        if let ExprKind::Call(func, [arg]) = cond.kind;
+        if let ExprKind::Path(_) = func.kind;
+        if let ty::FnDef(..) = cx.typeck_results().expr_ty(func).kind;
+        if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, referent) = arg.kind;
+        if match_var(referent, binding.name);
+        if cx.typeck_results().expr_adjustments(arg).is_empty();
+        then {
+            Some(func)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the identifier of a pattern binding the whole value by move.
+fn binding_ident(pat: &Pat<'_>) -> Option<Ident> {
+    match pat.kind {
+        PatKind::Binding(BindingAnnotation::Unannotated, _, ident, None) => Some(ident),
+        _ => None,
+    }
+}
+
+/// Checks for `Some(x)` with the given binding `x`.
+fn is_some_of(expr: &Expr<'_>, binding: Ident) -> bool {
+    if_chain! {
+        if let ExprKind::Call(some_expr, [value]) = expr.kind;
+        if let ExprKind::Path(ref some_path) = some_expr.kind;
+        if match_qpath(some_path, &paths::OPTION_SOME);
+        then {
+            match_var(value, binding.name)
+        } else {
+            false
+        }
+    }
+}
+
+/// Checks for a `None` or `_` pattern.
+fn is_none_arm(arm: &Arm<'_>) -> bool {
+    match arm.pat.kind {
+        PatKind::Path(ref path) => match_qpath(path, &paths::OPTION_NONE),
+        PatKind::Wild => true,
+        _ => false,
+    }
+}
+
+fn is_none_expr(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Path(ref path) if match_qpath(path, &paths::OPTION_NONE))
+}
@@ -0,0 +1,211 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{higher, in_constant, in_macro, is_direct_expn_of, meets_msrv, span_lint_and_sugg, SpanlessEq};
+use if_chain::if_chain;
+use rustc_ast::ast::{LitKind, RangeLimits, UintTy};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, Pat, PatKind, RangeEnd};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::source_map::Span;
+use semver::Version;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for manual checks whether a `char` or `u8` lies in one of the
+    /// common ASCII ranges, like `c >= 'a' && c <= 'z'`, `('0'..='9').contains(&c)` or
+    /// `matches!(b, b'a'..=b'z' | b'A'..=b'Z')`.
+    ///
+    /// **Why is this bad?** The `is_ascii_*` methods of `char` and `u8` are easier to read and
+    /// rule out typos in the bounds.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let c = 'x';
+    /// # let b = b'7';
+    /// let is_lower = c >= 'a' && c <= 'z';
+    /// let is_digit = (b'0'..=b'9').contains(&b);
+    /// let is_alphabetic = matches!(c, 'a'..='z' | 'A'..='Z');
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let c = 'x';
+    /// # let b = b'7';
+    /// let is_lower = c.is_ascii_lowercase();
+    /// let is_digit = b.is_ascii_digit();
+    /// let is_alphabetic = c.is_ascii_alphabetic();
+    /// ```
+    pub MANUAL_IS_ASCII_CHECK,
+    style,
+    "checking whether a character lies in an ASCII range manually instead of using `is_ascii_*`"
+}
+
+const MANUAL_IS_ASCII_CHECK_MSRV: Version = Version {
+    major: 1,
+    minor: 24,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+const MANUAL_IS_ASCII_CHECK_CONST_MSRV: Version = Version {
+    major: 1,
+    minor: 47,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+/// The sorted ranges each `is_ascii_*` method checks for.
+const ASCII_CLASSES: [(&[(char, char)], &str); 6] = [
+    (&[('a', 'z')], "is_ascii_lowercase"),
+    (&[('A', 'Z')], "is_ascii_uppercase"),
+    (&[('0', '9')], "is_ascii_digit"),
+    (&[('A', 'Z'), ('a', 'z')], "is_ascii_alphabetic"),
+    (&[('0', '9'), ('A', 'Z'), ('a', 'z')], "is_ascii_alphanumeric"),
+    (&[('0', '9'), ('A', 'F'), ('a', 'f')], "is_ascii_hexdigit"),
+];
+
+pub struct ManualIsAsciiCheck {
+    msrv: Option<Version>,
+}
+
+impl ManualIsAsciiCheck {
+    #[must_use]
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualIsAsciiCheck => [MANUAL_IS_ASCII_CHECK]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualIsAsciiCheck {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !meets_msrv(self.msrv.as_ref(), &MANUAL_IS_ASCII_CHECK_MSRV) {
+            return;
+        }
+        // the `is_ascii_*` methods are only `const` since 1.47
+        if in_constant(cx, expr.hir_id) && !meets_msrv(self.msrv.as_ref(), &MANUAL_IS_ASCII_CHECK_CONST_MSRV) {
+            return;
+        }
+
+        if let Some(macro_call) = is_direct_expn_of(expr.span, "matches") {
+            if_chain! {
+                if let ExprKind::Match(recv, [arm, _], _) = expr.kind;
+                if arm.guard.is_none();
+                if let Some(ranges) = pat_ranges(arm.pat);
+                then {
+                    check_ranges(cx, macro_call, recv, ranges);
+                }
+            }
+            return;
+        }
+        if in_macro(expr.span) {
+            return;
+        }
+
+        match expr.kind {
+            // `('a'..='z').contains(&c)`
+            ExprKind::MethodCall(path, _, [range_expr, arg], _) if path.ident.name == sym!(contains) => {
+                if_chain! {
+                    if let Some(higher::Range {
+                        start: Some(start),
+                        end: Some(end),
+                        limits: RangeLimits::Closed,
+                    }) = higher::range(cx, range_expr);
+                    if let (Some(lower), Some(upper)) = (lit_char(start), lit_char(end));
+                    if let ExprKind::AddrOf(BorrowKind::Ref, _, recv) = arg.kind;
+                    then {
+                        check_ranges(cx, expr.span, recv, vec![(lower, upper)]);
+                    }
+                }
+            },
+            // `c >= 'a' && c <= 'z'`, for `u8` this is linted by `manual_range_contains`
+            ExprKind::Binary(op, left, right) if op.node == BinOpKind::And => {
+                if_chain! {
+                    if let Some((left_value, left_bound, left_is_lower)) = char_bound(left);
+                    if let Some((right_value, right_bound, right_is_lower)) = char_bound(right);
+                    if left_is_lower != right_is_lower;
+                    if SpanlessEq::new(cx).ignore_fn().eq_expr(left_value, right_value);
+                    if let ty::Char = cx.typeck_results().expr_ty(left_value).kind;
+                    then {
+                        let range = if left_is_lower {
+                            (left_bound, right_bound)
+                        } else {
+                            (right_bound, left_bound)
+                        };
+                        check_ranges(cx, expr.span, left_value, vec![range]);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+fn check_ranges(cx: &LateContext<'_>, span: Span, recv: &Expr<'_>, mut ranges: Vec<(char, char)>) {
+    match cx.typeck_results().expr_ty(recv).kind {
+        ty::Char | ty::Uint(UintTy::U8) => {},
+        _ => return,
+    }
+    ranges.sort_unstable();
+    if let Some((_, method)) = ASCII_CLASSES.iter().find(|(class, _)| **class == *ranges) {
+        let mut applicability = Applicability::MachineApplicable;
+        let recv = Sugg::hir_with_applicability(cx, recv, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            MANUAL_IS_ASCII_CHECK,
+            span,
+            "manual check for a common ASCII range",
+            "try",
+            format!("{}.{}()", recv.maybe_par(), method),
+            applicability,
+        );
+    }
+}
+
+/// Collects the ranges of a pattern like `'a'..='z' | 'A'..='Z'`.
+fn pat_ranges(pat: &Pat<'_>) -> Option<Vec<(char, char)>> {
+    match pat.kind {
+        PatKind::Range(Some(start), Some(end), RangeEnd::Included) => Some(vec![(lit_char(start)?, lit_char(end)?)]),
+        PatKind::Or(pats) => pats.iter().try_fold(Vec::new(), |mut ranges, pat| {
+            ranges.extend(pat_ranges(pat)?);
+            Some(ranges)
+        }),
+        _ => None,
+    }
+}
+
+/// Checks for comparisons like `c >= 'a'` or `'z' >= c`. Returns the compared value, the bound
+/// and whether it is the lower bound.
+fn char_bound<'a>(expr: &'a Expr<'a>) -> Option<(&'a Expr<'a>, char, bool)> {
+    if let ExprKind::Binary(op, left, right) = expr.kind {
+        let left_is_lower = match op.node {
+            BinOpKind::Le => true,
+            BinOpKind::Ge => false,
+            _ => return None,
+        };
+        if let Some(bound) = lit_char(right) {
+            return Some((left, bound, !left_is_lower));
+        } else if let Some(bound) = lit_char(left) {
+            return Some((right, bound, left_is_lower));
+        }
+    }
+    None
+}
+
+/// Returns the value of a `char` or byte literal.
+fn lit_char(expr: &Expr<'_>) -> Option<char> {
+    if let ExprKind::Lit(ref lit) = expr.kind {
+        match lit.node {
+            LitKind::Char(c) => Some(c),
+            LitKind::Byte(b) => Some(char::from(b)),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
@@ -0,0 +1,196 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::usage::contains_return_break_continue_macro;
+use crate::utils::{
+    in_constant, in_macro, is_else_clause, is_refutable, is_type_diagnostic_item, match_qpath, match_var, paths,
+    remove_blocks, snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, BindingAnnotation, BorrowKind, Expr, ExprKind, MatchSource, Mutability, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `match` and `if let` expressions which map the value inside
+    /// an `Option` and evaluate to `None` otherwise.
+    ///
+    /// **Why is this bad?** `Option::map` expresses the same more concisely.
+    ///
+    /// **Known problems:** The suggestion is only machine applicable if the mapped expression
+    /// consists of paths, literals, operators and calls, since moving code into a closure can
+    /// cause borrow check errors otherwise.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let name: Option<&str> = None;
+    /// let len = match name {
+    ///     Some(name) => Some(name.len()),
+    ///     None => None,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let name: Option<&str> = None;
+    /// let len = name.map(|name| name.len());
+    /// ```
+    pub MANUAL_MAP,
+    style,
+    "reimplementing `Option::map` with a `match` or `if let`"
+}
+
+declare_lint_pass!(ManualMap => [MANUAL_MAP]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualMap {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if in_macro(expr.span) || in_constant(cx, expr.hir_id) || is_else_clause(cx, expr) {
+            return;
+        }
+        let (scrutinee, some_arm, none_arm) = match expr.kind {
+            ExprKind::Match(scrutinee, [first, second], MatchSource::Normal) => {
+                if is_none_arm(first) {
+                    (scrutinee, second, first)
+                } else {
+                    (scrutinee, first, second)
+                }
+            },
+            ExprKind::Match(scrutinee, [then, els], MatchSource::IfLetDesugar { .. }) => (scrutinee, then, els),
+            _ => return,
+        };
+        if_chain! {
+            if some_arm.guard.is_none() && none_arm.guard.is_none();
+            if is_none_arm(none_arm) && is_none_expr(remove_blocks(none_arm.body));
+            if let PatKind::TupleStruct(ref some_path, [inner_pat], _) = some_arm.pat.kind;
+            if match_qpath(some_path, &paths::OPTION_SOME);
+            if let ExprKind::Call(some_expr, [value]) = remove_blocks(some_arm.body).kind;
+            if let ExprKind::Path(ref some_expr_path) = some_expr.kind;
+            if match_qpath(some_expr_path, &paths::OPTION_SOME);
+            if !contains_return_break_continue_macro(value);
+            then {
+                lint(cx, expr, scrutinee, inner_pat, value);
+            }
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, expr: &Expr<'_>, scrutinee: &Expr<'_>, pat: &Pat<'_>, value: &Expr<'_>) {
+    // With a reference as the scrutinee, the bindings are references due to the default binding
+    // modes, which makes `as_ref` or `as_mut` necessary.
+    let scrutinee_ty = cx.typeck_results().expr_ty(scrutinee);
+    let (option_ty, scrutinee_ref) = match scrutinee_ty.kind {
+        ty::Ref(_, inner, mutability) => (inner, Some(mutability)),
+        _ => (scrutinee_ty, None),
+    };
+    if !is_type_diagnostic_item(cx, option_ty, sym!(option_type)) {
+        return;
+    }
+    // `match &o { .. }` becomes `o.as_ref().map(..)`
+    let scrutinee = match scrutinee.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, referent) if scrutinee_ref.is_some() => referent,
+        _ => scrutinee,
+    };
+
+    let mut applicability = if is_simple_expr(value) {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+    let (param, binding_ref) = match pat.kind {
+        PatKind::Binding(annotation, _, ident, None) => {
+            // `Some(x) => Some(x)` doesn't map anything and `Some(ref x) => Some(x)` is linted by
+            // `match_as_ref`
+            if match_var(value, ident.name) {
+                return;
+            }
+            match annotation {
+                BindingAnnotation::Ref => (ident.to_string(), Some(Mutability::Not)),
+                BindingAnnotation::RefMut => (ident.to_string(), Some(Mutability::Mut)),
+                _ => (
+                    snippet_with_applicability(cx, pat.span, "..", &mut applicability).into_owned(),
+                    None,
+                ),
+            }
+        },
+        // nested patterns need to be irrefutable for the closure parameter, and they can't bind
+        // by reference without binding the whole value by reference
+        _ if !is_refutable(cx, pat) && pat.contains_explicit_ref_binding().is_none() => (
+            snippet_with_applicability(cx, pat.span, "..", &mut applicability).into_owned(),
+            None,
+        ),
+        _ => return,
+    };
+    let as_ref = match (scrutinee_ref, binding_ref) {
+        (Some(_), Some(_)) => return,
+        (Some(Mutability::Not), None) | (None, Some(Mutability::Not)) => ".as_ref()",
+        (Some(Mutability::Mut), None) | (None, Some(Mutability::Mut)) => ".as_mut()",
+        (None, None) => "",
+    };
+
+    let scrutinee_snippet = Sugg::hir_with_applicability(cx, scrutinee, "..", &mut applicability);
+    let func = match (as_ref, fn_shorthand(cx, pat, value)) {
+        ("", Some(func)) => snippet_with_applicability(cx, func.span, "..", &mut applicability).into_owned(),
+        _ => format!(
+            "|{}| {}",
+            param,
+            snippet_with_applicability(cx, value.span, "..", &mut applicability)
+        ),
+    };
+    span_lint_and_sugg(
+        cx,
+        MANUAL_MAP,
+        expr.span,
+        "manual implementation of `Option::map`",
+        "try this",
+        format!("{}{}.map({})", scrutinee_snippet.maybe_par(), as_ref, func),
+        applicability,
+    );
+}
+
+/// Returns the function `f` if the value is `f(x)` with the binding `x` as the only argument,
+/// such that the closure `|x| f(x)` can be replaced by `f`.
+fn fn_shorthand<'a>(cx: &LateContext<'_>, pat: &Pat<'_>, value: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    if_chain! {
+        if let PatKind::Binding(BindingAnnotation::Unannotated, _, ident, None) = pat.kind;
+        if let ExprKind::Call(func, [arg]) = value.kind;
+        if let ExprKind::Path(_) = func.kind;
+        if let ty::FnDef(..) = cx.typeck_results().expr_ty(func).kind;
+        if match_var(arg, ident.name);
+        // the argument mustn't be coerced, e.g. from `&String` to `&str`
+        if cx.typeck_results().expr_adjustments(arg).is_empty();
+        then {
+            Some(func)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks whether the expression only consists of paths, literals, operators and calls.
+fn is_simple_expr(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Path(_) | ExprKind::Lit(_) => true,
+        ExprKind::Field(inner, _)
+        | ExprKind::Unary(_, inner)
+        | ExprKind::Cast(inner, _)
+        | ExprKind::AddrOf(_, _, inner) => is_simple_expr(inner),
+        ExprKind::Binary(_, left, right) => is_simple_expr(left) && is_simple_expr(right),
+        ExprKind::Call(func, args) => is_simple_expr(func) && args.iter().all(is_simple_expr),
+        ExprKind::MethodCall(_, _, args, _) | ExprKind::Tup(args) | ExprKind::Array(args) => {
+            args.iter().all(is_simple_expr)
+        },
+        _ => false,
+    }
+}
+
+/// Checks for a `None` or `_` pattern.
+fn is_none_arm(arm: &Arm<'_>) -> bool {
+    match arm.pat.kind {
+        PatKind::Path(ref path) => match_qpath(path, &paths::OPTION_NONE),
+        PatKind::Wild => true,
+        _ => false,
+    }
+}
+
+fn is_none_expr(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Path(ref path) if match_qpath(path, &paths::OPTION_NONE))
+}
@@ -0,0 +1,132 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::usage::contains_return_break_continue_macro;
+use crate::utils::{
+    in_constant, in_macro, is_cheap_expr, is_else_clause, is_type_diagnostic_item, match_qpath, match_var, paths,
+    remove_blocks, snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, BindingAnnotation, Expr, ExprKind, MatchSource, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `match` and `if let` expressions which turn an `Option` into
+    /// a `Result`, evaluating to `Ok(x)` for `Some(x)` and to `Err(e)` for `None`.
+    ///
+    /// **Why is this bad?** `Option::ok_or` and `Option::ok_or_else` express the same more
+    /// concisely.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let port: Option<u16> = None;
+    /// let port: Result<u16, &str> = match port {
+    ///     Some(port) => Ok(port),
+    ///     None => Err("no port given"),
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let port: Option<u16> = None;
+    /// let port: Result<u16, &str> = port.ok_or("no port given");
+    /// ```
+    pub MANUAL_OK_OR,
+    pedantic,
+    "reimplementing `Option::ok_or` or `Option::ok_or_else` with a `match` or `if let`"
+}
+
+declare_lint_pass!(ManualOkOr => [MANUAL_OK_OR]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualOkOr {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if in_macro(expr.span) || in_constant(cx, expr.hir_id) || is_else_clause(cx, expr) {
+            return;
+        }
+        let (scrutinee, arms) = match expr.kind {
+            ExprKind::Match(scrutinee, [first, second], MatchSource::Normal) => (scrutinee, [first, second]),
+            ExprKind::Match(
+                scrutinee,
+                [then, els],
+                MatchSource::IfLetDesugar {
+                    contains_else_clause: true,
+                },
+            ) => (scrutinee, [then, els]),
+            _ => return,
+        };
+        let (ok_arm, err) = if let Some(err) = err_arm_value(arms[1]) {
+            (arms[0], err)
+        } else if let Some(err) = err_arm_value(arms[0]) {
+            (arms[1], err)
+        } else {
+            return;
+        };
+        if_chain! {
+            if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(scrutinee), sym!(option_type));
+            if arms.iter().all(|arm| arm.guard.is_none());
+            if is_ok_arm(cx, ok_arm);
+            if !contains_return_break_continue_macro(err);
+            // `ok_or` would infer the error type from the argument instead of coercing it
+            if cx.typeck_results().expr_adjustments(err).is_empty();
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let err_snippet = snippet_with_applicability(cx, err.span, "..", &mut applicability);
+                let (method, arg) = if is_cheap_expr(err) {
+                    ("ok_or", err_snippet.into_owned())
+                } else {
+                    ("ok_or_else", format!("|| {}", err_snippet))
+                };
+                let scrutinee = Sugg::hir_with_applicability(cx, scrutinee, "..", &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_OK_OR,
+                    expr.span,
+                    &format!("this pattern reimplements `Option::{}`", method),
+                    "replace with",
+                    format!("{}.{}({})", scrutinee.maybe_par(), method, arg),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// Checks for an arm like `Some(x) => Ok(x)`.
+fn is_ok_arm(cx: &LateContext<'_>, arm: &Arm<'_>) -> bool {
+    if_chain! {
+        if let PatKind::TupleStruct(ref path, [inner], _) = arm.pat.kind;
+        if match_qpath(path, &paths::OPTION_SOME);
+        if let PatKind::Binding(BindingAnnotation::Unannotated, _, ident, None) = inner.kind;
+        if let ExprKind::Call(ok_expr, [value]) = remove_blocks(arm.body).kind;
+        if let ExprKind::Path(ref ok_path) = ok_expr.kind;
+        if match_qpath(ok_path, &paths::RESULT_OK);
+        if match_var(value, ident.name);
+        if cx.typeck_results().expr_adjustments(value).is_empty();
+        then {
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns the error of an arm like `None => Err(e)` or `_ => Err(e)`.
+fn err_arm_value<'tcx>(arm: &'tcx Arm<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let is_none_pat = match arm.pat.kind {
+        PatKind::Path(ref path) => match_qpath(path, &paths::OPTION_NONE),
+        PatKind::Wild => true,
+        _ => false,
+    };
+    if_chain! {
+        if is_none_pat;
+        if let ExprKind::Call(err_expr, [err]) = remove_blocks(arm.body).kind;
+        if let ExprKind::Path(ref err_path) = err_expr.kind;
+        if match_qpath(err_path, &paths::RESULT_ERR);
+        then {
+            Some(err)
+        } else {
+            None
+        }
+    }
+}
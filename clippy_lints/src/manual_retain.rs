@@ -0,0 +1,245 @@
+use crate::utils::{
+    has_usable_inherent_method, in_macro, is_type_diagnostic_item, match_trait_method, match_type, meets_msrv, paths,
+    snippet_with_applicability, span_lint_and_sugg, SpanlessEq,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{CaptureBy, Expr, ExprKind, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use semver::Version;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for code to be replaced by `.retain()`, i.e. collections which
+    /// are filtered into a new collection that is assigned back to them.
+    ///
+    /// **Why is this bad?** `.retain()` is simpler and removes the elements in place, without
+    /// allocating a new collection.
+    ///
+    /// **Known problems:** For maps, the value is passed to the predicate of `retain` as a
+    /// mutable reference, so the suggestion might need some adjustments.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut vec = vec![0, 1, 2];
+    /// vec = vec.iter().copied().filter(|x| x % 2 == 0).collect();
+    /// vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut vec = vec![0, 1, 2];
+    /// vec.retain(|x| x % 2 == 0);
+    /// ```
+    pub MANUAL_RETAIN,
+    perf,
+    "filtering a collection into a new one that replaces it, instead of using `retain()`"
+}
+
+const BTREE_RETAIN_MSRV: Version = Version {
+    major: 1,
+    minor: 53,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+pub struct ManualRetain {
+    msrv: Option<Version>,
+}
+
+impl ManualRetain {
+    #[must_use]
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualRetain => [MANUAL_RETAIN]);
+
+/// The ways the filtered iterator is created from the collection.
+#[derive(Clone, Copy, PartialEq)]
+enum IterKind {
+    /// `v.into_iter()`
+    IntoIter,
+    /// `v.iter().cloned()` or `v.iter().copied()`
+    Cloned,
+    /// `s.chars()`
+    Chars,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualRetain {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Assign(target, value, _) = expr.kind;
+            if let ExprKind::MethodCall(collect_path, _, [filter_expr], _) = value.kind;
+            if collect_path.ident.name == sym!(collect);
+            if match_trait_method(cx, value, &paths::ITERATOR);
+            if let ExprKind::MethodCall(filter_path, _, [iter_expr, pred], _) = filter_expr.kind;
+            if filter_path.ident.name == sym!(filter);
+            if let Some((iter_kind, collection)) = iter_source(iter_expr);
+            if SpanlessEq::new(cx).eq_expr(target, collection);
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let pred = match self.retain_kind(cx, target, iter_kind) {
+                    Some(RetainKind::Elements) => {
+                        snippet_with_applicability(cx, pred.span, "..", &mut applicability).into_owned()
+                    },
+                    Some(RetainKind::Map) => match map_predicate(cx, pred, &mut applicability) {
+                        Some(pred) => pred,
+                        None => return,
+                    },
+                    Some(RetainKind::Chars) => match chars_predicate(cx, pred, &mut applicability) {
+                        Some(pred) => pred,
+                        None => return,
+                    },
+                    None => return,
+                };
+
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_RETAIN,
+                    expr.span,
+                    "this expression can be written more simply using `.retain()`",
+                    "consider calling `.retain()` instead",
+                    format!(
+                        "{}.retain({})",
+                        snippet_with_applicability(cx, target.span, "..", &mut applicability),
+                        pred
+                    ),
+                    applicability,
+                );
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// The signatures of the predicates of the different `retain` methods.
+enum RetainKind {
+    /// `Vec`, `VecDeque` and the sets, whose predicates take the elements by reference, like
+    /// `filter`
+    Elements,
+    /// The maps, whose predicates take the key and the value as separate arguments
+    Map,
+    /// `String`, whose predicate takes a `char` by value
+    Chars,
+}
+
+impl ManualRetain {
+    fn retain_kind(&self, cx: &LateContext<'_>, collection: &Expr<'_>, iter_kind: IterKind) -> Option<RetainKind> {
+        let ty = cx.typeck_results().expr_ty(collection);
+        // the standard library in use may predate the stabilization of the `BTree*::retain` methods
+        let btree_retain =
+            meets_msrv(self.msrv.as_ref(), &BTREE_RETAIN_MSRV) && has_usable_inherent_method(cx, ty, "retain");
+
+        if iter_kind == IterKind::Chars {
+            return if is_type_diagnostic_item(cx, ty, sym!(string_type)) {
+                Some(RetainKind::Chars)
+            } else {
+                None
+            };
+        }
+
+        if is_type_diagnostic_item(cx, ty, sym!(vec_type))
+            || match_type(cx, ty, &paths::VEC_DEQUE)
+            || is_type_diagnostic_item(cx, ty, sym!(hashset_type))
+            || (btree_retain && match_type(cx, ty, &paths::BTREESET))
+        {
+            Some(RetainKind::Elements)
+        } else if iter_kind == IterKind::IntoIter
+            && (is_type_diagnostic_item(cx, ty, sym!(hashmap_type))
+                || (btree_retain && match_type(cx, ty, &paths::BTREEMAP)))
+        {
+            Some(RetainKind::Map)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns how the iterator is created and the collection it is created from.
+fn iter_source<'tcx>(iter_expr: &'tcx Expr<'tcx>) -> Option<(IterKind, &'tcx Expr<'tcx>)> {
+    match iter_expr.kind {
+        ExprKind::MethodCall(path, _, [collection], _) if path.ident.name == sym!(into_iter) => {
+            Some((IterKind::IntoIter, collection))
+        },
+        ExprKind::MethodCall(path, _, [collection], _) if path.ident.name == sym!(chars) => {
+            Some((IterKind::Chars, collection))
+        },
+        ExprKind::MethodCall(path, _, [iter], _)
+            if path.ident.name == sym!(cloned) || path.ident.name == sym!(copied) =>
+        {
+            match iter.kind {
+                ExprKind::MethodCall(path, _, [collection], _) if path.ident.name == sym!(iter) => {
+                    Some((IterKind::Cloned, collection))
+                },
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Turns a `filter` closure on the items of a map like `|(k, v)| ..` into a `retain` closure
+/// like `|k, v| ..`. A `move` closure stays a `move` closure.
+fn map_predicate(cx: &LateContext<'_>, pred: &Expr<'_>, applicability: &mut Applicability) -> Option<String> {
+    if_chain! {
+        if let ExprKind::Closure(capture, _, body_id, _, _) = pred.kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params;
+        then {
+            let (key_pat, value_pat, by_value) = match param.pat.kind {
+                PatKind::Tuple([key_pat, value_pat], None) => (key_pat, value_pat, false),
+                PatKind::Ref(inner, _) => match inner.kind {
+                    PatKind::Tuple([key_pat, value_pat], None) => (key_pat, value_pat, true),
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            // `retain` passes the value as `&mut V` instead of `&V`
+            if !by_value {
+                *applicability = Applicability::MaybeIncorrect;
+            }
+            let key = snippet_with_applicability(cx, key_pat.span, "..", applicability);
+            let value = snippet_with_applicability(cx, value_pat.span, "..", applicability);
+            let body = snippet_with_applicability(cx, body.value.span, "..", applicability);
+            return Some(if by_value {
+                format!("{}|&{}, &mut {}| {}", capture_prefix(capture), key, value, body)
+            } else {
+                format!("{}|{}, {}| {}", capture_prefix(capture), key, value, body)
+            });
+        }
+    }
+
+    None
+}
+
+/// Turns a `filter` closure on `&char`s like `|&c| ..` into a `retain` closure on `char`s
+/// like `|c| ..`. A `move` closure stays a `move` closure.
+fn chars_predicate(cx: &LateContext<'_>, pred: &Expr<'_>, applicability: &mut Applicability) -> Option<String> {
+    if_chain! {
+        if let ExprKind::Closure(capture, _, body_id, _, _) = pred.kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params;
+        if let PatKind::Ref(char_pat, _) = param.pat.kind;
+        then {
+            return Some(format!(
+                "{}|{}| {}",
+                capture_prefix(capture),
+                snippet_with_applicability(cx, char_pat.span, "..", applicability),
+                snippet_with_applicability(cx, body.value.span, "..", applicability),
+            ));
+        }
+    }
+
+    None
+}
+
+fn capture_prefix(capture: CaptureBy) -> &'static str {
+    match capture {
+        CaptureBy::Value => "move ",
+        CaptureBy::Ref => "",
+    }
+}
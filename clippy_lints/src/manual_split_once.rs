@@ -0,0 +1,256 @@
+use crate::utils::{
+    has_usable_inherent_method, in_macro, is_integer_const, match_qpath, meets_msrv, paths, qpath_res,
+    snippet_with_applicability, span_lint_and_sugg, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, HirId, Local, MatchSource, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use semver::Version;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `splitn(2, _)` and `rsplitn(2, _)` calls on strings whose
+    /// parts are taken by calling `next` twice or with `nth(1)`.
+    ///
+    /// **Why is this bad?** `split_once` and `rsplit_once` return both parts at once and don't
+    /// need an intermediate iterator.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let mut parts = "key=value".splitn(2, '=');
+    /// let key = parts.next()?;
+    /// let value = parts.next()?;
+    /// let value = "key=value".splitn(2, '=').nth(1);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let (key, value) = "key=value".split_once('=')?;
+    /// let value = "key=value".split_once('=').map(|(_, value)| value);
+    /// ```
+    pub MANUAL_SPLIT_ONCE,
+    complexity,
+    "replace `s.splitn(2, pat)` with `s.split_once(pat)`"
+}
+
+const MANUAL_SPLIT_ONCE_MSRV: Version = Version {
+    major: 1,
+    minor: 52,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+pub struct ManualSplitOnce {
+    msrv: Option<Version>,
+}
+
+impl ManualSplitOnce {
+    #[must_use]
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualSplitOnce => [MANUAL_SPLIT_ONCE]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualSplitOnce {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !meets_msrv(self.msrv.as_ref(), &MANUAL_SPLIT_ONCE_MSRV) || in_macro(expr.span) {
+            return;
+        }
+        // `s.splitn(2, pat).nth(1)`
+        if_chain! {
+            if let ExprKind::MethodCall(path, _, [splitn, index], _) = expr.kind;
+            if path.ident.as_str() == "nth" && is_integer_const(cx, index, 1);
+            if let Some(split) = SplitN::from_expr(cx, splitn);
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                // the second part of `splitn` is the tail, the second part of `rsplitn` the head
+                let part = if split.reverse { "(part, _)" } else { "(_, part)" };
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_SPLIT_ONCE,
+                    expr.span,
+                    &format!("manual implementation of `{}`", split.method()),
+                    "try",
+                    format!("{}.map(|{}| part)", split.sugg(cx, &mut applicability), part),
+                    applicability,
+                );
+            }
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if !meets_msrv(self.msrv.as_ref(), &MANUAL_SPLIT_ONCE_MSRV) {
+            return;
+        }
+        // `let mut iter = s.splitn(2, pat); let a = iter.next()?; let b = iter.next()?;`
+        for (i, stmts) in block.stmts.windows(3).enumerate() {
+            if_chain! {
+                if let StmtKind::Local(iter_local) = stmts[0].kind;
+                if let PatKind::Binding(BindingAnnotation::Mutable, iter_id, _, None) = iter_local.pat.kind;
+                if let Some(init) = iter_local.init;
+                if !in_macro(stmts[0].span) && !in_macro(stmts[2].span);
+                if let Some(split) = SplitN::from_expr(cx, init);
+                if let Some((first, _)) = next_part(cx, &stmts[1], iter_id);
+                if let Some((second, operator)) = next_part(cx, &stmts[2], iter_id);
+                if !is_used_after(cx, iter_id, &block.stmts[i + 3..], block.expr);
+                then {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let first = snippet_with_applicability(cx, first.pat.span, "..", &mut applicability);
+                    let second = snippet_with_applicability(cx, second.pat.span, "..", &mut applicability);
+                    let (head, tail) = if split.reverse { (second, first) } else { (first, second) };
+                    span_lint_and_sugg(
+                        cx,
+                        MANUAL_SPLIT_ONCE,
+                        stmts[0].span.to(stmts[2].span),
+                        &format!("manual implementation of `{}`", split.method()),
+                        "try",
+                        format!(
+                            "let ({}, {}) = {}{};",
+                            head,
+                            tail,
+                            split.sugg(cx, &mut applicability),
+                            operator
+                        ),
+                        applicability,
+                    );
+                }
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// A `s.splitn(2, pat)` or `s.rsplitn(2, pat)` call on a string.
+struct SplitN<'tcx> {
+    recv: &'tcx Expr<'tcx>,
+    pat: &'tcx Expr<'tcx>,
+    reverse: bool,
+}
+
+impl<'tcx> SplitN<'tcx> {
+    fn from_expr(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<Self> {
+        if_chain! {
+            if let ExprKind::MethodCall(path, _, [recv, n, pat], _) = expr.kind;
+            let reverse = match &*path.ident.as_str() {
+                "splitn" => false,
+                "rsplitn" => true,
+                _ => return None,
+            };
+            if is_integer_const(cx, n, 2);
+            if walk_ptrs_ty(cx.typeck_results().expr_ty_adjusted(recv)).is_str();
+            let split = Self { recv, pat, reverse };
+            // the MSRV alone doesn't tell whether the standard library has a stable `split_once`
+            if has_usable_inherent_method(cx, cx.tcx.types.str_, split.method());
+            then {
+                Some(split)
+            } else {
+                None
+            }
+        }
+    }
+
+    fn method(&self) -> &'static str {
+        if self.reverse {
+            "rsplit_once"
+        } else {
+            "split_once"
+        }
+    }
+
+    fn sugg(&self, cx: &LateContext<'_>, applicability: &mut Applicability) -> String {
+        format!(
+            "{}.{}({})",
+            snippet_with_applicability(cx, self.recv.span, "..", applicability),
+            self.method(),
+            snippet_with_applicability(cx, self.pat.span, "..", applicability),
+        )
+    }
+}
+
+/// Checks whether `stmt` is `let x = iter.next()?;` or `let x = iter.next().unwrap();` and returns
+/// the `let` statement and the way the `Option` is unwrapped.
+fn next_part<'tcx>(
+    cx: &LateContext<'_>,
+    stmt: &'tcx Stmt<'tcx>,
+    iter_id: HirId,
+) -> Option<(&'tcx Local<'tcx>, &'static str)> {
+    if_chain! {
+        if let StmtKind::Local(local) = stmt.kind;
+        if local.ty.is_none();
+        if let Some(init) = local.init;
+        then {
+            let (next, operator) = match init.kind {
+                ExprKind::MethodCall(path, _, [next], _) if path.ident.as_str() == "unwrap" => (next, ".unwrap()"),
+                // `x?` is desugared to `match Try::into_result(x) { .. }`
+                ExprKind::Match(match_arg, _, MatchSource::TryDesugar) => match match_arg.kind {
+                    ExprKind::Call(func, [next]) => match func.kind {
+                        ExprKind::Path(ref func_path) if match_qpath(func_path, &paths::TRY_INTO_RESULT) => (next, "?"),
+                        _ => return None,
+                    },
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            if_chain! {
+                if let ExprKind::MethodCall(path, _, [iter], _) = next.kind;
+                if path.ident.as_str() == "next";
+                if let ExprKind::Path(ref iter_path) = iter.kind;
+                if qpath_res(cx, iter_path, iter.hir_id) == Res::Local(iter_id);
+                then {
+                    return Some((local, operator));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Checks whether the iterator is used in the statements and the expression following the
+/// `let` statements.
+fn is_used_after<'tcx>(
+    cx: &LateContext<'tcx>,
+    iter_id: HirId,
+    stmts: &'tcx [Stmt<'tcx>],
+    expr: Option<&'tcx Expr<'tcx>>,
+) -> bool {
+    let mut visitor = LocalUseVisitor { cx, iter_id, used: false };
+    for stmt in stmts {
+        visitor.visit_stmt(stmt);
+    }
+    if let Some(expr) = expr {
+        visitor.visit_expr(expr);
+    }
+    visitor.used
+}
+
+struct LocalUseVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    iter_id: HirId,
+    used: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LocalUseVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Path(ref qpath) = expr.kind {
+            if qpath_res(self.cx, qpath, expr.hir_id) == Res::Local(self.iter_id) {
+                self.used = true;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
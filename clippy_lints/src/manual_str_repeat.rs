@@ -0,0 +1,161 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    higher, in_macro, is_integer_const, is_type_diagnostic_item, match_trait_method, paths, remove_blocks,
+    snippet_with_applicability, span_lint_and_then, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc_ast::ast::{LitIntType, LitKind, RangeLimits, UintTy};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for strings built by repeating another string or a `char` a
+    /// number of times, either by collecting `(0..n).map(|_| s)` into a `String` or by pushing
+    /// it in a `for _ in 0..n` loop.
+    ///
+    /// **Why is this bad?** `str::repeat` is shorter and allocates the resulting string with
+    /// the right capacity at once instead of growing it step by step.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s: String = (0..3).map(|_| "ab").collect();
+    /// let mut t = String::new();
+    /// for _ in 0..3 {
+    ///     t.push_str("ab");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s: String = "ab".repeat(3);
+    /// let mut t = String::new();
+    /// t.push_str(&"ab".repeat(3));
+    /// ```
+    pub MANUAL_STR_REPEAT,
+    perf,
+    "manually repeating a string instead of using `str::repeat`"
+}
+
+declare_lint_pass!(ManualStrRepeat => [MANUAL_STR_REPEAT]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualStrRepeat {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_macro(expr.span) {
+            return;
+        }
+        // `(0..n).map(|_| s).collect::<String>()`
+        if_chain! {
+            if let ExprKind::MethodCall(path, _, [map], _) = expr.kind;
+            if path.ident.as_str() == "collect";
+            if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym!(string_type));
+            if let ExprKind::MethodCall(map_path, _, [range, closure], _) = map.kind;
+            if map_path.ident.as_str() == "map" && match_trait_method(cx, map, &paths::ITERATOR);
+            if let Some(count) = range_count(cx, range);
+            if let ExprKind::Closure(_, _, body_id, _, _) = closure.kind;
+            let body = cx.tcx.hir().body(body_id);
+            if let [param] = body.params;
+            if let PatKind::Wild = param.pat.kind;
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                if let Some(repeated) = repeat_sugg(cx, remove_blocks(&body.value), count, &mut applicability) {
+                    lint(cx, expr, repeated, applicability);
+                }
+            }
+        }
+
+        // `for _ in 0..n { buf.push_str(s); }`
+        if_chain! {
+            if let Some((pat, arg, body)) = higher::for_loop(expr);
+            if let PatKind::Wild = pat.kind;
+            if let Some(count) = range_count(cx, arg);
+            if let ExprKind::Block(block, _) = body.kind;
+            if let Some(push) = match (block.stmts, block.expr) {
+                ([stmt], None) => match stmt.kind {
+                    StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                    _ => None,
+                },
+                ([], Some(e)) => Some(e),
+                _ => None,
+            };
+            if let ExprKind::MethodCall(push_path, _, [buf, pushed], _) = push.kind;
+            if push_path.ident.as_str() == "push_str" || push_path.ident.as_str() == "push";
+            if is_type_diagnostic_item(cx, walk_ptrs_ty(cx.typeck_results().expr_ty(buf)), sym!(string_type));
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                if let Some(repeated) = repeat_sugg(cx, pushed, count, &mut applicability) {
+                    let buf = Sugg::hir_with_applicability(cx, buf, "..", &mut applicability);
+                    lint(cx, expr, format!("{}.push_str(&{});", buf.maybe_par(), repeated), applicability);
+                }
+            }
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, expr: &Expr<'_>, sugg: String, applicability: Applicability) {
+    span_lint_and_then(
+        cx,
+        MANUAL_STR_REPEAT,
+        expr.span,
+        "manual implementation of `str::repeat`",
+        |diag| {
+            diag.span_suggestion(expr.span, "try", sugg, applicability);
+            diag.note("`repeat` allocates the whole string at once instead of growing it repeatedly");
+        },
+    );
+}
+
+/// Returns the suggestion for `n` if `range` is `0..n`.
+fn range_count(cx: &LateContext<'_>, range: &Expr<'_>) -> Option<String> {
+    if_chain! {
+        if let Some(higher::Range {
+            start: Some(start),
+            end: Some(end),
+            limits: RangeLimits::HalfOpen,
+        }) = higher::range(cx, range);
+        if is_integer_const(cx, start, 0);
+        // `repeat` takes a `usize`, an unsuffixed literal will be inferred as such
+        if cx.typeck_results().expr_ty(end).kind == ty::Uint(UintTy::Usize) || is_unsuffixed_int(end);
+        then {
+            Some(Sugg::hir(cx, end, "..").to_string())
+        } else {
+            None
+        }
+    }
+}
+
+fn is_unsuffixed_int(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Int(_, LitIntType::Unsuffixed)))
+}
+
+/// Returns the suggestion to repeat `repeated` `count` times, if it's a string or a `char`
+/// which evaluates to the same value every time.
+fn repeat_sugg(
+    cx: &LateContext<'_>,
+    repeated: &Expr<'_>,
+    count: String,
+    applicability: &mut Applicability,
+) -> Option<String> {
+    let ty = walk_ptrs_ty(cx.typeck_results().expr_ty(repeated));
+    let is_char = ty.kind == ty::Char;
+    if !(is_char || ty.is_str() || is_type_diagnostic_item(cx, ty, sym!(string_type))) {
+        return None;
+    }
+    let snippet = match repeated.kind {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Char(c) => format!("{:?}", c.to_string()),
+            _ => snippet_with_applicability(cx, repeated.span, "..", applicability).into_owned(),
+        },
+        // a plain path can't have side effects or yield different values
+        ExprKind::Path(_) if is_char => format!(
+            "{}.to_string()",
+            snippet_with_applicability(cx, repeated.span, "..", applicability)
+        ),
+        ExprKind::Path(_) => snippet_with_applicability(cx, repeated.span, "..", applicability).into_owned(),
+        _ => return None,
+    };
+    Some(format!("{}.repeat({})", snippet, count))
+}
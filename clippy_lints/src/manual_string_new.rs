@@ -0,0 +1,117 @@
+use crate::utils::{
+    is_direct_expn_of, is_type_diagnostic_item, match_def_path, match_function_call, paths, qpath_res,
+    span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for the creation of an empty `String` from an empty string
+    /// literal, like `"".to_string()`, `"".to_owned()`, `String::from("")` or `format!("")`.
+    ///
+    /// **Why is this bad?** `String::new()` is clearer and doesn't need to go through the
+    /// conversion machinery.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let a = "".to_string();
+    /// let b = String::from("");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let a = String::new();
+    /// let b = String::new();
+    /// ```
+    pub MANUAL_STRING_NEW,
+    pedantic,
+    "empty `String` created from an empty string literal instead of `String::new()`"
+}
+
+declare_lint_pass!(ManualStringNew => [MANUAL_STRING_NEW]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualStringNew {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym!(string_type)) {
+            return;
+        }
+
+        let span = if expr.span.from_expansion() {
+            match empty_format_call_span(cx, expr) {
+                Some(span) => span,
+                None => return,
+            }
+        } else {
+            match expr.kind {
+                // `"".to_string()` and `"".to_owned()`
+                ExprKind::MethodCall(path, _, [receiver], _)
+                    if matches!(&*path.ident.as_str(), "to_string" | "to_owned") && is_empty_str_lit(receiver) =>
+                {
+                    expr.span
+                },
+                // `String::from("")`
+                ExprKind::Call(func, [arg]) if is_empty_str_lit(arg) => {
+                    if_chain! {
+                        if let ExprKind::Path(ref qpath) = func.kind;
+                        if let Some(def_id) = qpath_res(cx, qpath, func.hir_id).opt_def_id();
+                        if match_def_path(cx, def_id, &paths::FROM_FROM);
+                        then {
+                            expr.span
+                        } else {
+                            return;
+                        }
+                    }
+                },
+                _ => return,
+            }
+        };
+
+        span_lint_and_sugg(
+            cx,
+            MANUAL_STRING_NEW,
+            span,
+            "empty `String` is being created manually",
+            "consider using",
+            "String::new()".to_string(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+fn is_empty_str_lit(expr: &Expr<'_>) -> bool {
+    if let ExprKind::Lit(ref lit) = expr.kind {
+        if let LitKind::Str(value, _) = lit.node {
+            return value.is_empty();
+        }
+    }
+    false
+}
+
+/// Returns the span of the `format!("")` call if `expr` is the `alloc::fmt::format` call of its
+/// expansion.
+fn empty_format_call_span(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Span> {
+    if_chain! {
+        if let Some(span) = is_direct_expn_of(expr.span, "format");
+        if !span.from_expansion();
+        if let Some([arguments]) = match_function_call(cx, expr, &paths::FMT_FORMAT);
+        // `Arguments::new_v1(&[""], &match () { () => [] })`
+        if let Some([pieces, args]) = match_function_call(cx, arguments, &paths::FMT_ARGUMENTS_NEW_V1);
+        if let ExprKind::AddrOf(_, _, pieces) = pieces.kind;
+        if let ExprKind::Array(pieces) = pieces.kind;
+        if pieces.iter().all(is_empty_str_lit);
+        if let ExprKind::AddrOf(_, _, args) = args.kind;
+        if let ExprKind::Match(scrutinee, _, _) = args.kind;
+        if let ExprKind::Tup([]) = scrutinee.kind;
+        then {
+            Some(span)
+        } else {
+            None
+        }
+    }
+}
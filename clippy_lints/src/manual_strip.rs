@@ -0,0 +1,260 @@
+use crate::consts::{constant, Constant};
+use crate::utils::{
+    higher, in_macro, match_def_path, match_type, meets_msrv, multispan_sugg, paths, qpath_res, snippet,
+    span_lint_and_then, SpanlessEq,
+};
+use if_chain::if_chain;
+use rustc_ast::ast::{LitKind, RangeLimits};
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::source_map::Span;
+use semver::Version;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `if` expressions which test a string for a prefix or suffix
+    /// with `starts_with` or `ends_with` and then slice the prefix or suffix off manually, e.g.
+    /// with `&s[p.len()..]`, or `truncate` a `String` by the length of the suffix.
+    ///
+    /// **Why is this bad?** `strip_prefix` and `strip_suffix` do the test and the slicing at once,
+    /// so the pattern and the length can't get out of sync.
+    ///
+    /// **Known problems:** The lint doesn't check whether the string is modified before it is
+    /// sliced.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let s = "hello, world!";
+    /// if s.starts_with("hello, ") {
+    ///     assert_eq!(s["hello, ".len()..].to_uppercase(), "WORLD!");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = "hello, world!";
+    /// if let Some(end) = s.strip_prefix("hello, ") {
+    ///     assert_eq!(end.to_uppercase(), "WORLD!");
+    /// }
+    /// ```
+    pub MANUAL_STRIP,
+    complexity,
+    "slicing off a tested prefix or suffix manually instead of using `strip_prefix` or `strip_suffix`"
+}
+
+const MANUAL_STRIP_MSRV: Version = Version {
+    major: 1,
+    minor: 45,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+pub struct ManualStrip {
+    msrv: Option<Version>,
+}
+
+impl ManualStrip {
+    #[must_use]
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ManualStrip => [MANUAL_STRIP]);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum StripKind {
+    Prefix,
+    Suffix,
+}
+
+impl StripKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Prefix => "prefix",
+            Self::Suffix => "suffix",
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualStrip {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !meets_msrv(self.msrv.as_ref(), &MANUAL_STRIP_MSRV) || in_macro(expr.span) {
+            return;
+        }
+        if_chain! {
+            if let Some((cond, then, _)) = higher::if_block(expr);
+            if let ExprKind::MethodCall(_, _, [target_arg, pattern], _) = cond.kind;
+            if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(cond.hir_id);
+            if let ExprKind::Path(ref target_path) = target_arg.kind;
+            then {
+                let strip_kind = if match_def_path(cx, method_def_id, &paths::STR_STARTS_WITH) {
+                    StripKind::Prefix
+                } else if match_def_path(cx, method_def_id, &paths::STR_ENDS_WITH) {
+                    StripKind::Suffix
+                } else {
+                    return;
+                };
+                let target_res = qpath_res(cx, target_path, target_arg.hir_id);
+                if target_res == Res::Err {
+                    return;
+                }
+
+                let mut finder = StrippingFinder {
+                    cx,
+                    strip_kind,
+                    target_res,
+                    pattern,
+                    strippings: Vec::new(),
+                };
+                finder.visit_expr(then);
+                if finder.strippings.is_empty() {
+                    return;
+                }
+
+                let kind_word = strip_kind.as_str();
+                let test_span = expr.span.until(then.span);
+                span_lint_and_then(
+                    cx,
+                    MANUAL_STRIP,
+                    finder.strippings[0].0,
+                    &format!("stripping a {} manually", kind_word),
+                    |diag| {
+                        diag.span_note(test_span, &format!("the {} was tested here", kind_word));
+                        multispan_sugg(
+                            diag,
+                            &format!("try using the `strip_{}` method", kind_word),
+                            vec![(
+                                test_span,
+                                format!(
+                                    "if let Some(<stripped>) = {}.strip_{}({}) ",
+                                    snippet(cx, target_arg.span, ".."),
+                                    kind_word,
+                                    snippet(cx, pattern.span, "..")
+                                ),
+                            )]
+                            .into_iter()
+                            .chain(finder.strippings),
+                        );
+                    },
+                );
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Finds the places in the body of the `if` which strip the tested prefix or suffix off the
+/// target, together with their replacements.
+struct StrippingFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    strip_kind: StripKind,
+    target_res: Res,
+    pattern: &'tcx Expr<'tcx>,
+    strippings: Vec<(Span, String)>,
+}
+
+impl<'a, 'tcx> StrippingFinder<'a, 'tcx> {
+    /// Checks whether `expr` is the target itself.
+    fn is_target(&self, expr: &Expr<'_>) -> bool {
+        if let ExprKind::Path(ref qpath) = expr.kind {
+            qpath_res(self.cx, qpath, expr.hir_id) == self.target_res
+        } else {
+            false
+        }
+    }
+
+    /// Checks whether `expr` evaluates to the length of the pattern, either as `p.len()` or as a
+    /// constant if the pattern is a literal.
+    fn is_pattern_len(&self, expr: &Expr<'_>) -> bool {
+        if let ExprKind::MethodCall(path, _, [arg], _) = expr.kind {
+            let name = path.ident.as_str();
+            if (name == "len" || name == "len_utf8") && SpanlessEq::new(self.cx).eq_expr(arg, self.pattern) {
+                return true;
+            }
+        }
+        let pattern_len = match self.pattern.kind {
+            ExprKind::Lit(ref lit) => match lit.node {
+                LitKind::Str(s, _) => s.as_str().len(),
+                LitKind::Char(c) => c.len_utf8(),
+                _ => return false,
+            },
+            _ => return false,
+        };
+        matches!(
+            constant(self.cx, self.cx.typeck_results(), expr),
+            Some((Constant::Int(n), _)) if n == pattern_len as u128
+        )
+    }
+
+    /// Checks whether `expr` is `s.len() - p.len()`.
+    fn is_len_without_suffix(&self, expr: &Expr<'_>) -> bool {
+        if_chain! {
+            if let ExprKind::Binary(op, left, right) = expr.kind;
+            if op.node == BinOpKind::Sub;
+            if let ExprKind::MethodCall(path, _, [arg], _) = left.kind;
+            if path.ident.as_str() == "len" && self.is_target(arg);
+            then {
+                self.is_pattern_len(right)
+            } else {
+                false
+            }
+        }
+    }
+
+    /// Checks whether `expr` is `s[p.len()..]` or `s[..s.len() - p.len()]`.
+    fn is_stripping(&self, expr: &Expr<'_>) -> bool {
+        if_chain! {
+            if let ExprKind::Index(indexed, index) = expr.kind;
+            if self.is_target(indexed);
+            if let Some(range) = higher::range(self.cx, index);
+            then {
+                match (self.strip_kind, range.start, range.end) {
+                    (StripKind::Prefix, Some(start), None) => self.is_pattern_len(start),
+                    (StripKind::Suffix, None, Some(end)) => {
+                        range.limits == RangeLimits::HalfOpen && self.is_len_without_suffix(end)
+                    },
+                    _ => false,
+                }
+            } else {
+                false
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for StrippingFinder<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, ex: &'tcx Expr<'_>) {
+        match ex.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, arg) if self.is_stripping(arg) => {
+                self.strippings.push((ex.span, "<stripped>".to_string()));
+            },
+            ExprKind::MethodCall(_, _, [recv, args @ ..], _) if self.is_stripping(recv) => {
+                self.strippings.push((recv.span, "<stripped>".to_string()));
+                for arg in args {
+                    self.visit_expr(arg);
+                }
+            },
+            ExprKind::MethodCall(path, _, [recv, arg], _)
+                if self.strip_kind == StripKind::Suffix
+                    && path.ident.as_str() == "truncate"
+                    && self.is_target(recv)
+                    && match_type(self.cx, self.cx.typeck_results().expr_ty(recv), &paths::STRING)
+                    && self.is_len_without_suffix(arg) =>
+            {
+                self.strippings.push((arg.span, "<stripped>.len()".to_string()));
+            },
+            _ => walk_expr(self, ex),
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,160 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    implements_trait, in_macro, match_trait_method, paths, snippet_with_applicability, span_lint_and_sugg, SpanlessEq,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Field};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for struct constructors copying fields one by one from another
+    /// instance of the same struct, where the struct update syntax could be used instead.
+    ///
+    /// **Why is this bad?** `..base` is shorter and makes it obvious which fields actually
+    /// differ from `base`.
+    ///
+    /// **Known problems:** If some of the fields are cloned, the suggested `..base.clone()`
+    /// clones the fields which are set explicitly as well.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # struct Foo { a: i32, b: i32, c: i32 }
+    /// # let base = Foo { a: 1, b: 2, c: 3 };
+    /// let foo = Foo {
+    ///     a: base.a,
+    ///     b: base.b,
+    ///     c: 4,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct Foo { a: i32, b: i32, c: i32 }
+    /// # let base = Foo { a: 1, b: 2, c: 3 };
+    /// let foo = Foo { c: 4, ..base };
+    /// ```
+    pub MANUAL_STRUCT_UPDATE,
+    style,
+    "struct constructor copying fields one by one from another instance of the struct"
+}
+
+declare_lint_pass!(ManualStructUpdate => [MANUAL_STRUCT_UPDATE]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualStructUpdate {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Struct(_, fields, None) = expr.kind;
+            if let Some(first_field) = fields.first();
+            let ty = cx.typeck_results().expr_ty(expr);
+            if let Some(adt_def) = ty.ty_adt_def();
+            if adt_def.is_struct();
+            then {
+                let mut base = None;
+                let mut needs_clone = false;
+                let mut new_fields = Vec::new();
+                for field in fields {
+                    match (copied_field_base(cx, field), base) {
+                        (Some((field_base, cloned)), None) => {
+                            base = Some(field_base);
+                            needs_clone |= cloned;
+                        },
+                        (Some((field_base, cloned)), Some(base)) => {
+                            // fields copied from different bases can't be replaced by `..base`
+                            if !SpanlessEq::new(cx).eq_expr(base, field_base) {
+                                return;
+                            }
+                            needs_clone |= cloned;
+                        },
+                        (None, _) => new_fields.push(field),
+                    }
+                }
+
+                let base = match base {
+                    Some(base) if is_place(base) && !new_fields.is_empty() => base,
+                    _ => return,
+                };
+
+                // field accesses auto-deref the base, the struct update syntax doesn't
+                let base_ty = cx.typeck_results().expr_ty(base);
+                let base_is_ref = match base_ty.kind {
+                    ty::Ref(_, inner_ty, _) if inner_ty == ty => true,
+                    _ if base_ty == ty => false,
+                    _ => return,
+                };
+
+                let mut applicability = Applicability::MachineApplicable;
+                let base_sugg = Sugg::hir_with_applicability(cx, base, "..", &mut applicability);
+                let base_sugg = if needs_clone {
+                    if !cx
+                        .tcx
+                        .lang_items()
+                        .clone_trait()
+                        .map_or(false, |clone_trait| implements_trait(cx, ty, clone_trait, &[]))
+                    {
+                        return;
+                    }
+                    // the fields which are set explicitly are cloned as well
+                    applicability = Applicability::MaybeIncorrect;
+                    format!("{}.clone()", base_sugg.maybe_par())
+                } else if base_is_ref {
+                    base_sugg.deref().to_string()
+                } else {
+                    base_sugg.to_string()
+                };
+
+                let path_snippet = snippet_with_applicability(
+                    cx,
+                    expr.span.until(first_field.span),
+                    "..",
+                    &mut applicability,
+                );
+                let path_snippet = path_snippet.trim_end().trim_end_matches('{').trim_end();
+                let mut fields_snippet = String::new();
+                for field in new_fields {
+                    fields_snippet.push_str(&snippet_with_applicability(cx, field.span, "..", &mut applicability));
+                    fields_snippet.push_str(", ");
+                }
+
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_STRUCT_UPDATE,
+                    expr.span,
+                    "struct constructor copying fields one by one from the same instance",
+                    "use the struct update syntax",
+                    format!("{} {{ {}..{} }}", path_snippet, fields_snippet, base_sugg),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// If the field is initialized like `field: base.field` or `field: base.field.clone()`, returns
+/// `base` and whether the value is cloned.
+fn copied_field_base<'tcx>(cx: &LateContext<'tcx>, field: &'tcx Field<'tcx>) -> Option<(&'tcx Expr<'tcx>, bool)> {
+    let (value, cloned) = match field.expr.kind {
+        ExprKind::MethodCall(path, _, [receiver], _)
+            if path.ident.name == sym!(clone) && match_trait_method(cx, field.expr, &paths::CLONE_TRAIT) =>
+        {
+            (receiver, true)
+        },
+        _ => (field.expr, false),
+    };
+    match value.kind {
+        ExprKind::Field(base, ident) if ident.name == field.ident.name => Some((base, cloned)),
+        _ => None,
+    }
+}
+
+/// Checks whether `expr` is a local or a field of one, which can be evaluated repeatedly
+/// without side effects.
+fn is_place(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Path(_) => true,
+        ExprKind::Field(inner, _) => is_place(inner),
+        _ => false,
+    }
+}
@@ -0,0 +1,179 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::usage::contains_return_break_continue_macro;
+use crate::utils::{
+    get_trait_def_id, implements_trait, in_constant, in_macro, is_cheap_expr, is_else_clause, is_type_diagnostic_item,
+    last_path_segment, match_qpath, match_var, paths, remove_blocks, snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, BindingAnnotation, Expr, ExprKind, MatchSource, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `match` and `if let` expressions which evaluate to the value
+    /// inside an `Option` or `Result` and to a fallback value otherwise.
+    ///
+    /// **Why is this bad?** `unwrap_or`, `unwrap_or_else` and `unwrap_or_default` express the
+    /// same more concisely.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let timeout: Option<u64> = None;
+    /// let timeout = match timeout {
+    ///     Some(timeout) => timeout,
+    ///     None => 30,
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let timeout: Option<u64> = None;
+    /// let timeout = timeout.unwrap_or(30);
+    /// ```
+    pub MANUAL_UNWRAP_OR,
+    complexity,
+    "reimplementing `unwrap_or`, `unwrap_or_else` or `unwrap_or_default` with a `match` or `if let`"
+}
+
+declare_lint_pass!(ManualUnwrapOr => [MANUAL_UNWRAP_OR]);
+
+#[derive(Clone, Copy)]
+enum Case {
+    Option,
+    Result,
+}
+
+impl Case {
+    fn unwrapped_path(self) -> &'static [&'static str] {
+        match self {
+            Self::Option => &paths::OPTION_SOME,
+            Self::Result => &paths::RESULT_OK,
+        }
+    }
+
+    fn type_name(self) -> &'static str {
+        match self {
+            Self::Option => "Option",
+            Self::Result => "Result",
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualUnwrapOr {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if in_macro(expr.span) || in_constant(cx, expr.hir_id) || is_else_clause(cx, expr) {
+            return;
+        }
+        let (scrutinee, arms) = match expr.kind {
+            ExprKind::Match(scrutinee, [first, second], MatchSource::Normal) => (scrutinee, [first, second]),
+            ExprKind::Match(
+                scrutinee,
+                [then, els],
+                MatchSource::IfLetDesugar {
+                    contains_else_clause: true,
+                },
+            ) => (scrutinee, [then, els]),
+            _ => return,
+        };
+        let scrutinee_ty = cx.typeck_results().expr_ty(scrutinee);
+        let case = if is_type_diagnostic_item(cx, scrutinee_ty, sym!(option_type)) {
+            Case::Option
+        } else if is_type_diagnostic_item(cx, scrutinee_ty, sym!(result_type)) {
+            Case::Result
+        } else {
+            return;
+        };
+        let (value, fallback_arm) = if let Some(value) = unwrapping_arm_value(case, arms[0]) {
+            (value, arms[1])
+        } else if let Some(value) = unwrapping_arm_value(case, arms[1]) {
+            (value, arms[0])
+        } else {
+            return;
+        };
+        let fallback = remove_blocks(fallback_arm.body);
+        if_chain! {
+            if arms.iter().all(|arm| arm.guard.is_none());
+            if is_fallback_pat(case, fallback_arm.pat);
+            if !contains_return_break_continue_macro(fallback);
+            // the fallback mustn't need a coercion to the type of the value
+            if cx.typeck_results().expr_ty(fallback) == cx.typeck_results().expr_ty(value);
+            if cx.typeck_results().expr_adjustments(fallback).is_empty();
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let (method, arg) = if is_default_call(cx, fallback) {
+                    ("unwrap_or_default", String::new())
+                } else if is_cheap_expr(fallback) {
+                    (
+                        "unwrap_or",
+                        snippet_with_applicability(cx, fallback.span, "..", &mut applicability).into_owned(),
+                    )
+                } else {
+                    (
+                        "unwrap_or_else",
+                        format!(
+                            "|{}| {}",
+                            if let Case::Result = case { "_" } else { "" },
+                            snippet_with_applicability(cx, fallback.span, "..", &mut applicability)
+                        ),
+                    )
+                };
+                let scrutinee = Sugg::hir_with_applicability(cx, scrutinee, "..", &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_UNWRAP_OR,
+                    expr.span,
+                    &format!("this pattern reimplements `{}::{}`", case.type_name(), method),
+                    "replace with",
+                    format!("{}.{}({})", scrutinee.maybe_par(), method, arg),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the value of an arm like `Some(x) => x` or `Ok(x) => x`.
+fn unwrapping_arm_value<'tcx>(case: Case, arm: &'tcx Arm<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if_chain! {
+        if let PatKind::TupleStruct(ref path, [inner], _) = arm.pat.kind;
+        if match_qpath(path, case.unwrapped_path());
+        if let PatKind::Binding(BindingAnnotation::Unannotated, _, ident, None) = inner.kind;
+        let value = remove_blocks(arm.body);
+        if match_var(value, ident.name);
+        then {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks for `None`, `Err(_)` or `_`.
+fn is_fallback_pat(case: Case, pat: &Pat<'_>) -> bool {
+    match (case, &pat.kind) {
+        (_, PatKind::Wild) => true,
+        (Case::Option, PatKind::Path(path)) => match_qpath(path, &paths::OPTION_NONE),
+        (Case::Result, PatKind::TupleStruct(path, [inner], _)) => {
+            match_qpath(path, &paths::RESULT_ERR) && matches!(inner.kind, PatKind::Wild)
+        },
+        _ => false,
+    }
+}
+
+/// Checks for calls like `Default::default()` or `Vec::new()` which create the default value of
+/// the type, like the `or_fun_call` lint does.
+fn is_default_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if_chain! {
+        if let ExprKind::Call(func, []) = expr.kind;
+        if let ExprKind::Path(ref qpath) = func.kind;
+        if ["default", "new"].contains(&&*last_path_segment(qpath).ident.as_str());
+        if let Some(default_trait_id) = get_trait_def_id(cx, &paths::DEFAULT_TRAIT);
+        then {
+            implements_trait(cx, cx.typeck_results().expr_ty(expr), default_trait_id, &[])
+        } else {
+            false
+        }
+    }
+}
@@ -411,7 +411,7 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// **What it does:** Lint for redundant pattern matching over `Result` or
-    /// `Option`
+    /// `Option`, including with the `matches!` macro
     ///
     /// **Why is this bad?** It's more concise and clear to just use the proper
     /// utility function
@@ -429,6 +429,8 @@ declare_clippy_lint! {
     ///     Ok(_) => true,
     ///     Err(_) => false,
     /// };
+    /// matches!(Some(42), Some(_));
+    /// !matches!(Some(42), None);
     /// ```
     ///
     /// The more idiomatic use would be:
@@ -439,6 +441,8 @@ declare_clippy_lint! {
     /// if None::<()>.is_none() {}
     /// if Some(42).is_some() {}
     /// Ok::<i32, i32>(42).is_ok();
+    /// Some(42).is_some();
+    /// Some(42).is_some();
     /// ```
     pub REDUNDANT_PATTERN_MATCHING,
     style,
@@ -502,6 +506,8 @@ impl_lint_pass!(Matches => [
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // `matches!` is an external macro, but the matched expression and the pattern come from the call
+        redundant_pattern_match::check_matches_macro(cx, expr);
         if in_external_macro(cx.sess(), expr.span) {
             return;
         }
@@ -611,10 +617,9 @@ fn check_single_match(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], exp
             if stmts.len() == 1 && block_expr.is_none() || stmts.is_empty() && block_expr.is_some() {
                 // single statement/expr "else" block, don't lint
                 return;
-            } else {
-                // block with 2+ statements or 1 expr and 1+ statement
-                Some(els)
             }
+            // block with 2+ statements or 1 expr and 1+ statement
+            Some(els)
         } else {
             // not a block, don't lint
             return; 
@@ -804,14 +809,14 @@ fn check_wild_err_arm(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
                     let mut ident_bind_name = String::from("_");
                     if !matching_wild {
                         // Looking for unused bindings (i.e.: `_e`)
-                        inner.iter().for_each(|pat| {
+                        for pat in *inner {
                             if let PatKind::Binding(.., ident, None) = &pat.kind {
                                 if ident.as_str().starts_with('_') && is_unused(ident, arm.body) {
                                     ident_bind_name = (&ident.name.as_str()).to_string();
                                     matching_wild = true;
                                 }
                             }
-                        });
+                        }
                     }
                     if_chain! {
                         if matching_wild;
@@ -931,7 +936,7 @@ fn check_wild_enum_match(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>])
                 "try this",
                 suggestion[0].clone(),
                 Applicability::MaybeIncorrect,
-            )
+            );
         };
 
         span_lint_and_sugg(
@@ -942,7 +947,7 @@ fn check_wild_enum_match(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>])
             "try this",
             suggestion.join(" | "),
             Applicability::MaybeIncorrect,
-        )
+        );
     }
 }
 
@@ -1041,7 +1046,7 @@ fn check_match_as_ref(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], exp
                     cast,
                 ),
                 applicability,
-            )
+            );
         }
     }
 }
@@ -1185,7 +1190,7 @@ fn check_match_single_binding<'a>(cx: &LateContext<'a>, ex: &Expr<'a>, arms: &[A
             } else {
                 // If we are in closure, we need curly braces around suggestion
                 let mut indent = " ".repeat(indent_of(cx, ex.span).unwrap_or(0));
-                let (mut cbrace_start, mut cbrace_end) = ("".to_string(), "".to_string());
+                let (mut cbrace_start, mut cbrace_end) = (String::new(), String::new());
                 if let Some(parent_expr) = get_parent_expr(cx, expr) {
                     if let ExprKind::Closure(..) = parent_expr.kind {
                         cbrace_end = format!("\n{}}}", indent);
@@ -1440,12 +1445,17 @@ where
 
 mod redundant_pattern_match {
     use super::REDUNDANT_PATTERN_MATCHING;
-    use crate::utils::{in_constant, match_qpath, match_trait_method, paths, snippet, span_lint_and_then};
+    use crate::utils::sugg::Sugg;
+    use crate::utils::{
+        get_parent_expr, in_constant, in_macro, is_direct_expn_of, match_qpath, match_trait_method, paths, snippet,
+        span_lint_and_sugg, span_lint_and_then,
+    };
     use if_chain::if_chain;
     use rustc_ast::ast::LitKind;
     use rustc_errors::Applicability;
-    use rustc_hir::{Arm, Expr, ExprKind, HirId, MatchSource, PatKind, QPath};
-    use rustc_lint::LateContext;
+    use rustc_hir::{Arm, Expr, ExprKind, HirId, MatchSource, Pat, PatKind, QPath, UnOp};
+    use rustc_lint::{LateContext, LintContext};
+    use rustc_middle::lint::in_external_macro;
     use rustc_middle::ty;
     use rustc_mir::const_eval::is_const_fn;
     use rustc_span::source_map::Symbol;
@@ -1468,35 +1478,7 @@ mod redundant_pattern_match {
         arms: &[Arm<'_>],
         keyword: &'static str,
     ) {
-        fn find_suggestion(cx: &LateContext<'_>, hir_id: HirId, path: &QPath<'_>) -> Option<&'static str> {
-            if match_qpath(path, &paths::RESULT_OK) && can_suggest(cx, hir_id, sym!(result_type), "is_ok") {
-                return Some("is_ok()");
-            }
-            if match_qpath(path, &paths::RESULT_ERR) && can_suggest(cx, hir_id, sym!(result_type), "is_err") {
-                return Some("is_err()");
-            }
-            if match_qpath(path, &paths::OPTION_SOME) && can_suggest(cx, hir_id, sym!(option_type), "is_some") {
-                return Some("is_some()");
-            }
-            if match_qpath(path, &paths::OPTION_NONE) && can_suggest(cx, hir_id, sym!(option_type), "is_none") {
-                return Some("is_none()");
-            }
-            None
-        }
-
-        let hir_id = expr.hir_id;
-        let good_method = match arms[0].pat.kind {
-            PatKind::TupleStruct(ref path, ref patterns, _) if patterns.len() == 1 => {
-                if let PatKind::Wild = patterns[0].kind {
-                    find_suggestion(cx, hir_id, path)
-                } else {
-                    None
-                }
-            },
-            PatKind::Path(ref path) => find_suggestion(cx, hir_id, path),
-            _ => None,
-        };
-        let good_method = match good_method {
+        let good_method = match find_method_for_pat(cx, expr.hir_id, &arms[0].pat, false) {
             Some(method) => method,
             None => return,
         };
@@ -1520,7 +1502,7 @@ mod redundant_pattern_match {
             cx,
             REDUNDANT_PATTERN_MATCHING,
             arms[0].pat.span,
-            &format!("redundant pattern matching, consider using `{}`", good_method),
+            &format!("redundant pattern matching, consider using `{}()`", good_method),
             |diag| {
                 // while let ... = ... { ... }
                 // ^^^^^^^^^^^^^^^^^^^^^^^^^^^
@@ -1536,13 +1518,85 @@ mod redundant_pattern_match {
                 diag.span_suggestion(
                     span,
                     "try this",
-                    format!("{} {}.{}", keyword, snippet(cx, op_span, "_"), good_method),
+                    format!("{} {}.{}()", keyword, snippet(cx, op_span, "_"), good_method),
                     Applicability::MachineApplicable, // snippet
                 );
             },
         );
     }
 
+    /// Returns the method which checks whether the matched value matches the pattern (or doesn't
+    /// match it if `negated` is `true`), if the pattern is `Some(_)`, `None`, `Ok(_)` or `Err(_)`.
+    fn find_method_for_pat(cx: &LateContext<'_>, hir_id: HirId, pat: &Pat<'_>, negated: bool) -> Option<&'static str> {
+        let path = match pat.kind {
+            PatKind::TupleStruct(ref path, ref patterns, _) if patterns.len() == 1 => {
+                if let PatKind::Wild = patterns[0].kind {
+                    path
+                } else {
+                    return None;
+                }
+            },
+            PatKind::Path(ref path) => path,
+            _ => return None,
+        };
+        let (diag_item, method, negated_method) = if match_qpath(path, &paths::RESULT_OK) {
+            (sym!(result_type), "is_ok", "is_err")
+        } else if match_qpath(path, &paths::RESULT_ERR) {
+            (sym!(result_type), "is_err", "is_ok")
+        } else if match_qpath(path, &paths::OPTION_SOME) {
+            (sym!(option_type), "is_some", "is_none")
+        } else if match_qpath(path, &paths::OPTION_NONE) {
+            (sym!(option_type), "is_none", "is_some")
+        } else {
+            return None;
+        };
+        let method = if negated { negated_method } else { method };
+        if can_suggest(cx, hir_id, diag_item, method) {
+            Some(method)
+        } else {
+            None
+        }
+    }
+
+    /// Checks for `matches!(x, Some(_))` and similar, and their negations.
+    pub fn check_matches_macro<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if let ExprKind::Match(op, arms, MatchSource::Normal) = &expr.kind;
+            if let Some(call_site) = is_direct_expn_of(expr.span, "matches");
+            if !in_external_macro(cx.sess(), call_site);
+            if let [arm, wild_arm] = arms;
+            if arm.guard.is_none();
+            if let PatKind::Wild = wild_arm.pat.kind;
+            then {
+                let negation = get_parent_expr(cx, expr)
+                    .filter(|parent| matches!(parent.kind, ExprKind::Unary(UnOp::UnNot, _)) && !in_macro(parent.span));
+                let (span, negated) = match negation {
+                    Some(parent) => (parent.span, true),
+                    None => (call_site, false),
+                };
+                let good_method = match find_method_for_pat(cx, expr.hir_id, &arm.pat, negated) {
+                    Some(method) => method,
+                    None => return,
+                };
+                let result_expr = match &op.kind {
+                    ExprKind::AddrOf(_, _, borrowed) => borrowed,
+                    _ => op,
+                };
+                let mut applicability = Applicability::MachineApplicable;
+                let result_sugg = Sugg::hir_with_applicability(cx, result_expr, "_", &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    REDUNDANT_PATTERN_MATCHING,
+                    span,
+                    &format!("redundant pattern matching, consider using `{}()`", good_method),
+                    "try this",
+                    format!("{}.{}()", result_sugg.maybe_par(), good_method),
+                    applicability,
+                );
+            }
+        }
+    }
+
     fn find_sugg_for_match<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, op: &Expr<'_>, arms: &[Arm<'_>]) {
         if arms.len() == 2 {
             let node_pair = (&arms[0].pat.kind, &arms[1].pat.kind);
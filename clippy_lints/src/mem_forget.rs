@@ -1,6 +1,7 @@
-use crate::utils::{match_def_path, paths, qpath_res, span_lint};
+use crate::utils::{is_allowed, match_def_path, paths, qpath_res, span_lint, span_lint_and_then};
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 
 declare_clippy_lint! {
@@ -23,7 +24,39 @@ declare_clippy_lint! {
     "`mem::forget` usage on `Drop` types, likely to cause memory leaks"
 }
 
-declare_lint_pass!(MemForget => [MEM_FORGET]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `std::mem::forget(t)` where `t` needs to be
+    /// dropped, i.e. `t` or one of its fields implements `Drop`, like a `MutexGuard`, a `File`
+    /// or a `Vec`.
+    ///
+    /// This lint is not emitted when `mem_forget`, which checks for the types implementing
+    /// `Drop` themselves, is enabled and fires for the same call.
+    ///
+    /// **Why is this bad?** The destructor is never run, so the resources owned by `t` are
+    /// leaked, e.g. the lock of a `MutexGuard` is never released. If this is intended,
+    /// `std::mem::ManuallyDrop` states it more clearly, and allows dropping the value later on.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// let mutex = Mutex::new(0);
+    /// std::mem::forget(mutex.lock().unwrap());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # use std::mem::ManuallyDrop;
+    /// let mutex = Mutex::new(0);
+    /// let guard = ManuallyDrop::new(mutex.lock().unwrap());
+    /// ```
+    pub FORGET_NEEDS_DROP,
+    style,
+    "`mem::forget` usage on values which need to be dropped"
+}
+
+declare_lint_pass!(MemForget => [MEM_FORGET, FORGET_NEEDS_DROP]);
 
 impl<'tcx> LateLintPass<'tcx> for MemForget {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
@@ -33,8 +66,24 @@ impl<'tcx> LateLintPass<'tcx> for MemForget {
                     if match_def_path(cx, def_id, &paths::MEM_FORGET) {
                         let forgot_ty = cx.typeck_results().expr_ty(&args[0]);
 
-                        if forgot_ty.ty_adt_def().map_or(false, |def| def.has_dtor(cx.tcx)) {
+                        if forgot_ty.ty_adt_def().map_or(false, |def| def.has_dtor(cx.tcx))
+                            && !is_allowed(cx, MEM_FORGET, e.hir_id)
+                        {
                             span_lint(cx, MEM_FORGET, e.span, "usage of `mem::forget` on `Drop` type");
+                        } else if needs_drop(cx, forgot_ty) {
+                            span_lint_and_then(
+                                cx,
+                                FORGET_NEEDS_DROP,
+                                e.span,
+                                "usage of `mem::forget` on a value which needs to be dropped",
+                                |diag| {
+                                    diag.note(&format!("the destructor of `{}` is never run", forgot_ty));
+                                    diag.help(
+                                        "if this is intended, consider wrapping the value in `std::mem::ManuallyDrop` \
+                                         instead",
+                                    );
+                                },
+                            );
                         }
                     }
                 }
@@ -42,3 +91,9 @@ impl<'tcx> LateLintPass<'tcx> for MemForget {
         }
     }
 }
+
+/// Checks whether the type needs to be dropped. Generic parameters are not considered to need it,
+/// as they may be instantiated with any type.
+fn needs_drop<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    !matches!(ty.kind, ty::Param(_)) && ty.needs_drop(cx.tcx, cx.param_env)
+}
@@ -0,0 +1,56 @@
+use super::CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS;
+use crate::utils::sugg::Sugg;
+use crate::utils::{is_type_diagnostic_item, span_lint_and_sugg, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::ExprKind;
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+/// Checks for the `CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS` lint. `args` are the receiver and
+/// the pattern of the `ends_with` call.
+pub fn lint(cx: &LateContext<'_>, expr: &hir::Expr<'_>, args: &[hir::Expr<'_>]) {
+    if_chain! {
+        if let [recv, pattern] = args;
+        if let ExprKind::Lit(ref lit) = pattern.kind;
+        if let LitKind::Str(ext_literal, _) = lit.node;
+        let ext_literal = ext_literal.as_str();
+        if let Some(ext) = ext_literal.strip_prefix('.');
+        if looks_like_extension(ext);
+        let recv_ty = cx.typeck_results().expr_ty(recv);
+        let base_ty = walk_ptrs_ty(recv_ty);
+        if base_ty.kind == ty::Str || is_type_diagnostic_item(cx, base_ty, sym!(string_type));
+        then {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let recv_sugg = Sugg::hir_with_applicability(cx, recv, "..", &mut applicability);
+            let path_arg = if let ty::Ref(..) = recv_ty.kind {
+                recv_sugg
+            } else {
+                recv_sugg.addr()
+            };
+            span_lint_and_sugg(
+                cx,
+                CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
+                expr.span,
+                "case-sensitive file extension comparison",
+                "consider comparing the extension case-insensitively",
+                format!(
+                    "std::path::Path::new({}).extension().and_then(|ext| ext.to_str())\
+                     .map_or(false, |ext| ext.eq_ignore_ascii_case(\"{}\"))",
+                    path_arg, ext
+                ),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Checks whether the string is a short alphanumeric file extension without a dot, which
+/// contains at least one letter whose case could differ.
+fn looks_like_extension(ext: &str) -> bool {
+    (1..=5).contains(&ext.len())
+        && ext.bytes().all(|b| b.is_ascii_alphanumeric())
+        && ext.bytes().any(|b| b.is_ascii_alphabetic())
+}
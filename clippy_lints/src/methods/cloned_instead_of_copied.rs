@@ -0,0 +1,59 @@
+use crate::utils::{is_copy, is_type_diagnostic_item, match_trait_method, meets_msrv, paths, span_lint_and_sugg};
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::source_map::Span;
+use semver::Version;
+
+use super::CLONED_INSTEAD_OF_COPIED;
+
+const ITERATOR_COPIED_MSRV: Version = Version {
+    major: 1,
+    minor: 36,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+const OPTION_COPIED_MSRV: Version = Version {
+    major: 1,
+    minor: 35,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+pub(super) fn lint(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    cloned_args: &[hir::Expr<'_>],
+    method_span: Span,
+    msrv: Option<&Version>,
+) {
+    let recv_ty = cx.typeck_results().expr_ty_adjusted(&cloned_args[0]);
+    let lint_msrv = if match_trait_method(cx, expr, &paths::ITERATOR) {
+        &ITERATOR_COPIED_MSRV
+    } else if is_type_diagnostic_item(cx, recv_ty, sym!(option_type)) {
+        &OPTION_COPIED_MSRV
+    } else {
+        return;
+    };
+    if !meets_msrv(msrv, lint_msrv) {
+        return;
+    }
+
+    // both `Iterator::cloned` and `Option::cloned` are generic over the cloned type `T`
+    if let Some(cloned_ty) = cx.typeck_results().node_substs(expr.hir_id).types().last() {
+        if is_copy(cx, cloned_ty) {
+            span_lint_and_sugg(
+                cx,
+                CLONED_INSTEAD_OF_COPIED,
+                method_span,
+                "used `cloned` where `copied` could be used instead",
+                "try",
+                "copied".to_string(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::DANGLING_PTR_FROM_TEMPORARY;
+use crate::utils::{is_type_diagnostic_item, match_type, paths, span_lint_and_then};
+use rustc_hir as hir;
+use rustc_hir::{ExprKind, Node};
+use rustc_lint::LateContext;
+
+/// Checks for the `DANGLING_PTR_FROM_TEMPORARY` lint. `recv` is the receiver of `as_ptr`, or of
+/// `as_bytes` for `.as_bytes().as_ptr()`.
+pub fn lint(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>) {
+    let owner = match owner_name(cx, recv) {
+        Some(owner) => owner,
+        None => return,
+    };
+    if !is_temporary(recv) || !outlives_statement(cx, expr) {
+        return;
+    }
+    // `CString::new(..).unwrap().as_ptr()` is already linted by `TEMPORARY_CSTRING_AS_PTR`
+    if let ExprKind::MethodCall(path, ..) = recv.kind {
+        if owner == "CString" && (path.ident.name == sym!(unwrap) || path.ident.name == sym!(expect)) {
+            return;
+        }
+    }
+    span_lint_and_then(
+        cx,
+        DANGLING_PTR_FROM_TEMPORARY,
+        expr.span,
+        &format!("this pointer outlives the temporary `{}` it points into", owner),
+        |diag| {
+            diag.note(&format!(
+                "the `{}` is dropped at the end of the statement, so the pointer is dangling",
+                owner
+            ));
+            diag.span_help(
+                recv.span,
+                &format!("assign the `{}` to a variable to extend its lifetime", owner),
+            );
+        },
+    );
+}
+
+/// Returns the name of the type if it is a type owning the data `as_ptr` points to.
+fn owner_name(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<&'static str> {
+    let ty = cx.typeck_results().expr_ty(expr);
+    if is_type_diagnostic_item(cx, ty, sym!(string_type)) {
+        Some("String")
+    } else if is_type_diagnostic_item(cx, ty, sym!(vec_type)) {
+        Some("Vec")
+    } else if match_type(cx, ty, &paths::CSTRING) {
+        Some("CString")
+    } else {
+        None
+    }
+}
+
+/// Checks whether the expression creates a value rather than referring to an existing place.
+fn is_temporary(expr: &hir::Expr<'_>) -> bool {
+    !matches!(
+        expr.kind,
+        ExprKind::Path(..) | ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(hir::UnOp::UnDeref, _)
+    )
+}
+
+/// Checks whether the value of the expression is stored or returned, possibly after casting it,
+/// so that it is still around after the statement.
+fn outlives_statement(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    let map = cx.tcx.hir();
+    let mut child = expr.hir_id;
+    loop {
+        match map.find(map.get_parent_node(child)) {
+            Some(Node::Expr(parent)) => match parent.kind {
+                ExprKind::Cast(..) => child = parent.hir_id,
+                ExprKind::Assign(_, rhs, _) => return rhs.hir_id == child,
+                ExprKind::Ret(_) => return true,
+                _ => return false,
+            },
+            Some(Node::Local(_)) => return true,
+            _ => return false,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::FLOAT_PARTIAL_CMP_UNWRAP;
+use crate::utils::{remove_blocks, span_lint_and_help, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc_hir as hir;
+use rustc_hir::ExprKind;
+use rustc_lint::LateContext;
+
+/// Checks for the `FLOAT_PARTIAL_CMP_UNWRAP` lint. `args` are the arguments of the `method` call,
+/// the comparator being the last one.
+pub fn lint(cx: &LateContext<'_>, args: &[hir::Expr<'_>], method: &str) {
+    if_chain! {
+        if let Some(comparator) = args.last();
+        if let ExprKind::Closure(_, _, body_id, _, _) = comparator.kind;
+        let body = cx.tcx.hir().body(body_id);
+        let value = remove_blocks(&body.value);
+        if let ExprKind::MethodCall(unwrap_path, _, unwrap_args, _) = value.kind;
+        if unwrap_path.ident.name == sym!(unwrap) || unwrap_path.ident.name == sym!(expect);
+        if let ExprKind::MethodCall(cmp_path, _, cmp_args, _) = unwrap_args[0].kind;
+        if cmp_path.ident.name == sym!(partial_cmp);
+        if walk_ptrs_ty(cx.typeck_results().expr_ty(&cmp_args[0])).is_floating_point();
+        then {
+            span_lint_and_help(
+                cx,
+                FLOAT_PARTIAL_CMP_UNWRAP,
+                value.span,
+                &format!("this comparator for `{}` panics if one of the compared values is NaN", method),
+                None,
+                "consider defining where NaN values are ordered with `unwrap_or_else`, or filtering them out first",
+            );
+        }
+    }
+}
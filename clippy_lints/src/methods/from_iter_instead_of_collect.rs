@@ -0,0 +1,83 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    get_trait_def_id, implements_trait, match_def_path, paths, qpath_res, snippet_with_applicability,
+    span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_lint::LateContext;
+use rustc_middle::ty::GenericParamDefKind;
+
+use super::FROM_ITER_INSTEAD_OF_COLLECT;
+
+/// lint use of `Type::from_iter(iter)` that can be replaced with `iter.collect::<Type>()`
+pub(super) fn lint<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, func: &hir::Expr<'_>, args: &[hir::Expr<'_>]) {
+    if_chain! {
+        if let [iter_arg] = args;
+        if let hir::ExprKind::Path(ref qpath) = func.kind;
+        if let Res::Def(_, def_id) = qpath_res(cx, qpath, func.hir_id);
+        if match_def_path(cx, def_id, &paths::FROM_ITERATOR_METHOD);
+        // `from_iter` takes an `IntoIterator`, but `collect` needs an `Iterator`
+        if let Some(iter_id) = get_trait_def_id(cx, &paths::ITERATOR);
+        if implements_trait(cx, cx.typeck_results().expr_ty(iter_arg), iter_id, &[]);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let iter = Sugg::hir_with_applicability(cx, iter_arg, "..", &mut applicability).maybe_par();
+            let turbofish = match from_iter_self_ty(qpath) {
+                Some(self_ty) => format!(
+                    "::<{}{}>",
+                    snippet_with_applicability(cx, self_ty.span, "..", &mut applicability),
+                    elided_generic_args(cx, self_ty),
+                ),
+                // `FromIterator::from_iter(iter)` infers its type from the context, just like `collect`
+                None => String::new(),
+            };
+
+            span_lint_and_sugg(
+                cx,
+                FROM_ITER_INSTEAD_OF_COLLECT,
+                expr.span,
+                "usage of `FromIterator::from_iter`",
+                "use `.collect()` instead of `::from_iter()`",
+                format!("{}.collect{}()", iter, turbofish),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Returns the type `from_iter` is called on, e.g. `Vec<_>` in `Vec::<_>::from_iter` or
+/// `<Vec<_> as FromIterator<_>>::from_iter`.
+fn from_iter_self_ty<'a>(qpath: &'a hir::QPath<'a>) -> Option<&'a hir::Ty<'a>> {
+    match qpath {
+        hir::QPath::TypeRelative(self_ty, _) | hir::QPath::Resolved(Some(self_ty), _) => Some(self_ty),
+        _ => None,
+    }
+}
+
+/// Returns placeholders for the generic arguments of a type path written without them, e.g.
+/// `<_, _>` for `HashMap`, since `collect::<HashMap>()` doesn't compile.
+fn elided_generic_args(cx: &LateContext<'_>, ty: &hir::Ty<'_>) -> String {
+    if_chain! {
+        if let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = ty.kind;
+        if let Some(segment) = path.segments.last();
+        if segment.args.is_none();
+        if let Res::Def(_, def_id) = path.res;
+        then {
+            let count = cx
+                .tcx
+                .generics_of(def_id)
+                .params
+                .iter()
+                .filter(|param| matches!(param.kind, GenericParamDefKind::Type { has_default: false, .. }))
+                .count();
+            if count > 0 {
+                return format!("<{}>", vec!["_"; count].join(", "));
+            }
+        }
+    }
+
+    String::new()
+}
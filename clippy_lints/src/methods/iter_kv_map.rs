@@ -0,0 +1,104 @@
+use crate::utils::{
+    has_usable_inherent_method, is_type_diagnostic_item, match_trait_method, match_type, meets_msrv, paths,
+    snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::source_map::Span;
+use semver::Version;
+
+use super::ITER_KV_MAP;
+
+const INTO_KEYS_MSRV: Version = Version {
+    major: 1,
+    minor: 54,
+    patch: 0,
+    pre: Vec::new(),
+    build: Vec::new(),
+};
+
+/// lint use of `map.iter().map(|(k, _)| ..)` and `map.into_iter().map(|(_, v)| ..)` for
+/// `HashMap`s and `BTreeMap`s
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    iter_method: &str,
+    iter_args: &'tcx [hir::Expr<'_>],
+    map_args: &'tcx [hir::Expr<'_>],
+    iter_span: Span,
+    msrv: Option<&Version>,
+) {
+    let recv_ty = cx.typeck_results().expr_ty(&iter_args[0]);
+    let prefix = match (iter_method, &recv_ty.kind) {
+        ("iter", _) => "",
+        // `into_iter` on a reference to a map behaves like `iter`
+        ("into_iter", ty::Ref(..)) => return,
+        // `into_keys` and `into_values` may still be unstable in the standard library in use
+        ("into_iter", _)
+            if meets_msrv(msrv, &INTO_KEYS_MSRV) && has_usable_inherent_method(cx, recv_ty, "into_keys") =>
+        {
+            "into_"
+        },
+        _ => return,
+    };
+    let map_ty = recv_ty.peel_refs();
+    if !is_type_diagnostic_item(cx, map_ty, sym!(hashmap_type)) && !match_type(cx, map_ty, &paths::BTREEMAP) {
+        return;
+    }
+
+    if_chain! {
+        if match_trait_method(cx, expr, &paths::ITERATOR);
+        if let hir::ExprKind::Closure(_, _, body_id, _, _) = map_args[1].kind;
+        let body = cx.tcx.hir().body(body_id);
+        if let [param] = body.params;
+        if let hir::PatKind::Tuple([key_pat, value_pat], None) = param.pat.kind;
+        let (kind, pat) = match (&key_pat.kind, &value_pat.kind) {
+            (_, hir::PatKind::Wild) => ("keys", key_pat),
+            (hir::PatKind::Wild, _) => ("values", value_pat),
+            _ => return,
+        };
+        if let hir::PatKind::Binding(annotation, binding_id, ident, None) = pat.kind;
+        if matches!(
+            annotation,
+            hir::BindingAnnotation::Unannotated | hir::BindingAnnotation::Mutable
+        );
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let sugg = if is_binding(&body.value, binding_id) {
+                format!("{}{}()", prefix, kind)
+            } else {
+                format!(
+                    "{}{}().map(|{}{}| {})",
+                    prefix,
+                    kind,
+                    if annotation == hir::BindingAnnotation::Mutable { "mut " } else { "" },
+                    ident,
+                    snippet_with_applicability(cx, body.value.span, "..", &mut applicability),
+                )
+            };
+
+            span_lint_and_sugg(
+                cx,
+                ITER_KV_MAP,
+                iter_span.with_hi(expr.span.hi()),
+                &format!("iterating on a map's {}", kind),
+                "try",
+                sugg,
+                applicability,
+            );
+        }
+    }
+}
+
+/// Checks whether `expr` is a path to the local with the given `HirId`.
+fn is_binding(expr: &hir::Expr<'_>, binding_id: hir::HirId) -> bool {
+    if let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = expr.kind {
+        path.res == Res::Local(binding_id)
+    } else {
+        false
+    }
+}
@@ -0,0 +1,128 @@
+use crate::utils::{get_enclosing_block, higher, is_type_diagnostic_item, match_type, paths, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::Node;
+use rustc_lint::LateContext;
+use rustc_middle::hir::map::Map;
+use rustc_span::source_map::Span;
+
+use super::ITER_WITH_DRAIN;
+
+/// lint use of `v.drain(..)` on a `Vec` or `VecDeque` which isn't used afterwards
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    drain_args: &'tcx [hir::Expr<'_>],
+    span: Span,
+) {
+    if_chain! {
+        if let [recv, range] = drain_args;
+        if let Some(higher::Range { start: None, end: None, .. }) = higher::range(cx, range);
+        // `drain` on a `&mut Vec` can't be replaced
+        let ty = cx.typeck_results().expr_ty(recv);
+        let ty_name = if is_type_diagnostic_item(cx, ty, sym!(vec_type)) {
+            "Vec"
+        } else if match_type(cx, ty, &paths::VEC_DEQUE) {
+            "VecDeque"
+        } else {
+            return;
+        };
+        // `v.drain(..);` is used to clear the collection
+        if !matches!(
+            cx.tcx.hir().find(cx.tcx.hir().get_parent_node(expr.hir_id)),
+            Some(Node::Stmt(hir::Stmt { kind: hir::StmtKind::Semi(_), .. }))
+        );
+        if is_dead_after(cx, recv, expr);
+        then {
+            span_lint_and_sugg(
+                cx,
+                ITER_WITH_DRAIN,
+                span.with_hi(expr.span.hi()),
+                &format!("`drain(..)` used on a `{}`", ty_name),
+                "try",
+                "into_iter()".to_string(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// Checks whether the drained collection is a temporary, or a local which isn't used after the
+/// `drain` call.
+fn is_dead_after<'tcx>(cx: &LateContext<'tcx>, recv: &hir::Expr<'_>, drain_expr: &hir::Expr<'_>) -> bool {
+    match recv.kind {
+        hir::ExprKind::Path(hir::QPath::Resolved(None, path)) => match path.res {
+            Res::Local(local_id) => is_local_dead_after(cx, local_id, drain_expr),
+            _ => false,
+        },
+        // Places which can't be moved out of, or other paths like statics
+        hir::ExprKind::Field(..) | hir::ExprKind::Index(..) | hir::ExprKind::Unary(..) | hir::ExprKind::Path(..) => {
+            false
+        },
+        _ => true,
+    }
+}
+
+fn is_local_dead_after<'tcx>(cx: &LateContext<'tcx>, local_id: hir::HirId, drain_expr: &hir::Expr<'_>) -> bool {
+    let scope = match get_enclosing_block(cx, local_id) {
+        Some(block) => block,
+        None => return false,
+    };
+
+    // The `drain` call mustn't be executed several times while the local is alive
+    for (parent_id, node) in cx.tcx.hir().parent_iter(drain_expr.hir_id) {
+        if parent_id == scope.hir_id {
+            break;
+        }
+        match node {
+            Node::Expr(hir::Expr {
+                kind: hir::ExprKind::Loop(..) | hir::ExprKind::Closure(..),
+                ..
+            }) => return false,
+            Node::Item(..) | Node::TraitItem(..) | Node::ImplItem(..) => return false,
+            _ => {},
+        }
+    }
+
+    let mut visitor = UsedAfterVisitor {
+        local_id,
+        after: drain_expr.span,
+        used_after: false,
+    };
+    visitor.visit_block(scope);
+    !visitor.used_after
+}
+
+/// Checks whether a local is used after the given span.
+struct UsedAfterVisitor {
+    local_id: hir::HirId,
+    after: Span,
+    used_after: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for UsedAfterVisitor {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if self.used_after {
+            return;
+        }
+        if_chain! {
+            if let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = expr.kind;
+            if path.res == Res::Local(self.local_id);
+            if expr.span.lo() >= self.after.hi();
+            then {
+                self.used_after = true;
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
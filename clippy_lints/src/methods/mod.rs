@@ -1,8 +1,18 @@
 mod bind_instead_of_map;
+mod case_sensitive_file_extension_comparisons;
+mod cloned_instead_of_copied;
+mod dangling_ptr_from_temporary;
+mod float_partial_cmp_unwrap;
+mod from_iter_instead_of_collect;
 mod inefficient_to_string;
+mod iter_kv_map;
+mod iter_with_drain;
 mod manual_saturating_arithmetic;
+mod obfuscated_if_else;
 mod option_map_unwrap_or;
+mod unchecked_integer_abs;
 mod unnecessary_filter_map;
+mod unnecessary_lazy_eval;
 
 use std::borrow::Cow;
 use std::fmt;
@@ -19,9 +29,10 @@ use rustc_middle::hir::map::Map;
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::subst::GenericArgKind;
 use rustc_middle::ty::{self, Ty, TyS};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
 use rustc_span::symbol::{sym, SymbolStr};
+use semver::Version;
 
 use crate::consts::{constant, Constant};
 use crate::utils::usage::mutated_variables;
@@ -267,6 +278,29 @@ declare_clippy_lint! {
     "using `.map(f).unwrap_or(a)` or `.map(f).unwrap_or_else(func)`, which are more succinctly expressed as `map_or(a, f)` or `map_or_else(a, f)`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for usages of `cond.then(|| a).unwrap_or(b)`.
+    ///
+    /// **Why is this bad?** This is a less readable way of writing `if cond { a } else { b }`.
+    /// Also, `b` is evaluated even if `cond` is true, unlike the `else` branch of an `if`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = true;
+    /// let answer = x.then(|| "yes").unwrap_or("no");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = true;
+    /// let answer = if x { "yes" } else { "no" };
+    /// ```
+    pub OBFUSCATED_IF_ELSE,
+    style,
+    "use of `.then(..).unwrap_or(..)`, which can be written more clearly with `if .. else ..`"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `_.map_or(None, _)`.
     ///
@@ -491,6 +525,28 @@ declare_clippy_lint! {
     "call to `flat_map` where `flatten` is sufficient"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `flat_map` with a closure returning an `Option` or
+    /// a `Result`.
+    ///
+    /// **Why is this bad?** `filter_map` states the intent more clearly and doesn't go through
+    /// the `IntoIterator` implementation of `Option`.
+    ///
+    /// **Known problems:** None
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let nums: Vec<i32> = ["1", "2", "whee!"].iter().flat_map(|x| x.parse().ok()).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let nums: Vec<i32> = ["1", "2", "whee!"].iter().filter_map(|x| x.parse().ok()).collect();
+    /// ```
+    pub FLAT_MAP_OPTION,
+    style,
+    "call to `flat_map` where `filter_map` is sufficient"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for usage of `_.find(_).map(_)`.
     ///
@@ -780,6 +836,29 @@ declare_clippy_lint! {
     "using a single-character str where a char could be used, e.g., `_.split(\"x\")`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Warns when using `push_str` with a single-character string literal,
+    /// and `push` with a `char` would work fine.
+    ///
+    /// **Why is this bad?** It's less clear that we are pushing a single character.
+    ///
+    /// **Known problems:** Does not catch multi-byte unicode characters.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut string = String::new();
+    /// string.push_str("\n");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut string = String::new();
+    /// string.push('\n');
+    /// ```
+    pub SINGLE_CHAR_PUSH_STR,
+    style,
+    "`push_str()` used with a single-character string literal as parameter"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for getting the inner pointer of a temporary
     /// `CString`.
@@ -814,6 +893,122 @@ declare_clippy_lint! {
     "getting the inner pointer of a temporary `CString`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for `as_ptr()` calls on temporary `String`s, `Vec`s and
+    /// `CString`s, or on the result of `as_bytes()` on them, where the pointer is stored in a
+    /// variable or returned.
+    ///
+    /// **Why is this bad?** The temporary is dropped at the end of the statement, so the stored
+    /// pointer points to freed memory. Using the pointer afterwards is undefined behavior.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # fn call_some_ffi_func(_: *const u8) {}
+    /// #
+    /// let ptr = String::from("foo").as_bytes().as_ptr();
+    /// unsafe {
+    ///     call_some_ffi_func(ptr);
+    /// }
+    /// ```
+    /// Here `ptr` points to a freed address. The correct use would be:
+    /// ```rust
+    /// # fn call_some_ffi_func(_: *const u8) {}
+    /// #
+    /// let string = String::from("foo");
+    /// unsafe {
+    ///     call_some_ffi_func(string.as_bytes().as_ptr());
+    /// }
+    /// ```
+    pub DANGLING_PTR_FROM_TEMPORARY,
+    correctness,
+    "storing the inner pointer of a temporary `String`, `Vec` or `CString`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for comparators like `|a, b| a.partial_cmp(b).unwrap()` on floats
+    /// passed to `sort_by`, `sort_unstable_by`, `max_by`, `min_by` or `binary_search_by`.
+    ///
+    /// **Why is this bad?** `partial_cmp` returns `None` if one of the values is NaN, so the
+    /// comparator panics as soon as a NaN value is compared.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut values = vec![1.0_f64, 0.5];
+    /// values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// let mut values = vec![1.0_f64, 0.5];
+    /// // sort NaN values to the end
+    /// values.sort_by(|a, b| a.partial_cmp(b).unwrap_or_else(|| a.is_nan().cmp(&b.is_nan())));
+    /// ```
+    pub FLOAT_PARTIAL_CMP_UNWRAP,
+    pedantic,
+    "unwrapping `partial_cmp` on floats in a comparator, which panics on NaN"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `abs()` on signed integers, unless the value is a
+    /// constant or a cast from a narrower integer type.
+    ///
+    /// **Why is this bad?** The absolute value of the minimum value, e.g. `i32::MIN`, doesn't fit
+    /// into the type. `abs()` panics on it in debug builds and returns the minimum value itself in
+    /// release builds, which is still negative.
+    ///
+    /// **Known problems:** Values which can't be the minimum value for other reasons, e.g. because
+    /// they were checked before, are linted as well.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let offset: i32 = -5;
+    /// let distance = offset.abs();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let offset: i32 = -5;
+    /// let distance = offset.checked_abs().unwrap_or(i32::MAX);
+    /// ```
+    pub UNCHECKED_INTEGER_ABS,
+    restriction,
+    "calling `abs()` on a signed integer which may be the minimum value"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `ends_with` on strings with a literal file
+    /// extension like `".json"`.
+    ///
+    /// **Why is this bad?** The comparison is case-sensitive, so files whose extension is written
+    /// in uppercase, e.g. `photo.JPG`, are not matched.
+    ///
+    /// **Known problems:** Strings ending with an extension are not necessarily paths, and
+    /// `Path::extension` returns `None` for file names like `.json` which only consist of the
+    /// extension.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn is_json(file_name: &str) -> bool {
+    ///     file_name.ends_with(".json")
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn is_json(file_name: &str) -> bool {
+    ///     std::path::Path::new(file_name)
+    ///         .extension()
+    ///         .and_then(|ext| ext.to_str())
+    ///         .map_or(false, |ext| ext.eq_ignore_ascii_case("json"))
+    /// }
+    /// ```
+    pub CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
+    pedantic,
+    "checking a file extension with the case-sensitive `ends_with`"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for calling `.step_by(0)` on iterators which panics.
     ///
@@ -973,6 +1168,28 @@ declare_clippy_lint! {
     "using `x.extend(s.chars())` where s is a `&str` or `String`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for usages of `cloned()` on an `Iterator` or `Option` where
+    /// `copied()` could be used instead.
+    ///
+    /// **Why is this bad?** `copied()` is better because it guarantees that the type being cloned
+    /// implements `Copy`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// [1, 2, 3].iter().cloned();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// [1, 2, 3].iter().copied();
+    /// ```
+    pub CLONED_INSTEAD_OF_COPIED,
+    pedantic,
+    "used `cloned` where `copied` could be used instead"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for the use of `.cloned().collect()` on slice to
     /// create a `Vec`.
@@ -1307,7 +1524,139 @@ declare_clippy_lint! {
     "using `.iter().next()` on a sliced array, which can be shortened to just `.get()`"
 }
 
-declare_lint_pass!(Methods => [
+declare_clippy_lint! {
+    /// **What it does:** As the counterpart to `or_fun_call`, this lint looks for unnecessary
+    /// lazily evaluated closures on `Option` and `Result`.
+    ///
+    /// This lint suggests changing the following functions, when eager evaluation results in
+    /// simpler code:
+    ///  - `unwrap_or_else` to `unwrap_or`
+    ///  - `ok_or_else` to `ok_or`
+    ///  - `or_else` to `or`
+    ///  - `get_or_insert_with` to `get_or_insert`
+    ///  - `map_or_else` to `map_or`
+    ///
+    /// **Why is this bad?** Using eager evaluation is shorter and simpler in some cases.
+    ///
+    /// **Known problems:** It is possible, but not recommended for `Deref` and `Index` to have
+    /// side effects. Eagerly evaluating them can change the semantics of the program.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// // example code where clippy issues a warning
+    /// let opt: Option<u32> = None;
+    ///
+    /// opt.unwrap_or_else(|| 42);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let opt: Option<u32> = None;
+    ///
+    /// opt.unwrap_or(42);
+    /// ```
+    pub UNNECESSARY_LAZY_EVALUATIONS,
+    style,
+    "using unnecessary lazy evaluation, which can be replaced with simpler eager evaluation"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `from_iter()` function calls on types that implement the `FromIterator`
+    /// trait.
+    ///
+    /// **Why is this bad?** It is recommended style to use `collect()` instead of calling
+    /// `FromIterator::from_iter()` directly.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use std::iter::FromIterator;
+    ///
+    /// let five_fives = std::iter::repeat(5).take(5);
+    ///
+    /// let v = Vec::from_iter(five_fives);
+    ///
+    /// assert_eq!(v, vec![5, 5, 5, 5, 5]);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let five_fives = std::iter::repeat(5).take(5);
+    ///
+    /// let v = five_fives.collect::<Vec<_>>();
+    ///
+    /// assert_eq!(v, vec![5, 5, 5, 5, 5]);
+    /// ```
+    pub FROM_ITER_INSTEAD_OF_COLLECT,
+    style,
+    "use `.collect()` instead of `::from_iter()`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for iterating over a map with `iter()` or `into_iter()` and
+    /// mapping the items to only their keys or values.
+    ///
+    /// **Why is this bad?** `keys()`, `values()`, `into_keys()` and `into_values()` are more
+    /// readable and express the intent directly.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// let map: HashMap<u32, u32> = HashMap::new();
+    /// let values = map.iter().map(|(_, value)| value).collect::<Vec<_>>();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// let map: HashMap<u32, u32> = HashMap::new();
+    /// let values = map.values().collect::<Vec<_>>();
+    /// ```
+    pub ITER_KV_MAP,
+    style,
+    "iterating on a map and only using its keys or values"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.drain(..)` on a `Vec` or `VecDeque` which is a temporary
+    /// or isn't used afterwards.
+    ///
+    /// **Why is this bad?** `.into_iter()` is simpler, and doesn't need to borrow the collection
+    /// and clear it afterwards.
+    ///
+    /// **Known problems:** The `mut` of the drained variable might become unnecessary.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let mut vec = vec![String::from("foo"), String::from("bar")];
+    /// let strings: Vec<String> = vec.drain(..).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let vec = vec![String::from("foo"), String::from("bar")];
+    /// let strings: Vec<String> = vec.into_iter().collect();
+    /// ```
+    pub ITER_WITH_DRAIN,
+    style,
+    "using `.drain(..)` on a `Vec` or `VecDeque` which could be consumed with `.into_iter()`"
+}
+
+pub struct Methods {
+    msrv: Option<Version>,
+}
+
+impl Methods {
+    #[must_use]
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(Methods => [
     UNWRAP_USED,
     EXPECT_USED,
     SHOULD_IMPLEMENT_TRAIT,
@@ -1315,6 +1664,7 @@ declare_lint_pass!(Methods => [
     WRONG_PUB_SELF_CONVENTION,
     OK_EXPECT,
     MAP_UNWRAP_OR,
+    OBFUSCATED_IF_ELSE,
     RESULT_MAP_OR_INTO_OPTION,
     OPTION_MAP_OR_NONE,
     BIND_INSTEAD_OF_MAP,
@@ -1328,13 +1678,19 @@ declare_lint_pass!(Methods => [
     INEFFICIENT_TO_STRING,
     NEW_RET_NO_SELF,
     SINGLE_CHAR_PATTERN,
+    SINGLE_CHAR_PUSH_STR,
     SEARCH_IS_SOME,
     TEMPORARY_CSTRING_AS_PTR,
+    DANGLING_PTR_FROM_TEMPORARY,
+    FLOAT_PARTIAL_CMP_UNWRAP,
+    UNCHECKED_INTEGER_ABS,
+    CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
     FILTER_NEXT,
     SKIP_WHILE_NEXT,
     FILTER_MAP,
     FILTER_MAP_NEXT,
     FLAT_MAP_IDENTITY,
+    FLAT_MAP_OPTION,
     FIND_MAP,
     MAP_FLATTEN,
     ITERATOR_STEP_BY_ZERO,
@@ -1345,6 +1701,7 @@ declare_lint_pass!(Methods => [
     GET_UNWRAP,
     STRING_EXTEND_CHARS,
     ITER_CLONED_COLLECT,
+    CLONED_INSTEAD_OF_COPIED,
     USELESS_ASREF,
     UNNECESSARY_FOLD,
     UNNECESSARY_FILTER_MAP,
@@ -1355,6 +1712,10 @@ declare_lint_pass!(Methods => [
     ZST_OFFSET,
     FILETYPE_IS_FILE,
     OPTION_AS_REF_DEREF,
+    UNNECESSARY_LAZY_EVALUATIONS,
+    FROM_ITER_INSTEAD_OF_COLLECT,
+    ITER_KV_MAP,
+    ITER_WITH_DRAIN,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Methods {
@@ -1375,6 +1736,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             ["expect", "ok"] => lint_ok_expect(cx, expr, arg_lists[1]),
             ["expect", ..] => lint_expect(cx, expr, arg_lists[0]),
             ["unwrap_or", "map"] => option_map_unwrap_or::lint(cx, expr, arg_lists[1], arg_lists[0], method_spans[1]),
+            ["unwrap_or", "then"] => obfuscated_if_else::lint(cx, expr, arg_lists[1], arg_lists[0]),
             ["unwrap_or_else", "map"] => lint_map_unwrap_or_else(cx, expr, arg_lists[1], arg_lists[0]),
             ["map_or", ..] => lint_map_or_none(cx, expr, arg_lists[0]),
             ["and_then", ..] => {
@@ -1383,7 +1745,12 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             },
             ["or_else", ..] => {
                 bind_instead_of_map::ResultOrElseErrInfo::lint(cx, expr, arg_lists[0]);
+                unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], false, "or");
             },
+            ["unwrap_or_else", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], true, "unwrap_or"),
+            ["ok_or_else", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], true, "ok_or"),
+            ["get_or_insert_with", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], true, "get_or_insert"),
+            ["map_or_else", ..] => unnecessary_lazy_eval::lint(cx, expr, arg_lists[0], true, "map_or"),
             ["next", "filter"] => lint_filter_next(cx, expr, arg_lists[1]),
             ["next", "skip_while"] => lint_skip_while_next(cx, expr, arg_lists[1]),
             ["next", "iter"] => lint_iter_next(cx, expr, arg_lists[1]),
@@ -1393,38 +1760,64 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             ["map", "find"] => lint_find_map(cx, expr, arg_lists[1], arg_lists[0]),
             ["flat_map", "filter"] => lint_filter_flat_map(cx, expr, arg_lists[1], arg_lists[0]),
             ["flat_map", "filter_map"] => lint_filter_map_flat_map(cx, expr, arg_lists[1], arg_lists[0]),
-            ["flat_map", ..] => lint_flat_map_identity(cx, expr, arg_lists[0], method_spans[0]),
+            ["flat_map", ..] => {
+                lint_flat_map_identity(cx, expr, arg_lists[0], method_spans[0]);
+                lint_flat_map_option(cx, expr, arg_lists[0], method_spans[0]);
+            },
             ["flatten", "map"] => lint_map_flatten(cx, expr, arg_lists[1]),
             ["is_some", "find"] => lint_search_is_some(cx, expr, "find", arg_lists[1], arg_lists[0], method_spans[1]),
             ["is_some", "position"] => {
-                lint_search_is_some(cx, expr, "position", arg_lists[1], arg_lists[0], method_spans[1])
+                lint_search_is_some(cx, expr, "position", arg_lists[1], arg_lists[0], method_spans[1]);
             },
             ["is_some", "rposition"] => {
-                lint_search_is_some(cx, expr, "rposition", arg_lists[1], arg_lists[0], method_spans[1])
+                lint_search_is_some(cx, expr, "rposition", arg_lists[1], arg_lists[0], method_spans[1]);
             },
             ["extend", ..] => lint_extend(cx, expr, arg_lists[0]),
-            ["as_ptr", "unwrap" | "expect"] => lint_cstring_as_ptr(cx, expr, &arg_lists[1][0], &arg_lists[0][0]),
+            ["as_ptr", "unwrap" | "expect"] => {
+                lint_cstring_as_ptr(cx, expr, &arg_lists[1][0], &arg_lists[0][0]);
+                dangling_ptr_from_temporary::lint(cx, expr, &arg_lists[0][0]);
+            },
+            ["as_ptr", "as_bytes"] => dangling_ptr_from_temporary::lint(cx, expr, &arg_lists[1][0]),
+            ["as_ptr", ..] => dangling_ptr_from_temporary::lint(cx, expr, &arg_lists[0][0]),
+            [method @ ("sort_by" | "sort_unstable_by" | "max_by" | "min_by" | "binary_search_by"), ..] => {
+                float_partial_cmp_unwrap::lint(cx, arg_lists[0], method);
+            },
+            ["abs"] => unchecked_integer_abs::lint(cx, expr, &arg_lists[0][0]),
+            ["ends_with", ..] => case_sensitive_file_extension_comparisons::lint(cx, expr, arg_lists[0]),
             ["nth", "iter"] => lint_iter_nth(cx, expr, &arg_lists, false),
             ["nth", "iter_mut"] => lint_iter_nth(cx, expr, &arg_lists, true),
             ["nth", ..] => lint_iter_nth_zero(cx, expr, arg_lists[0]),
             ["step_by", ..] => lint_step_by(cx, expr, arg_lists[0]),
             ["next", "skip"] => lint_iter_skip_next(cx, expr, arg_lists[1]),
             ["collect", "cloned"] => lint_iter_cloned_collect(cx, expr, arg_lists[1]),
+            ["cloned", ..] => {
+                cloned_instead_of_copied::lint(cx, expr, arg_lists[0], method_spans[0], self.msrv.as_ref());
+            },
             ["as_ref"] => lint_asref(cx, expr, "as_ref", arg_lists[0]),
             ["as_mut"] => lint_asref(cx, expr, "as_mut", arg_lists[0]),
             ["fold", ..] => lint_unnecessary_fold(cx, expr, arg_lists[0], method_spans[0]),
-            ["filter_map", ..] => unnecessary_filter_map::lint(cx, expr, arg_lists[0]),
+            ["filter_map", ..] => unnecessary_filter_map::lint(cx, expr, arg_lists[0], method_spans[0]),
             ["count", "map"] => lint_suspicious_map(cx, expr),
             ["assume_init"] => lint_maybe_uninit(cx, &arg_lists[0][0], expr),
             ["unwrap_or", arith @ ("checked_add" | "checked_sub" | "checked_mul")] => {
-                manual_saturating_arithmetic::lint(cx, expr, &arg_lists, &arith["checked_".len()..])
+                manual_saturating_arithmetic::lint(cx, expr, &arg_lists, &arith["checked_".len()..]);
             },
             ["add" | "offset" | "sub" | "wrapping_offset" | "wrapping_add" | "wrapping_sub"] => {
-                check_pointer_offset(cx, expr, arg_lists[0])
+                check_pointer_offset(cx, expr, arg_lists[0]);
             },
             ["is_file", ..] => lint_filetype_is_file(cx, expr, arg_lists[0]),
             ["map", "as_ref"] => lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], false),
             ["map", "as_mut"] => lint_option_as_ref_deref(cx, expr, arg_lists[1], arg_lists[0], true),
+            ["drain", ..] => iter_with_drain::lint(cx, expr, arg_lists[0], method_spans[0]),
+            ["map", iter_method @ ("iter" | "into_iter")] => iter_kv_map::lint(
+                cx,
+                expr,
+                iter_method,
+                arg_lists[1],
+                arg_lists[0],
+                method_spans[1],
+                self.msrv.as_ref(),
+            ),
             _ => {},
         }
 
@@ -1441,6 +1834,12 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                 if args.len() == 1 && method_call.ident.name == sym!(to_string) {
                     inefficient_to_string::lint(cx, expr, &args[0], self_ty);
                 }
+                if args.len() == 2
+                    && method_call.ident.name == sym!(push_str)
+                    && is_type_diagnostic_item(cx, walk_ptrs_ty(self_ty), sym!(string_type))
+                {
+                    lint_single_char_push_string(cx, expr, args);
+                }
 
                 match self_ty.kind {
                     ty::Ref(_, ty, _) if ty.kind == ty::Str => {
@@ -1467,6 +1866,7 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                 };
                 lint_binary_expr_with_method_call(cx, &mut info);
             }
+            hir::ExprKind::Call(ref func, ref args) => from_iter_instead_of_collect::lint(cx, expr, func, args),
             _ => (),
         }
     }
@@ -1578,6 +1978,8 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
             }
         }
     }
+
+    extract_msrv_attr!(LateContext);
 }
 
 /// Checks for the `OR_FUN_CALL` lint.
@@ -2225,16 +2627,16 @@ fn lint_unnecessary_fold(cx: &LateContext<'_>, expr: &hir::Expr<'_>, fold_args:
     if let hir::ExprKind::Lit(ref lit) = fold_args[1].kind {
         match lit.node {
             ast::LitKind::Bool(false) => {
-                check_fold_with_op(cx, expr, fold_args, fold_span, hir::BinOpKind::Or, "any", true)
+                check_fold_with_op(cx, expr, fold_args, fold_span, hir::BinOpKind::Or, "any", true);
             },
             ast::LitKind::Bool(true) => {
-                check_fold_with_op(cx, expr, fold_args, fold_span, hir::BinOpKind::And, "all", true)
+                check_fold_with_op(cx, expr, fold_args, fold_span, hir::BinOpKind::And, "all", true);
             },
             ast::LitKind::Int(0, _) => {
-                check_fold_with_op(cx, expr, fold_args, fold_span, hir::BinOpKind::Add, "sum", false)
+                check_fold_with_op(cx, expr, fold_args, fold_span, hir::BinOpKind::Add, "sum", false);
             },
             ast::LitKind::Int(1, _) => {
-                check_fold_with_op(cx, expr, fold_args, fold_span, hir::BinOpKind::Mul, "product", false)
+                check_fold_with_op(cx, expr, fold_args, fold_span, hir::BinOpKind::Mul, "product", false);
             },
             _ => (),
         }
@@ -2920,6 +3322,46 @@ fn lint_flat_map_identity<'tcx>(
     }
 }
 
+/// lint use of `flat_map` for `Iterators` where `filter_map` would be sufficient
+fn lint_flat_map_option<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    flat_map_args: &'tcx [hir::Expr<'_>],
+    flat_map_span: Span,
+) {
+    if !match_trait_method(cx, expr, &paths::ITERATOR) {
+        return;
+    }
+    let arg_ty = cx.typeck_results().expr_ty_adjusted(&flat_map_args[1]);
+    let sig = match arg_ty.kind {
+        ty::Closure(_, substs) => substs.as_closure().sig(),
+        _ if arg_ty.is_fn() => arg_ty.fn_sig(cx.tcx),
+        _ => return,
+    };
+    let ret_ty = cx.tcx.erase_late_bound_regions(&sig.output());
+
+    if is_type_diagnostic_item(cx, ret_ty, sym!(option_type)) {
+        span_lint_and_sugg(
+            cx,
+            FLAT_MAP_OPTION,
+            flat_map_span,
+            "used `flat_map` where `filter_map` could be used instead",
+            "try",
+            "filter_map".to_string(),
+            Applicability::MachineApplicable,
+        );
+    } else if is_type_diagnostic_item(cx, ret_ty, sym!(result_type)) {
+        span_lint_and_help(
+            cx,
+            FLAT_MAP_OPTION,
+            flat_map_span,
+            "used `flat_map` where `filter_map` could be used instead",
+            None,
+            "use `filter_map` and convert the `Result` with `.ok()`",
+        );
+    }
+}
+
 /// lint searching an Iterator followed by `is_some()`
 fn lint_search_is_some<'tcx>(
     cx: &LateContext<'tcx>,
@@ -3111,37 +3553,66 @@ fn lint_chars_last_cmp_with_unwrap<'tcx>(cx: &LateContext<'tcx>, info: &BinaryEx
     }
 }
 
-/// lint for length-1 `str`s for methods in `PATTERN_METHODS`
-fn lint_single_char_pattern<'tcx>(cx: &LateContext<'tcx>, _expr: &'tcx hir::Expr<'_>, arg: &'tcx hir::Expr<'_>) {
+/// Returns the `char` literal equivalent to `arg` if it is a single-character string literal.
+fn get_hint_if_single_char_arg(
+    cx: &LateContext<'_>,
+    arg: &hir::Expr<'_>,
+    applicability: &mut Applicability,
+) -> Option<String> {
     if_chain! {
         if let hir::ExprKind::Lit(lit) = &arg.kind;
         if let ast::LitKind::Str(r, style) = lit.node;
         if r.as_str().len() == 1;
         then {
-            let mut applicability = Applicability::MachineApplicable;
-            let snip = snippet_with_applicability(cx, arg.span, "..", &mut applicability);
-            let ch = if let ast::StrStyle::Raw(nhash) = style {
-                let nhash = nhash as usize;
-                // for raw string: r##"a"##
-                &snip[(nhash + 2)..(snip.len() - 1 - nhash)]
+            let ch = if let ast::StrStyle::Raw(_) = style {
+                // for raw string: r##"a"##, the character isn't escaped yet
+                r.as_str().escape_default().to_string()
             } else {
                 // for regular string: "a"
-                &snip[1..(snip.len() - 1)]
+                let snip = snippet_with_applicability(cx, arg.span, "..", applicability);
+                snip[1..(snip.len() - 1)].to_string()
             };
-            let hint = format!("'{}'", if ch == "'" { "\\'" } else { ch });
-            span_lint_and_sugg(
-                cx,
-                SINGLE_CHAR_PATTERN,
-                arg.span,
-                "single-character string constant used as pattern",
-                "try using a `char` instead",
-                hint,
-                applicability,
-            );
+            Some(format!("'{}'", if ch == "'" { "\\'" } else { ch.as_str() }))
+        } else {
+            None
         }
     }
 }
 
+/// lint for length-1 `str`s for methods in `PATTERN_METHODS`
+fn lint_single_char_pattern<'tcx>(cx: &LateContext<'tcx>, _expr: &'tcx hir::Expr<'_>, arg: &'tcx hir::Expr<'_>) {
+    let mut applicability = Applicability::MachineApplicable;
+    if let Some(hint) = get_hint_if_single_char_arg(cx, arg, &mut applicability) {
+        span_lint_and_sugg(
+            cx,
+            SINGLE_CHAR_PATTERN,
+            arg.span,
+            "single-character string constant used as pattern",
+            "try using a `char` instead",
+            hint,
+            applicability,
+        );
+    }
+}
+
+/// lint for length-1 `str`s as argument for `push_str`
+fn lint_single_char_push_string(cx: &LateContext<'_>, expr: &hir::Expr<'_>, args: &[hir::Expr<'_>]) {
+    let mut applicability = Applicability::MachineApplicable;
+    if let Some(extension_string) = get_hint_if_single_char_arg(cx, &args[1], &mut applicability) {
+        let base_string_snippet = snippet_with_applicability(cx, args[0].span, "..", &mut applicability);
+        let sugg = format!("{}.push({})", base_string_snippet, extension_string);
+        span_lint_and_sugg(
+            cx,
+            SINGLE_CHAR_PUSH_STR,
+            expr.span,
+            "calling `push_str()` using a single-character string literal",
+            "consider using `push` with a character literal",
+            sugg,
+            applicability,
+        );
+    }
+}
+
 /// Checks for the `USELESS_ASREF` lint.
 fn lint_asref(cx: &LateContext<'_>, expr: &hir::Expr<'_>, call_name: &str, as_ref_args: &[hir::Expr<'_>]) {
     // when we get here, we've already checked that the call name is "as_ref" or "as_mut"
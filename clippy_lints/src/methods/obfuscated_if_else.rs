@@ -0,0 +1,53 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{snippet_with_applicability, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+use super::OBFUSCATED_IF_ELSE;
+
+/// lint use of `cond.then(|| a).unwrap_or(b)`
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &hir::Expr<'_>,
+    then_args: &'tcx [hir::Expr<'_>],
+    unwrap_args: &'tcx [hir::Expr<'_>],
+) {
+    if_chain! {
+        if cx.typeck_results().expr_ty(&then_args[0]).is_bool();
+        if let hir::ExprKind::Closure(_, _, body_id, _, _) = then_args[1].kind;
+        let body = cx.tcx.hir().body(body_id);
+        if body.params.is_empty();
+        then {
+            // the argument of `unwrap_or` is evaluated eagerly, the `else` branch only if needed
+            let mut applicability = Applicability::MaybeIncorrect;
+            let cond = Sugg::hir_with_applicability(cx, &then_args[0], "..", &mut applicability);
+            let then_snippet = snippet_with_applicability(cx, body.value.span, "..", &mut applicability);
+            let then_block = if let hir::ExprKind::Block(..) = body.value.kind {
+                then_snippet.into_owned()
+            } else {
+                format!("{{ {} }}", then_snippet)
+            };
+            let else_snippet = snippet_with_applicability(cx, unwrap_args[1].span, "..", &mut applicability);
+            span_lint_and_then(
+                cx,
+                OBFUSCATED_IF_ELSE,
+                expr.span,
+                "use of `.then(..).unwrap_or(..)` can be written more clearly with `if .. else ..`",
+                |diag| {
+                    diag.span_suggestion(
+                        expr.span,
+                        "try",
+                        format!("if {} {} else {{ {} }}", cond, then_block, else_snippet),
+                        applicability,
+                    );
+                    diag.note(
+                        "the argument of `unwrap_or` is evaluated even if the condition is true, \
+                        the `else` branch is only evaluated if it is false",
+                    );
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use super::UNCHECKED_INTEGER_ABS;
+use crate::consts::{constant, Constant};
+use crate::utils::{int_bits, sext, span_lint_and_help};
+use rustc_hir as hir;
+use rustc_hir::ExprKind;
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_target::abi::LayoutOf;
+
+/// Checks for the `UNCHECKED_INTEGER_ABS` lint. `recv` is the receiver of the `abs` call.
+pub fn lint(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>) {
+    let ty = cx.typeck_results().expr_ty(expr);
+    if !ty.is_signed() || cannot_be_min(cx, recv) {
+        return;
+    }
+    span_lint_and_help(
+        cx,
+        UNCHECKED_INTEGER_ABS,
+        expr.span,
+        &format!("calling `abs()` on an `{}` which may be `{}::MIN`", ty, ty),
+        None,
+        "this overflows for the minimum value, consider using `checked_abs()` or `wrapping_abs()` instead",
+    );
+}
+
+/// Checks whether the receiver is a constant other than the minimum value of the signed type, or a
+/// cast from a narrower integer type, which can't be the minimum value either.
+fn cannot_be_min(cx: &LateContext<'_>, recv: &hir::Expr<'_>) -> bool {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    if let Some((value, _)) = constant(cx, cx.typeck_results(), recv) {
+        return match (value, &recv_ty.kind) {
+            (Constant::Int(value), ty::Int(ity)) => {
+                sext(cx.tcx, value, *ity) != i128::MIN >> (128 - int_bits(cx.tcx, *ity))
+            },
+            _ => true,
+        };
+    }
+    if let ExprKind::Cast(inner, _) = recv.kind {
+        let inner_ty = cx.typeck_results().expr_ty(inner);
+        if inner_ty.is_integral() {
+            if let (Ok(inner_layout), Ok(recv_layout)) = (cx.layout_of(inner_ty), cx.layout_of(recv_ty)) {
+                return inner_layout.size.bits() < recv_layout.size.bits();
+            }
+        }
+    }
+    false
+}
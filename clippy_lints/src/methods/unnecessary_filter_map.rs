@@ -1,17 +1,22 @@
 use crate::utils::paths;
-use crate::utils::usage::mutated_variables;
-use crate::utils::{match_qpath, match_trait_method, span_lint};
+use crate::utils::usage::{contains_return_break_continue_macro, mutated_variables};
+use crate::utils::{
+    match_qpath, match_trait_method, multispan_sugg_with_applicability, remove_blocks, snippet_with_applicability,
+    span_lint, span_lint_and_then,
+};
+use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::def::Res;
 use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc_lint::LateContext;
 use rustc_middle::hir::map::Map;
+use rustc_span::source_map::Span;
 
 use if_chain::if_chain;
 
 use super::UNNECESSARY_FILTER_MAP;
 
-pub(super) fn lint(cx: &LateContext<'_>, expr: &hir::Expr<'_>, args: &[hir::Expr<'_>]) {
+pub(super) fn lint(cx: &LateContext<'_>, expr: &hir::Expr<'_>, args: &[hir::Expr<'_>], method_span: Span) {
     if !match_trait_method(cx, expr, &paths::ITERATOR) {
         return;
     }
@@ -30,6 +35,36 @@ pub(super) fn lint(cx: &LateContext<'_>, expr: &hir::Expr<'_>, args: &[hir::Expr
         found_filtering |= return_visitor.found_filtering;
 
         if !found_filtering {
+            // `filter_map(|x| Some(..))` can be rewritten directly
+            if_chain! {
+                if !contains_return_break_continue_macro(&body.value);
+                let some_expr = remove_blocks(&body.value);
+                if let hir::ExprKind::Call(ref func, [ref inner]) = some_expr.kind;
+                if let hir::ExprKind::Path(ref path) = func.kind;
+                if match_qpath(path, &paths::OPTION_SOME);
+                then {
+                    span_lint_and_then(
+                        cx,
+                        UNNECESSARY_FILTER_MAP,
+                        expr.span,
+                        "this `.filter_map` can be written more simply using `.map`",
+                        |diag| {
+                            let mut applicability = Applicability::MachineApplicable;
+                            let inner_snippet = snippet_with_applicability(cx, inner.span, "..", &mut applicability);
+                            multispan_sugg_with_applicability(
+                                diag,
+                                "try",
+                                applicability,
+                                vec![
+                                    (method_span, "map".to_string()),
+                                    (some_expr.span, inner_snippet.into_owned()),
+                                ],
+                            );
+                        },
+                    );
+                    return;
+                }
+            }
             span_lint(
                 cx,
                 UNNECESSARY_FILTER_MAP,
@@ -69,10 +104,9 @@ fn check_expression<'tcx>(cx: &LateContext<'tcx>, arg_id: hir::HirId, expr: &'tc
                             }
                         }
                         return (true, false);
-                    } else {
-                        // We don't know. It might do anything.
-                        return (true, true);
                     }
+                    // We don't know. It might do anything.
+                    return (true, true);
                 }
             }
             (true, true)
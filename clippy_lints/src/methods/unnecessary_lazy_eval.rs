@@ -0,0 +1,108 @@
+use crate::utils::{is_type_diagnostic_item, match_qpath, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_lint::LateContext;
+
+use super::UNNECESSARY_LAZY_EVALUATIONS;
+
+/// Checks whether `expr` is a local bound by one of the closure parameters.
+fn is_param_binding(expr: &hir::Expr<'_>, params: &[hir::Param<'_>]) -> bool {
+    if let hir::ExprKind::Path(hir::QPath::Resolved(None, ref path)) = expr.kind {
+        if let Res::Local(local_id) = path.res {
+            let mut found = false;
+            for param in params {
+                param.pat.each_binding(|_, hir_id, _, _| found |= hir_id == local_id);
+            }
+            return found;
+        }
+    }
+    false
+}
+
+/// Checks whether evaluating `expr` eagerly is as cheap as creating the closure and can't have
+/// any side effects.
+fn can_simplify(expr: &hir::Expr<'_>, params: &[hir::Param<'_>], variant_calls: bool) -> bool {
+    match expr.kind {
+        // Closures returning literals can be unconditionally simplified
+        hir::ExprKind::Lit(_) => true,
+        // Paths can be simplified if they don't refer to an argument, this also covers `None`
+        hir::ExprKind::Path(_) => !is_param_binding(expr, params),
+        // Reading fields can be simplified if the object is not an argument of the closure.
+        // Indexing is not included, since it might panic.
+        hir::ExprKind::Field(ref object, _) => can_simplify(object, params, variant_calls),
+        // Calls to `Some`, `Ok` and `Err` can be considered literals if their arguments are
+        hir::ExprKind::Call(ref func, ref args) => {
+            if_chain! {
+                // `Some(..)` in `or_else` is handled by `bind_instead_of_map`
+                if variant_calls;
+                if let hir::ExprKind::Path(ref path) = func.kind;
+                if match_qpath(path, &["Some"]) || match_qpath(path, &["Ok"]) || match_qpath(path, &["Err"]);
+                then {
+                    args.iter().all(|arg| can_simplify(arg, params, variant_calls))
+                } else {
+                    false
+                }
+            }
+        },
+        // For anything more complex than the above, a closure is probably the right solution,
+        // or the case is handled by another lint
+        _ => false,
+    }
+}
+
+/// lint use of `<fn>_else(simple closure)` for `Option`s and `Result`s that can be
+/// replaced with `<fn>(return value of simple closure)`
+pub(super) fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    args: &'tcx [hir::Expr<'_>],
+    allow_variant_calls: bool,
+    simplify_using: &str,
+) {
+    let ty = cx.typeck_results().expr_ty(&args[0]);
+    let is_option = is_type_diagnostic_item(cx, ty, sym!(option_type));
+    let is_result = is_type_diagnostic_item(cx, ty, sym!(result_type));
+
+    if !is_option && !is_result {
+        return;
+    }
+
+    if let hir::ExprKind::Closure(_, _, body_id, _, _) = args[1].kind {
+        let body = cx.tcx.hir().body(body_id);
+        let value = &body.value;
+
+        if can_simplify(value, body.params, allow_variant_calls) {
+            let msg = if is_option {
+                "unnecessary closure used to substitute value for `Option::None`"
+            } else {
+                "unnecessary closure used to substitute value for `Result::Err`"
+            };
+
+            let mut applicability = Applicability::MachineApplicable;
+            let mut sugg_args = vec![snippet_with_applicability(cx, value.span, "..", &mut applicability)];
+            // e.g. the mapping function of `map_or_else`
+            sugg_args.extend(
+                args[2..]
+                    .iter()
+                    .map(|arg| snippet_with_applicability(cx, arg.span, "..", &mut applicability)),
+            );
+
+            span_lint_and_sugg(
+                cx,
+                UNNECESSARY_LAZY_EVALUATIONS,
+                expr.span,
+                msg,
+                &format!("use `{}` instead", simplify_using),
+                format!(
+                    "{}.{}({})",
+                    snippet_with_applicability(cx, args[0].span, "..", &mut applicability),
+                    simplify_using,
+                    sugg_args.join(", ")
+                ),
+                applicability,
+            );
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use if_chain::if_chain;
-use rustc_ast::ast::LitKind;
+use rustc_ast::ast::{FloatTy, LitKind};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::FnKind;
 use rustc_hir::{
@@ -405,22 +405,30 @@ impl<'tcx> LateLintPass<'tcx> for MiscLints {
                         is_comparing_arrays,
                     );
                     span_lint_and_then(cx, lint, expr.span, msg, |diag| {
+                        if is_comparing_arrays {
+                            diag.note("`f32::EPSILON` and `f64::EPSILON` are available for the `error`");
+                            return;
+                        }
+
                         let lhs = Sugg::hir(cx, left, "..");
                         let rhs = Sugg::hir(cx, right, "..");
-
-                        if !is_comparing_arrays {
-                            diag.span_suggestion(
-                                expr.span,
-                                "consider comparing them within some error",
-                                format!(
-                                    "({}).abs() {} error",
-                                    lhs - rhs,
-                                    if op == BinOpKind::Eq { '<' } else { '>' }
-                                ),
-                                Applicability::HasPlaceholders, // snippet
-                            );
-                        }
-                        diag.note("`f32::EPSILON` and `f64::EPSILON` are available for the `error`");
+                        let float_ty = float_ty_name(cx, left);
+                        diag.span_suggestion(
+                            expr.span,
+                            "consider comparing them within some error",
+                            format!(
+                                "({}).abs() {} {}::EPSILON",
+                                lhs - rhs,
+                                if op == BinOpKind::Eq { '<' } else { '>' },
+                                float_ty,
+                            ),
+                            // `EPSILON` is not a suitable error for all magnitudes
+                            Applicability::MaybeIncorrect,
+                        );
+                        diag.note(&format!(
+                            "`{}::EPSILON` is only a suitable error for values close to `1.0`",
+                            float_ty
+                        ));
                     });
                 } else if op == BinOpKind::Rem && is_integer_const(cx, right, 1) {
                     span_lint(cx, MODULO_ONE, expr.span, "any number modulo 1 will be 0");
@@ -570,6 +578,14 @@ fn is_float(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     matches!(value, ty::Float(_))
 }
 
+/// Returns the name of the float type of `expr`, which is one of the operands of the comparison.
+fn float_ty_name(cx: &LateContext<'_>, expr: &Expr<'_>) -> &'static str {
+    match walk_ptrs_ty(cx.typeck_results().expr_ty(expr)).kind {
+        ty::Float(FloatTy::F32) => "f32",
+        _ => "f64",
+    }
+}
+
 fn is_array(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     matches!(&walk_ptrs_ty(cx.typeck_results().expr_ty(expr)).kind, ty::Array(_, _))
 }
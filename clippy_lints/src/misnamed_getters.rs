@@ -0,0 +1,117 @@
+use crate::utils::{snippet_with_applicability, span_lint_and_sugg, trait_ref_of_method, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::{BorrowKind, Expr, ExprKind, ImplItem, ImplItemKind, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for getter methods whose body only returns a field of `self`,
+    /// but a different one than the field with the name of the method.
+    ///
+    /// **Why is this bad?** This is most likely a copy-paste error which the type checker doesn't
+    /// catch if both fields have the same type.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Config {
+    ///     timeout: u64,
+    ///     retries: u64,
+    /// }
+    ///
+    /// impl Config {
+    ///     fn timeout(&self) -> u64 {
+    ///         self.retries
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Config {
+    ///     timeout: u64,
+    ///     retries: u64,
+    /// }
+    ///
+    /// impl Config {
+    ///     fn timeout(&self) -> u64 {
+    ///         self.timeout
+    ///     }
+    /// }
+    /// ```
+    pub MISNAMED_GETTERS,
+    style,
+    "getter methods returning a different field than the one they are named after"
+}
+
+declare_lint_pass!(MisnamedGetters => [MISNAMED_GETTERS]);
+
+impl<'tcx> LateLintPass<'tcx> for MisnamedGetters {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx ImplItem<'_>) {
+        if_chain! {
+            if !impl_item.span.from_expansion();
+            if let ImplItemKind::Fn(ref sig, body_id) = impl_item.kind;
+            if sig.decl.implicit_self.has_implicit_self();
+            if sig.decl.inputs.len() == 1;
+            let body = cx.tcx.hir().body(body_id);
+            if let PatKind::Binding(_, self_id, _, None) = body.params[0].pat.kind;
+            if let ExprKind::Block(block, _) = body.value.kind;
+            if block.stmts.is_empty();
+            if let Some(returned) = block.expr;
+            let field_expr = strip_borrow(returned);
+            if let ExprKind::Field(recv, used_field) = field_expr.kind;
+            if let ExprKind::Path(QPath::Resolved(None, path)) = recv.kind;
+            if path.res == Res::Local(self_id);
+            then {
+                let method_name = impl_item.ident.as_str();
+                let field_name = method_name.strip_suffix("_mut").unwrap_or(&method_name);
+                if used_field.as_str() == field_name {
+                    return;
+                }
+
+                let fn_def_id = cx.tcx.hir().local_def_id(impl_item.hir_id);
+                let self_ty = walk_ptrs_ty(cx.tcx.fn_sig(fn_def_id).input(0).skip_binder());
+                let (adt, substs) = match self_ty.kind {
+                    ty::Adt(adt, substs) if adt.is_struct() => (adt, substs),
+                    _ => return,
+                };
+                let fields = &adt.non_enum_variant().fields;
+                let used = fields.iter().find(|field| field.ident.name == used_field.name);
+                let named = fields.iter().find(|field| field.ident.as_str() == field_name);
+                if_chain! {
+                    if let (Some(used), Some(named)) = (used, named);
+                    if used.ty(cx.tcx, substs) == named.ty(cx.tcx, substs);
+                    // the name of a trait method doesn't necessarily refer to a field
+                    if trait_ref_of_method(cx, impl_item.hir_id).is_none();
+                    then {
+                        let mut applicability = Applicability::MaybeIncorrect;
+                        let recv_snippet = snippet_with_applicability(cx, recv.span, "self", &mut applicability);
+                        span_lint_and_sugg(
+                            cx,
+                            MISNAMED_GETTERS,
+                            field_expr.span,
+                            &format!(
+                                "getter `{}` returns the field `{}` instead of `{}`",
+                                method_name, used_field, field_name
+                            ),
+                            "consider returning the field with the same name",
+                            format!("{}.{}", recv_snippet, field_name),
+                            applicability,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Removes a `&` or `&mut` in front of the returned expression.
+fn strip_borrow<'a>(expr: &'a Expr<'a>) -> &'a Expr<'a> {
+    match expr.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, _, inner) => inner,
+        _ => expr,
+    }
+}
@@ -0,0 +1,141 @@
+use crate::utils::{in_macro, is_automatically_derived, is_copy, match_trait_method, paths, span_lint_hir_and_then};
+use if_chain::if_chain;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Crate, Expr, ExprKind, GenericParamKind, HirId, Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for types deriving `Clone` but not `Copy`, although all their
+    /// fields are `Copy`, which are cloned somewhere in the crate.
+    ///
+    /// **Why is this bad?** Deriving `Copy` makes the `.clone()` calls unnecessary, and values
+    /// of the type can be used after being moved.
+    ///
+    /// **Known problems:** Types which are deliberately not `Copy`, e.g. to avoid accidental
+    /// copies of large values, have to allow the lint. Exported types are not linted by default,
+    /// since they can't stop being `Copy` later without breaking other crates; this can be
+    /// changed with the `avoid-breaking-exported-api` configuration option.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// #[derive(Clone)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let a = Point { x: 1, y: 2 };
+    /// let b = a.clone();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[derive(Clone, Copy)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let a = Point { x: 1, y: 2 };
+    /// let b = a;
+    /// ```
+    pub MISSING_DERIVE_COPY,
+    pedantic,
+    "type deriving `Clone` which is cloned and could derive `Copy` as well"
+}
+
+/// A local type deriving `Clone` whose fields are all `Copy`.
+struct Candidate {
+    def_id: DefId,
+    hir_id: HirId,
+    span: Span,
+}
+
+pub struct MissingDeriveCopy {
+    avoid_breaking_exported_api: bool,
+    candidates: Vec<Candidate>,
+    clones: FxHashMap<DefId, Vec<Span>>,
+}
+
+impl MissingDeriveCopy {
+    #[must_use]
+    pub fn new(avoid_breaking_exported_api: bool) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+            candidates: Vec::new(),
+            clones: FxHashMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(MissingDeriveCopy => [MISSING_DERIVE_COPY]);
+
+impl<'tcx> LateLintPass<'tcx> for MissingDeriveCopy {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if_chain! {
+            if let ItemKind::Impl {
+                of_trait: Some(ref trait_ref),
+                ref generics,
+                ..
+            } = item.kind;
+            if is_automatically_derived(item.attrs);
+            if trait_ref.trait_def_id() == cx.tcx.lang_items().clone_trait();
+            // the bounds of a derived `Copy` impl might not be satisfied for all type parameters
+            if generics
+                .params
+                .iter()
+                .all(|param| matches!(param.kind, GenericParamKind::Lifetime { .. }));
+            let self_ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(item.hir_id));
+            if let ty::Adt(adt_def, substs) = self_ty.kind;
+            if !adt_def.is_union();
+            // types implementing `Drop` can't be `Copy`
+            if !adt_def.has_dtor(cx.tcx);
+            if let Some(local_def_id) = adt_def.did.as_local();
+            let hir_id = cx.tcx.hir().local_def_id_to_hir_id(local_def_id);
+            if !(self.avoid_breaking_exported_api && cx.access_levels.is_exported(hir_id));
+            if !is_copy(cx, self_ty);
+            if adt_def.all_fields().all(|field| is_copy(cx, field.ty(cx.tcx, substs)));
+            then {
+                self.candidates.push(Candidate {
+                    def_id: adt_def.did,
+                    hir_id,
+                    span: cx.tcx.def_span(adt_def.did),
+                });
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::MethodCall(path, _, _, _) = expr.kind;
+            if path.ident.name == sym!(clone);
+            if match_trait_method(cx, expr, &paths::CLONE_TRAIT);
+            if let ty::Adt(adt_def, _) = cx.typeck_results().expr_ty(expr).kind;
+            then {
+                self.clones.entry(adt_def.did).or_default().push(expr.span);
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Crate<'_>) {
+        for candidate in &self.candidates {
+            if let Some(clones) = self.clones.get(&candidate.def_id) {
+                span_lint_hir_and_then(
+                    cx,
+                    MISSING_DERIVE_COPY,
+                    candidate.hir_id,
+                    candidate.span,
+                    "this type could derive `Copy`, which would make cloning it unnecessary",
+                    |diag| {
+                        diag.span_note(clones.clone(), "the type is cloned here");
+                        diag.help("add `Copy` to the derived traits");
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::utils::{span_lint_and_then, type_is_unsafe_function, walk_ptrs_ty};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, BlockCheckMode, Expr, ExprKind, UnOp, UnsafeSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe` blocks which contain more unsafe operations than
+    /// allowed by the `max-unsafe-ops-per-block` option, which defaults to one. Unsafe
+    /// operations are calls to unsafe functions and methods, dereferences of raw pointers,
+    /// accesses of mutable or extern statics, and reads of union fields.
+    ///
+    /// **Why is this bad?** Every unsafe operation comes with its own safety requirements, which
+    /// have to be upheld by the surrounding code. Keeping `unsafe` blocks small makes it clear
+    /// which operations they are for and makes them easier to audit, e.g. by writing a
+    /// `// SAFETY:` comment for each of them.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// let values = [1, 2, 3];
+    /// let ptr = values.as_ptr();
+    /// let sum = unsafe { *ptr + *ptr.add(1) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let values = [1, 2, 3];
+    /// let ptr = values.as_ptr();
+    /// // SAFETY: `ptr` points to the first element of `values`
+    /// let first = unsafe { *ptr };
+    /// // SAFETY: `values` has more than one element
+    /// let second = unsafe { ptr.add(1) };
+    /// // SAFETY: `second` points to the second element of `values`
+    /// let sum = first + unsafe { *second };
+    /// ```
+    pub MULTIPLE_UNSAFE_OPS_PER_BLOCK,
+    restriction,
+    "`unsafe` blocks containing more than the allowed number of unsafe operations"
+}
+
+#[derive(Copy, Clone)]
+pub struct MultipleUnsafeOpsPerBlock {
+    max_unsafe_ops_per_block: u64,
+}
+
+impl MultipleUnsafeOpsPerBlock {
+    pub fn new(max_unsafe_ops_per_block: u64) -> Self {
+        Self {
+            max_unsafe_ops_per_block,
+        }
+    }
+}
+
+impl_lint_pass!(MultipleUnsafeOpsPerBlock => [MULTIPLE_UNSAFE_OPS_PER_BLOCK]);
+
+impl<'tcx> LateLintPass<'tcx> for MultipleUnsafeOpsPerBlock {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        if block.span.from_expansion() || !is_unsafe_block(block) {
+            return;
+        }
+        let mut visitor = UnsafeOpsVisitor { cx, ops: Vec::new() };
+        visitor.visit_block(block);
+        if visitor.ops.len() as u64 > self.max_unsafe_ops_per_block {
+            span_lint_and_then(
+                cx,
+                MULTIPLE_UNSAFE_OPS_PER_BLOCK,
+                block.span,
+                &format!(
+                    "this `unsafe` block contains {} unsafe operations, expected only {}",
+                    visitor.ops.len(),
+                    self.max_unsafe_ops_per_block
+                ),
+                |diag| {
+                    for (span, op) in visitor.ops {
+                        diag.span_note(span, &format!("{} occurs here", op));
+                    }
+                },
+            );
+        }
+    }
+}
+
+fn is_unsafe_block(block: &Block<'_>) -> bool {
+    block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+}
+
+/// Collects the unsafe operations in an `unsafe` block, not looking into nested `unsafe` blocks
+/// as these are checked on their own.
+struct UnsafeOpsVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    ops: Vec<(Span, &'static str)>,
+}
+
+impl<'a, 'tcx> UnsafeOpsVisitor<'a, 'tcx> {
+    fn is_union_field(&self, expr: &Expr<'_>) -> bool {
+        if let ExprKind::Field(base, _) = expr.kind {
+            let base_ty = walk_ptrs_ty(self.cx.typeck_results().expr_ty(base));
+            matches!(base_ty.kind, ty::Adt(def, _) if def.is_union())
+        } else {
+            false
+        }
+    }
+
+    fn unsafe_op(&self, expr: &Expr<'_>) -> Option<&'static str> {
+        let cx = self.cx;
+        match expr.kind {
+            ExprKind::Call(func, _) if type_is_unsafe_function(cx, cx.typeck_results().expr_ty(func)) => {
+                Some("unsafe function call")
+            },
+            ExprKind::MethodCall(..) => cx
+                .typeck_results()
+                .type_dependent_def_id(expr.hir_id)
+                .filter(|&def_id| type_is_unsafe_function(cx, cx.tcx.type_of(def_id)))
+                .map(|_| "unsafe method call"),
+            ExprKind::Unary(UnOp::UnDeref, inner) => match cx.typeck_results().expr_ty(inner).kind {
+                ty::RawPtr(_) => Some("raw pointer dereference"),
+                _ => None,
+            },
+            ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, expr.hir_id) {
+                Res::Def(DefKind::Static, def_id) if cx.tcx.is_mutable_static(def_id) => {
+                    Some("access of a mutable static")
+                },
+                Res::Def(DefKind::Static, def_id) if cx.tcx.is_foreign_item(def_id) => {
+                    Some("access of an extern static")
+                },
+                _ => None,
+            },
+            ExprKind::Field(..) if self.is_union_field(expr) => Some("union field access"),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UnsafeOpsVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            ExprKind::Block(block, _) if is_unsafe_block(block) => return,
+            // writing to a union field is safe
+            ExprKind::Assign(lhs, rhs, _) if self.is_union_field(lhs) => {
+                if let ExprKind::Field(base, _) = lhs.kind {
+                    self.visit_expr(base);
+                }
+                self.visit_expr(rhs);
+                return;
+            },
+            _ => {},
+        }
+        if let Some(op) = self.unsafe_op(expr) {
+            self.ops.push((expr.span, op));
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
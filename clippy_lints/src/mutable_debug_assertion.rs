@@ -10,7 +10,8 @@ use rustc_span::Span;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for function/method calls with a mutable
-    /// parameter in `debug_assert!`, `debug_assert_eq!` and `debug_assert_ne!` macros.
+    /// parameter and for assignments in `debug_assert!`, `debug_assert_eq!` and
+    /// `debug_assert_ne!` macros.
     ///
     /// **Why is this bad?** In release builds `debug_assert!` macros are optimized out by the
     /// compiler.
@@ -25,6 +26,9 @@ declare_clippy_lint! {
     /// // or
     /// fn take_a_mut_parameter(_: &mut u32) -> bool { unimplemented!() }
     /// debug_assert!(take_a_mut_parameter(&mut 5));
+    /// // or
+    /// let mut counter = 0;
+    /// debug_assert!({ counter += 1; counter } < 10);
     /// ```
     pub DEBUG_ASSERT_WITH_MUT_CALL,
     nursery,
@@ -39,13 +43,13 @@ impl<'tcx> LateLintPass<'tcx> for DebugAssertWithMutCall {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         for dmn in &DEBUG_MACRO_NAMES {
             if is_direct_expn_of(e.span, dmn).is_some() {
-                if let Some(span) = extract_call(cx, e) {
-                    span_lint(
-                        cx,
-                        DEBUG_ASSERT_WITH_MUT_CALL,
-                        span,
-                        &format!("do not call a function with mutable arguments inside of `{}!`", dmn),
-                    );
+                if let Some((span, is_assignment)) = extract_call(cx, e) {
+                    let msg = if is_assignment {
+                        format!("do not assign to a place inside of `{}!`", dmn)
+                    } else {
+                        format!("do not call a function with mutable arguments inside of `{}!`", dmn)
+                    };
+                    span_lint(cx, DEBUG_ASSERT_WITH_MUT_CALL, span, &msg);
                 }
             }
         }
@@ -53,7 +57,8 @@ impl<'tcx> LateLintPass<'tcx> for DebugAssertWithMutCall {
 }
 
 //HACK(hellow554): remove this when #4694 is implemented
-fn extract_call<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> Option<Span> {
+/// Returns the span of the mutating expression and whether it is an assignment.
+fn extract_call<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> Option<(Span, bool)> {
     if_chain! {
         if let ExprKind::Block(ref block, _) = e.kind;
         if block.stmts.len() == 1;
@@ -67,7 +72,7 @@ fn extract_call<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> Option<Span>
                 then {
                     let mut visitor = MutArgVisitor::new(cx);
                     visitor.visit_expr(condition);
-                    return visitor.expr_span();
+                    return visitor.result();
                 }
             }
 
@@ -82,15 +87,15 @@ fn extract_call<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> Option<Span>
                     if let ExprKind::AddrOf(BorrowKind::Ref, _, ref lhs) = conditions[0].kind {
                         let mut visitor = MutArgVisitor::new(cx);
                         visitor.visit_expr(lhs);
-                        if let Some(span) = visitor.expr_span() {
-                            return Some(span);
+                        if let Some(result) = visitor.result() {
+                            return Some(result);
                         }
                     }
                     if let ExprKind::AddrOf(BorrowKind::Ref, _, ref rhs) = conditions[1].kind {
                         let mut visitor = MutArgVisitor::new(cx);
                         visitor.visit_expr(rhs);
-                        if let Some(span) = visitor.expr_span() {
-                            return Some(span);
+                        if let Some(result) = visitor.result() {
+                            return Some(result);
                         }
                     }
                 }
@@ -105,6 +110,7 @@ struct MutArgVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     expr_span: Option<Span>,
     found: bool,
+    is_assignment: bool,
 }
 
 impl<'a, 'tcx> MutArgVisitor<'a, 'tcx> {
@@ -113,12 +119,13 @@ impl<'a, 'tcx> MutArgVisitor<'a, 'tcx> {
             cx,
             expr_span: None,
             found: false,
+            is_assignment: false,
         }
     }
 
-    fn expr_span(&self) -> Option<Span> {
+    fn result(&self) -> Option<(Span, bool)> {
         if self.found {
-            self.expr_span
+            self.expr_span.map(|span| (span, self.is_assignment))
         } else {
             None
         }
@@ -145,12 +152,18 @@ impl<'a, 'tcx> Visitor<'tcx> for MutArgVisitor<'a, 'tcx> {
                     }
                 }
             },
+            ExprKind::Assign(..) | ExprKind::AssignOp(..) if !self.found => {
+                self.expr_span = Some(expr.span);
+                self.found = true;
+                self.is_assignment = true;
+                return;
+            },
             // Don't check await desugars
             ExprKind::Match(_, _, MatchSource::AwaitDesugar) => return,
             _ if !self.found => self.expr_span = Some(expr.span),
             _ => return,
         }
-        walk_expr(self, expr)
+        walk_expr(self, expr);
     }
 
     fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
@@ -0,0 +1,184 @@
+use crate::utils::{in_macro, qpath_res, snippet_with_applicability, span_lint_and_then};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, Expr, ExprKind, HirId, MatchSource, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for late initializations that can be replaced by a `let`
+    /// statement with an initializer.
+    ///
+    /// **Why is this bad?** Assigning in the `let` statement is less repetitive and makes it
+    /// obvious that the variable is always initialized.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let cond = true;
+    /// let a;
+    /// a = 1;
+    ///
+    /// let b;
+    /// if cond {
+    ///     b = 1;
+    /// } else {
+    ///     b = 2;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let cond = true;
+    /// let a = 1;
+    ///
+    /// let b = if cond { 1 } else { 2 };
+    /// ```
+    pub NEEDLESS_LATE_INIT,
+    style,
+    "late initializations that can be replaced by a `let` statement with an initializer"
+}
+
+declare_lint_pass!(NeedlessLateInit => [NEEDLESS_LATE_INIT]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessLateInit {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (local_stmt, next_stmt) in block.stmts.iter().zip(block.stmts.iter().skip(1)) {
+            if_chain! {
+                if let StmtKind::Local(local) = local_stmt.kind;
+                if local.init.is_none() && local.attrs.is_empty();
+                if let PatKind::Binding(_, binding_id, ident, None) = local.pat.kind;
+                if !in_macro(local_stmt.span) && !in_macro(next_stmt.span);
+                if let StmtKind::Expr(expr) | StmtKind::Semi(expr) = next_stmt.kind;
+                let mut assignments = Vec::new();
+                if let Some(needs_semi) = match expr.kind {
+                    ExprKind::Assign(..) => Some(false),
+                    ExprKind::Match(..) => Some(matches!(next_stmt.kind, StmtKind::Expr(_))),
+                    _ => None,
+                };
+                if collect_assignments(cx, expr, binding_id, &mut assignments);
+                if !assignments.is_empty();
+                // the variable must not be used anywhere else before it is initialized
+                if count_uses(cx, expr, binding_id) == assignments.len();
+                then {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let mut let_snippet = format!(
+                        "let {}",
+                        snippet_with_applicability(cx, local.pat.span, "..", &mut applicability)
+                    );
+                    if let Some(ty) = local.ty {
+                        let_snippet.push_str(": ");
+                        let_snippet.push_str(&snippet_with_applicability(cx, ty.span, "..", &mut applicability));
+                    }
+                    let_snippet.push_str(" = ");
+
+                    let mut suggestion = vec![(local_stmt.span, String::new())];
+                    if let ExprKind::Assign(_, rhs, _) = expr.kind {
+                        suggestion.push((expr.span.until(rhs.span), let_snippet));
+                    } else {
+                        suggestion.push((expr.span.shrink_to_lo(), let_snippet));
+                        suggestion.extend(assignments.iter().map(|(span, rhs)| {
+                            (*span, snippet_with_applicability(cx, rhs.span, "..", &mut applicability).into_owned())
+                        }));
+                        if needs_semi {
+                            suggestion.push((expr.span.shrink_to_hi(), ";".to_string()));
+                        }
+                    }
+
+                    span_lint_and_then(
+                        cx,
+                        NEEDLESS_LATE_INIT,
+                        local_stmt.span,
+                        "unneeded late initialization",
+                        |diag| {
+                            diag.multipart_suggestion(&format!("declare `{}` here", ident), suggestion, applicability);
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Collects the assignments to the variable at the end of every branch of `expr` together with
+/// the span which has to be replaced by the assigned value. Returns `false` if a branch neither
+/// ends with such an assignment nor diverges.
+fn collect_assignments<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    binding_id: HirId,
+    assignments: &mut Vec<(Span, &'tcx Expr<'tcx>)>,
+) -> bool {
+    if cx.typeck_results().expr_ty(expr).is_never() {
+        return true;
+    }
+    match expr.kind {
+        ExprKind::Assign(lhs, rhs, _) if is_binding(cx, lhs, binding_id) => {
+            assignments.push((expr.span, rhs));
+            true
+        },
+        ExprKind::Block(block, None) => match (block.stmts.last(), block.expr) {
+            (_, Some(tail)) => collect_assignments(cx, tail, binding_id, assignments),
+            (Some(stmt), None) => match stmt.kind {
+                StmtKind::Semi(semi) => match semi.kind {
+                    ExprKind::Assign(lhs, rhs, _) if is_binding(cx, lhs, binding_id) => {
+                        assignments.push((stmt.span, rhs));
+                        true
+                    },
+                    _ => cx.typeck_results().expr_ty(semi).is_never(),
+                },
+                _ => false,
+            },
+            (None, None) => false,
+        },
+        ExprKind::Match(_, arms, MatchSource::Normal)
+        | ExprKind::Match(_, arms, MatchSource::IfDesugar { .. })
+        | ExprKind::Match(_, arms, MatchSource::IfLetDesugar { .. }) => arms
+            .iter()
+            .all(|arm| collect_assignments(cx, arm.body, binding_id, assignments)),
+        _ => false,
+    }
+}
+
+fn is_binding(cx: &LateContext<'_>, expr: &Expr<'_>, binding_id: HirId) -> bool {
+    if let ExprKind::Path(ref qpath) = expr.kind {
+        qpath_res(cx, qpath, expr.hir_id) == Res::Local(binding_id)
+    } else {
+        false
+    }
+}
+
+fn count_uses<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, binding_id: HirId) -> usize {
+    let mut visitor = UseCounter {
+        cx,
+        binding_id,
+        count: 0,
+    };
+    visitor.visit_expr(expr);
+    visitor.count
+}
+
+struct UseCounter<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    binding_id: HirId,
+    count: usize,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UseCounter<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if is_binding(self.cx, expr, self.binding_id) {
+            self.count += 1;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
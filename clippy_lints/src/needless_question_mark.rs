@@ -0,0 +1,86 @@
+use crate::utils::{in_macro, match_qpath, paths, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Body, Expr, ExprKind, MatchSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Some(x?)` and `Ok(x?)` in return position where the
+    /// question mark operator doesn't change the type of `x`.
+    ///
+    /// **Why is this bad?** The value is unwrapped and immediately wrapped again, which can be
+    /// replaced by just returning `x`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Inner {
+    ///     value: Option<u32>,
+    /// }
+    ///
+    /// fn value(inner: &Inner) -> Option<u32> {
+    ///     Some(inner.value?)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct Inner {
+    /// #     value: Option<u32>,
+    /// # }
+    /// fn value(inner: &Inner) -> Option<u32> {
+    ///     inner.value
+    /// }
+    /// ```
+    pub NEEDLESS_QUESTION_MARK,
+    style,
+    "`Some(x?)` or `Ok(x?)` in return position which could just be `x`"
+}
+
+declare_lint_pass!(NeedlessQuestionMark => [NEEDLESS_QUESTION_MARK]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessQuestionMark {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Ret(Some(ret)) = expr.kind {
+            check(cx, ret);
+        }
+    }
+
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'_>) {
+        if let ExprKind::Block(block, _) = body.value.kind {
+            if let Some(tail) = block.expr {
+                check(cx, tail);
+            }
+        } else {
+            check(cx, &body.value);
+        }
+    }
+}
+
+fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if_chain! {
+        if !in_macro(expr.span);
+        if let ExprKind::Call(ref path, [ref arg]) = expr.kind;
+        if let ExprKind::Path(ref qpath) = path.kind;
+        if match_qpath(qpath, &paths::OPTION_SOME) || match_qpath(qpath, &paths::RESULT_OK);
+        // `x?` is desugared to `match Try::into_result(x) { .. }`
+        if let ExprKind::Match(ref match_arg, _, MatchSource::TryDesugar) = arg.kind;
+        if let ExprKind::Call(_, [ref inner]) = match_arg.kind;
+        if !in_macro(inner.span);
+        // otherwise the question mark converts the error type
+        if cx.typeck_results().expr_ty(inner) == cx.typeck_results().expr_ty(expr);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_QUESTION_MARK,
+                expr.span,
+                "question mark operator is useless here",
+                "try",
+                snippet_with_applicability(cx, inner.span, "..", &mut applicability).into_owned(),
+                applicability,
+            );
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::utils::{last_path_segment, match_type, paths, snippet_opt, span_lint_and_sugg, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for decimal integer literals which look like octal Unix
+    /// permission modes, e.g. `644` or `755`, passed to `OpenOptionsExt::mode`,
+    /// `DirBuilderExt::mode`, `PermissionsExt::set_mode` or `PermissionsExt::from_mode`.
+    ///
+    /// **Why is this bad?** Permission modes are almost always meant as octal numbers, but
+    /// unlike in C a leading `0` doesn't make a literal octal in Rust. The decimal `644` is
+    /// `0o1204` in octal, which sets entirely different permissions than `0o644`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// use std::fs::OpenOptions;
+    /// use std::os::unix::fs::OpenOptionsExt;
+    ///
+    /// let mut options = OpenOptions::new();
+    /// options.mode(644);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use std::fs::OpenOptions;
+    /// use std::os::unix::fs::OpenOptionsExt;
+    ///
+    /// let mut options = OpenOptions::new();
+    /// options.mode(0o644);
+    /// ```
+    pub NON_OCTAL_UNIX_PERMISSIONS,
+    correctness,
+    "decimal literals which look like octal Unix permission modes"
+}
+
+declare_lint_pass!(NonOctalUnixPermissions => [NON_OCTAL_UNIX_PERMISSIONS]);
+
+impl<'tcx> LateLintPass<'tcx> for NonOctalUnixPermissions {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        match expr.kind {
+            ExprKind::MethodCall(path, _, args, _) if args.len() == 2 => {
+                let recv_ty = walk_ptrs_ty(cx.typeck_results().expr_ty(&args[0]));
+                let is_mode_method = match &*path.ident.as_str() {
+                    "mode" => {
+                        match_type(cx, recv_ty, &paths::OPEN_OPTIONS) || match_type(cx, recv_ty, &paths::DIR_BUILDER)
+                    },
+                    "set_mode" => match_type(cx, recv_ty, &paths::PERMISSIONS),
+                    _ => false,
+                };
+                if is_mode_method {
+                    check_mode(cx, &args[1]);
+                }
+            },
+            ExprKind::Call(func, [arg]) => {
+                if_chain! {
+                    if let ExprKind::Path(ref qpath) = func.kind;
+                    if last_path_segment(qpath).ident.name == sym!(from_mode);
+                    if match_type(cx, cx.typeck_results().expr_ty(expr), &paths::PERMISSIONS);
+                    then {
+                        check_mode(cx, arg);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+fn check_mode(cx: &LateContext<'_>, mode: &Expr<'_>) {
+    if_chain! {
+        if let ExprKind::Lit(lit) = &mode.kind;
+        if let LitKind::Int(..) = lit.node;
+        if let Some(snippet) = snippet_opt(cx, lit.span);
+        if looks_like_octal_mode(&snippet);
+        then {
+            span_lint_and_sugg(
+                cx,
+                NON_OCTAL_UNIX_PERMISSIONS,
+                mode.span,
+                "using a decimal literal which looks like an octal permission mode",
+                "consider using an octal literal instead",
+                format!("0o{}", snippet.trim_start_matches('0')),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// Checks whether the literal consists of three or four octal digits only, like `644`, `0755` or
+/// `1777`, without a radix prefix or a suffix.
+fn looks_like_octal_mode(snippet: &str) -> bool {
+    (3..=4).contains(&snippet.len())
+        && snippet.bytes().all(|b| (b'0'..=b'7').contains(&b))
+        && snippet.bytes().any(|b| b != b'0')
+}
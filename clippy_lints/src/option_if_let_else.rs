@@ -1,11 +1,14 @@
 use crate::utils;
 use crate::utils::sugg::Sugg;
-use crate::utils::{match_type, paths, span_lint_and_sugg};
+use crate::utils::usage::{contains_return_break_continue_macro, mutated_variables};
+use crate::utils::{is_copy, match_type, paths, span_lint_and_sugg};
 use if_chain::if_chain;
 
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
-use rustc_hir::intravisit::{NestedVisitorMap, Visitor};
-use rustc_hir::{Arm, BindingAnnotation, Block, Expr, ExprKind, MatchSource, Mutability, PatKind, UnOp};
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Arm, BindingAnnotation, Block, Expr, ExprKind, HirId, MatchSource, Mutability, PatKind, QPath, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::map::Map;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
@@ -33,6 +36,10 @@ declare_clippy_lint! {
     /// cause it to not suggest a fix if either block contains a loop with
     /// continues or breaks contained within the loop.
     ///
+    /// Moving the bodies into closures can cause borrow checker errors, e.g. if
+    /// both of them use the same variable mutably. The lint tries to detect these
+    /// cases, but it might miss some of them.
+    ///
     /// **Example:**
     ///
     /// ```rust
@@ -63,7 +70,7 @@ declare_clippy_lint! {
     /// }, |foo| foo);
     /// ```
     pub OPTION_IF_LET_ELSE,
-    pedantic,
+    nursery,
     "reimplementation of Option::map_or"
 }
 
@@ -89,51 +96,6 @@ struct OptionIfLetElseOccurence {
     wrap_braces: bool,
 }
 
-struct ReturnBreakContinueMacroVisitor {
-    seen_return_break_continue: bool,
-}
-impl ReturnBreakContinueMacroVisitor {
-    fn new() -> ReturnBreakContinueMacroVisitor {
-        ReturnBreakContinueMacroVisitor {
-            seen_return_break_continue: false,
-        }
-    }
-}
-impl<'tcx> Visitor<'tcx> for ReturnBreakContinueMacroVisitor {
-    type Map = Map<'tcx>;
-    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
-        NestedVisitorMap::None
-    }
-
-    fn visit_expr(&mut self, ex: &'tcx Expr<'tcx>) {
-        if self.seen_return_break_continue {
-            // No need to look farther if we've already seen one of them
-            return;
-        }
-        match &ex.kind {
-            ExprKind::Ret(..) | ExprKind::Break(..) | ExprKind::Continue(..) => {
-                self.seen_return_break_continue = true;
-            },
-            // Something special could be done here to handle while or for loop
-            // desugaring, as this will detect a break if there's a while loop
-            // or a for loop inside the expression.
-            _ => {
-                if utils::in_macro(ex.span) {
-                    self.seen_return_break_continue = true;
-                } else {
-                    rustc_hir::intravisit::walk_expr(self, ex);
-                }
-            },
-        }
-    }
-}
-
-fn contains_return_break_continue_macro(expression: &Expr<'_>) -> bool {
-    let mut recursive_visitor = ReturnBreakContinueMacroVisitor::new();
-    recursive_visitor.visit_expr(expression);
-    recursive_visitor.seen_return_break_continue
-}
-
 /// Extracts the body of a given arm. If the arm contains only an expression,
 /// then it returns the expression. Otherwise, it returns the entire block
 fn extract_body_from_arm<'a>(arm: &'a Arm<'a>) -> Option<&'a Expr<'a>> {
@@ -196,14 +158,87 @@ fn format_option_in_sugg(cx: &LateContext<'_>, cond_expr: &Expr<'_>, as_ref: boo
     )
 }
 
+/// Collects the local variables used in an expression.
+struct LocalsVisitor {
+    locals: FxHashSet<HirId>,
+}
+
+impl<'tcx> Visitor<'tcx> for LocalsVisitor {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind {
+            if let Res::Local(id) = path.res {
+                self.locals.insert(id);
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::None
+    }
+}
+
+fn used_locals<'tcx>(expr: &'tcx Expr<'tcx>) -> FxHashSet<HirId> {
+    let mut visitor = LocalsVisitor {
+        locals: FxHashSet::default(),
+    };
+    visitor.visit_expr(expr);
+    visitor.locals
+}
+
+/// Returns the local variable a place expression like `a.b.c` is based on.
+fn place_root_local(expr: &Expr<'_>) -> Option<HirId> {
+    match expr.kind {
+        ExprKind::Field(inner, _) => place_root_local(inner),
+        ExprKind::Path(QPath::Resolved(None, path)) => match path.res {
+            Res::Local(id) => Some(id),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Checks whether turning the arms into closures could cause borrow checker errors, which the
+/// `if let` doesn't have: the option is moved before the closures are created, and both
+/// closures exist at the same time.
+fn has_borrow_conflicts<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond_expr: &Expr<'_>,
+    moves_option: bool,
+    some_body: &'tcx Expr<'tcx>,
+    none_body: &'tcx Expr<'tcx>,
+) -> bool {
+    let some_locals = used_locals(some_body);
+    let none_locals = used_locals(none_body);
+
+    if moves_option {
+        match place_root_local(cond_expr) {
+            Some(root) if some_locals.contains(&root) || none_locals.contains(&root) => return true,
+            _ => {},
+        }
+    }
+
+    match (mutated_variables(some_body, cx), mutated_variables(none_body, cx)) {
+        (Some(some_mutated), Some(none_mutated)) => {
+            some_mutated.iter().any(|id| none_locals.contains(id))
+                || none_mutated.iter().any(|id| some_locals.contains(id))
+        },
+        _ => true,
+    }
+}
+
 /// If this expression is the option if let/else construct we're detecting, then
 /// this function returns an `OptionIfLetElseOccurence` struct with details if
 /// this construct is found, or None if this construct is not found.
-fn detect_option_if_let_else(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<OptionIfLetElseOccurence> {
+fn detect_option_if_let_else<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<OptionIfLetElseOccurence> {
     if_chain! {
         if !utils::in_macro(expr.span); // Don't lint macros, because it behaves weirdly
         if let ExprKind::Match(cond_expr, arms, MatchSource::IfLetDesugar{contains_else_clause: true}) = &expr.kind;
         if arms.len() == 2;
+        // `map_or` shouldn't be used for its side effects only
+        if !cx.typeck_results().expr_ty(expr).is_unit();
         if !is_result_ok(cx, cond_expr); // Don't lint on Result::ok because a different lint does it already
         if let PatKind::TupleStruct(struct_qpath, &[inner_pat], _) = &arms[0].pat.kind;
         if utils::match_qpath(struct_qpath, &paths::OPTION_SOME);
@@ -219,12 +254,16 @@ fn detect_option_if_let_else(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Op
                 _ => "map_or",
             };
             let capture_name = id.name.to_ident_string();
-            let wrap_braces = should_wrap_in_braces(cx, expr);
             let (as_ref, as_mut) = match &cond_expr.kind {
                 ExprKind::AddrOf(_, Mutability::Not, _) => (true, false),
                 ExprKind::AddrOf(_, Mutability::Mut, _) => (false, true),
                 _ => (bind_annotation == &BindingAnnotation::Ref, bind_annotation == &BindingAnnotation::RefMut),
             };
+            let moves_option = !as_ref && !as_mut && !is_copy(cx, cx.typeck_results().expr_ty(cond_expr));
+            if has_borrow_conflicts(cx, cond_expr, moves_option, some_body, none_body) {
+                return None;
+            }
+            let wrap_braces = should_wrap_in_braces(cx, expr);
             let cond_expr = match &cond_expr.kind {
                 // Pointer dereferencing happens automatically, so we can omit it in the suggestion
                 ExprKind::Unary(UnOp::UnDeref, expr) | ExprKind::AddrOf(_, _, expr) => expr,
@@ -244,7 +283,7 @@ fn detect_option_if_let_else(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Op
 }
 
 impl<'a> LateLintPass<'a> for OptionIfLetElse {
-    fn check_expr(&mut self, cx: &LateContext<'a>, expr: &Expr<'_>) {
+    fn check_expr(&mut self, cx: &LateContext<'a>, expr: &'a Expr<'a>) {
         if let Some(detection) = detect_option_if_let_else(cx, expr) {
             span_lint_and_sugg(
                 cx,
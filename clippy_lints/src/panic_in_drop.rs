@@ -0,0 +1,81 @@
+use crate::panic_in_extern_fn::find_panics;
+use crate::utils::{match_def_path, paths, span_lint_and_then, trait_ref_of_method};
+use if_chain::if_chain;
+use rustc_hir::{ImplItem, ImplItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Drop::drop` implementations which may panic, i.e. which call
+    /// `unwrap` or `expect` on an `Option` or `Result`, index into something or use a panicking
+    /// macro like `panic!` or `assert!`.
+    ///
+    /// **Why is this bad?** Values are also dropped while unwinding from a panic, and panicking
+    /// again at that point aborts the whole process.
+    ///
+    /// **Known problems:** Indexing is linted even if it can't go out of bounds, and calls to
+    /// functions which may panic in turn are not detected.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// struct Logger(File);
+    ///
+    /// impl Drop for Logger {
+    ///     fn drop(&mut self) {
+    ///         self.0.flush().unwrap();
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// struct Logger(File);
+    ///
+    /// impl Drop for Logger {
+    ///     fn drop(&mut self) {
+    ///         if let Err(e) = self.0.flush() {
+    ///             eprintln!("failed to flush the log: {}", e);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub PANIC_IN_DROP,
+    pedantic,
+    "`Drop::drop` implementations which may panic"
+}
+
+declare_lint_pass!(PanicInDrop => [PANIC_IN_DROP]);
+
+impl<'tcx> LateLintPass<'tcx> for PanicInDrop {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx ImplItem<'_>) {
+        if_chain! {
+            if !impl_item.span.from_expansion();
+            if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
+            if let Some(trait_ref) = trait_ref_of_method(cx, impl_item.hir_id);
+            if let Some(trait_id) = trait_ref.trait_def_id();
+            if match_def_path(cx, trait_id, &paths::DROP_TRAIT);
+            then {
+                let panics = find_panics(cx, cx.tcx.hir().body(body_id));
+                if !panics.is_empty() {
+                    span_lint_and_then(
+                        cx,
+                        PANIC_IN_DROP,
+                        impl_item.span,
+                        "`Drop::drop` implementation may panic",
+                        move |diag| {
+                            diag.help(
+                                "panicking while unwinding from another panic aborts the process, \
+                                 consider handling or logging the error instead",
+                            );
+                            diag.span_note(panics, "potential panic(s)");
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
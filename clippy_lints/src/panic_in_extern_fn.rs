@@ -0,0 +1,135 @@
+use crate::utils::{is_type_diagnostic_item, match_function_call, paths, span_lint_and_then, walk_ptrs_ty};
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions with a foreign ABI, like `extern "C" fn`s, whose body
+    /// may panic, i.e. which call `unwrap` or `expect` on an `Option` or `Result`, index into
+    /// something or use a panicking macro like `panic!` or `assert!`. Everything inside of a
+    /// closure passed to `std::panic::catch_unwind` is ignored.
+    ///
+    /// **Why is this bad?** These functions are meant to be called from foreign code, and
+    /// unwinding out of a Rust function into foreign code is undefined behavior.
+    ///
+    /// **Known problems:** Indexing is linted even if it can't go out of bounds, and calls to
+    /// functions which may panic in turn are not detected.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// extern "C" fn parse(input: u8) -> u8 {
+    ///     std::char::from_digit(u32::from(input), 10).unwrap() as u8
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// extern "C" fn parse(input: u8) -> u8 {
+    ///     std::panic::catch_unwind(|| std::char::from_digit(u32::from(input), 10).unwrap() as u8)
+    ///         .unwrap_or_else(|_| std::process::abort())
+    /// }
+    /// ```
+    pub PANIC_IN_EXTERN_FN,
+    pedantic,
+    "functions with a foreign ABI which may unwind into foreign code"
+}
+
+declare_lint_pass!(PanicInExternFn => [PANIC_IN_EXTERN_FN]);
+
+impl<'tcx> LateLintPass<'tcx> for PanicInExternFn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        fn_kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        _: HirId,
+    ) {
+        if span.from_expansion() {
+            return;
+        }
+        let abi = match fn_kind.header() {
+            Some(header) => header.abi,
+            None => return,
+        };
+        if matches!(
+            abi,
+            Abi::Rust | Abi::RustCall | Abi::RustIntrinsic | Abi::PlatformIntrinsic
+        ) {
+            return;
+        }
+        let panics = find_panics(cx, body);
+        if !panics.is_empty() {
+            span_lint_and_then(
+                cx,
+                PANIC_IN_EXTERN_FN,
+                span,
+                &format!("`extern \"{}\"` function may panic", abi.name()),
+                move |diag| {
+                    diag.help(
+                        "unwinding into foreign code is undefined behavior, consider wrapping the body in \
+                         `std::panic::catch_unwind` and aborting or returning an error code on panic",
+                    );
+                    diag.span_note(panics, "potential panic(s)");
+                },
+            );
+        }
+    }
+}
+
+/// Returns the spans of the expressions in the body which may panic, not looking into closures
+/// passed to `catch_unwind`.
+pub(crate) fn find_panics<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) -> Vec<Span> {
+    let mut visitor = FindPanics { cx, panics: Vec::new() };
+    visitor.visit_body(body);
+    visitor.panics
+}
+
+struct FindPanics<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    panics: Vec<Span>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FindPanics<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if match_function_call(self.cx, expr, &paths::CATCH_UNWIND).is_some() {
+            return;
+        }
+        if match_function_call(self.cx, expr, &paths::BEGIN_PANIC).is_some()
+            || match_function_call(self.cx, expr, &paths::BEGIN_PANIC_FMT).is_some()
+        {
+            // point at the outermost macro call, e.g. `assert!(..)`
+            let span = expr.span.source_callsite();
+            if !self.panics.contains(&span) {
+                self.panics.push(span);
+            }
+        } else if !expr.span.from_expansion() {
+            match expr.kind {
+                ExprKind::MethodCall(path, _, args, _)
+                    if path.ident.name == sym!(unwrap) || path.ident.name == sym!(expect) =>
+                {
+                    let recv_ty = walk_ptrs_ty(self.cx.typeck_results().expr_ty(&args[0]));
+                    if is_type_diagnostic_item(self.cx, recv_ty, sym!(option_type))
+                        || is_type_diagnostic_item(self.cx, recv_ty, sym!(result_type))
+                    {
+                        self.panics.push(expr.span);
+                    }
+                },
+                ExprKind::Index(..) => self.panics.push(expr.span),
+                _ => {},
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::utils::{match_type, paths, span_lint_and_sugg, walk_ptrs_ty};
+use crate::utils::{match_def_path, match_type, paths, snippet, span_lint_and_sugg, span_lint_and_then, walk_ptrs_ty};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
@@ -38,10 +38,41 @@ declare_clippy_lint! {
     "calling `push` with file system root on `PathBuf` can overwrite it"
 }
 
-declare_lint_pass!(PathBufPushOverwrite => [PATH_BUF_PUSH_OVERWRITE]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `Path::join` with a string literal which is an
+    /// absolute path, i.e. which starts with `/`, or on Windows with `\` or a drive prefix like
+    /// `C:`.
+    ///
+    /// **Why is this bad?** If the argument of `join` is an absolute path, it replaces the path
+    /// it is joined to, so `base.join("/etc/passwd")` is just `/etc/passwd`. This is surprising and
+    /// can let the path escape the intended base directory.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::path::Path;
+    ///
+    /// let config = Path::new("/home/user").join("/.config");
+    /// assert_eq!(config, Path::new("/.config"));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::path::Path;
+    ///
+    /// let config = Path::new("/home/user").join(".config");
+    /// assert_eq!(config, Path::new("/home/user/.config"));
+    /// ```
+    pub JOIN_ABSOLUTE_PATH,
+    style,
+    "calling `Path::join` with an absolute path, which discards the base path"
+}
+
+declare_lint_pass!(PathBufPushOverwrite => [PATH_BUF_PUSH_OVERWRITE, JOIN_ABSOLUTE_PATH]);
 
 impl<'tcx> LateLintPass<'tcx> for PathBufPushOverwrite {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        check_join(cx, expr);
         if_chain! {
             if let ExprKind::MethodCall(ref path, _, ref args, _) = expr.kind;
             if path.ident.name == sym!(push);
@@ -69,3 +100,49 @@ impl<'tcx> LateLintPass<'tcx> for PathBufPushOverwrite {
         }
     }
 }
+
+fn check_join(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if_chain! {
+        if !expr.span.from_expansion();
+        if let ExprKind::MethodCall(_, _, ref args, _) = expr.kind;
+        if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+        if match_def_path(cx, def_id, &paths::PATH_JOIN);
+        if let ExprKind::Lit(ref lit) = args[1].kind;
+        if let LitKind::Str(ref path_lit, _) = lit.node;
+        let path_lit = path_lit.as_str();
+        // `\` and drive prefixes are only special on Windows, elsewhere they are part of file names
+        let is_windows = cx.sess().target.target.options.is_like_windows;
+        let is_separator = |c: char| c == '/' || (is_windows && c == '\\');
+        let has_root = path_lit.starts_with(is_separator);
+        if has_root || (is_windows && has_drive_prefix(&path_lit));
+        then {
+            span_lint_and_then(
+                cx,
+                JOIN_ABSOLUTE_PATH,
+                args[1].span,
+                "argument to `Path::join` is an absolute path",
+                |diag| {
+                    diag.note(&format!(
+                        "joining an absolute path replaces the whole path, so `{}` is discarded",
+                        snippet(cx, args[0].span, "..")
+                    ));
+                    if has_root {
+                        diag.span_suggestion(
+                            args[1].span,
+                            "remove the leading separator to join a relative path",
+                            format!("{:?}", path_lit.trim_start_matches(is_separator)),
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                    diag.help("if the absolute path is intended, use `PathBuf::from` instead");
+                },
+            );
+        }
+    }
+}
+
+/// Checks whether the path starts with a Windows drive prefix like `C:`.
+fn has_drive_prefix(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
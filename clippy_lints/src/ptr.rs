@@ -2,10 +2,11 @@
 
 use crate::utils::ptr::get_spans;
 use crate::utils::{
-    is_allowed, is_type_diagnostic_item, match_qpath, match_type, paths, snippet_opt, span_lint, span_lint_and_sugg,
-    span_lint_and_then, walk_ptrs_hir_ty,
+    is_allowed, is_type_diagnostic_item, match_def_path, match_qpath, match_type, paths, snippet_opt, span_lint,
+    span_lint_and_sugg, span_lint_and_then, walk_ptrs_hir_ty,
 };
 use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{
     BinOpKind, BodyId, Expr, ExprKind, FnDecl, FnRetTy, GenericArg, HirId, ImplItem, ImplItemKind, Item, ItemKind,
@@ -106,7 +107,29 @@ declare_clippy_lint! {
     "fns that create mutable refs from immutable ref args"
 }
 
-declare_lint_pass!(Ptr => [PTR_ARG, CMP_NULL, MUT_FROM_REF]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for null pointers passed as arguments to functions which require
+    /// them to be non-null, like `std::slice::from_raw_parts` or `std::ptr::read`.
+    ///
+    /// **Why is this bad?** Passing a null pointer to these functions is undefined behavior, even
+    /// if the length or the count is zero.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```ignore
+    /// unsafe { std::slice::from_raw_parts(ptr::null(), 0); }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// unsafe { std::slice::from_raw_parts(NonNull::dangling().as_ptr(), 0); }
+    /// ```
+    pub INVALID_NULL_PTR_USAGE,
+    correctness,
+    "null pointers passed to functions which require non-null pointers"
+}
+
+declare_lint_pass!(Ptr => [PTR_ARG, CMP_NULL, MUT_FROM_REF, INVALID_NULL_PTR_USAGE]);
 
 impl<'tcx> LateLintPass<'tcx> for Ptr {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
@@ -148,6 +171,61 @@ impl<'tcx> LateLintPass<'tcx> for Ptr {
                     "Comparing with null is better expressed by the `.is_null()` method",
                 );
             }
+        } else if let ExprKind::Call(ref fun, ref args) = expr.kind {
+            check_invalid_ptr_usage(cx, fun, args);
+        }
+    }
+}
+
+/// Functions which require some of their pointer arguments to be non-null, with the indices of
+/// these arguments, and whether a dangling pointer is a valid replacement for a null pointer,
+/// e.g. because it is only accessed for a length of zero.
+const INVALID_NULL_PTR_USAGE_TABLE: [(&[&str], &[usize], bool); 17] = [
+    (&paths::BOX_FROM_RAW, &[0], false),
+    (&paths::CSTR_FROM_PTR, &[0], false),
+    (&paths::NON_NULL_NEW_UNCHECKED, &[0], true),
+    (&paths::PTR_COPY, &[0, 1], true),
+    (&paths::PTR_COPY_NONOVERLAPPING, &[0, 1], true),
+    (&paths::PTR_READ, &[0], false),
+    (&paths::PTR_READ_UNALIGNED, &[0], false),
+    (&paths::PTR_READ_VOLATILE, &[0], false),
+    (&paths::PTR_REPLACE, &[0], false),
+    (&paths::PTR_SWAP, &[0, 1], false),
+    (&paths::PTR_SWAP_NONOVERLAPPING, &[0, 1], true),
+    (&paths::PTR_WRITE, &[0], false),
+    (&paths::PTR_WRITE_BYTES, &[0], true),
+    (&paths::PTR_WRITE_UNALIGNED, &[0], false),
+    (&paths::PTR_WRITE_VOLATILE, &[0], false),
+    (&paths::SLICE_FROM_RAW_PARTS, &[0], true),
+    (&paths::SLICE_FROM_RAW_PARTS_MUT, &[0], true),
+];
+
+fn check_invalid_ptr_usage<'tcx>(cx: &LateContext<'tcx>, fun: &Expr<'_>, args: &[Expr<'_>]) {
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = fun.kind;
+        if let Some(fun_def_id) = cx.qpath_res(qpath, fun.hir_id).opt_def_id();
+        if let Some(&(_, arg_indices, dangling_is_valid)) = INVALID_NULL_PTR_USAGE_TABLE
+            .iter()
+            .find(|&&(path, _, _)| match_def_path(cx, fun_def_id, path));
+        then {
+            for arg in arg_indices.iter().filter_map(|&i| args.get(i)) {
+                if !is_null_path(arg) && !is_null_cast(arg) {
+                    continue;
+                }
+                if dangling_is_valid {
+                    span_lint_and_sugg(
+                        cx,
+                        INVALID_NULL_PTR_USAGE,
+                        arg.span,
+                        "pointer must be non-null",
+                        "change this to",
+                        "core::ptr::NonNull::dangling().as_ptr()".to_string(),
+                        Applicability::MachineApplicable,
+                    );
+                } else {
+                    span_lint(cx, INVALID_NULL_PTR_USAGE, arg.span, "pointer must be non-null");
+                }
+            }
         }
     }
 }
@@ -304,6 +382,21 @@ fn get_rptr_lm<'tcx>(ty: &'tcx Ty<'tcx>) -> Option<(&'tcx Lifetime, Mutability,
     }
 }
 
+/// Checks whether the expression is a cast of `0` to a pointer, like `0 as *const u8`.
+fn is_null_cast(expr: &Expr<'_>) -> bool {
+    if_chain! {
+        if let ExprKind::Cast(ref inner, ref ty) = expr.kind;
+        if let TyKind::Ptr(_) = ty.kind;
+        if let ExprKind::Lit(ref lit) = inner.kind;
+        if let LitKind::Int(0, _) = lit.node;
+        then {
+            true
+        } else {
+            false
+        }
+    }
+}
+
 fn is_null_path(expr: &Expr<'_>) -> bool {
     if let ExprKind::Call(ref pathexp, ref args) = expr.kind {
         if args.is_empty() {
@@ -1,17 +1,21 @@
 use crate::consts::{constant, Constant};
 use if_chain::if_chain;
-use rustc_ast::ast::RangeLimits;
+use rustc_ast::ast::{RangeLimits, UintTy};
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
+use rustc_hir::{BinOpKind, Expr, ExprKind, PatKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::source_map::Spanned;
+use rustc_span::source_map::{Span, Spanned};
 use std::cmp::Ordering;
 
 use crate::utils::sugg::Sugg;
-use crate::utils::{get_parent_expr, is_integer_const, snippet, snippet_opt, span_lint, span_lint_and_then};
-use crate::utils::{higher, SpanlessEq};
+use crate::utils::{
+    get_parent_expr, get_trait_def_id, implements_trait, in_constant, in_macro, is_integer_const,
+    multispan_sugg_with_applicability, snippet, snippet_opt, snippet_with_applicability, span_lint,
+    span_lint_and_help, span_lint_and_sugg, span_lint_and_then,
+};
+use crate::utils::{higher, paths, SpanlessEq};
 
 declare_clippy_lint! {
     /// **What it does:** Checks for zipping a collection with the range of
@@ -128,11 +132,66 @@ declare_clippy_lint! {
     "reversing the limits of range expressions, resulting in empty ranges"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for expressions like `x >= 3 && x < 8` that could
+    /// be more readably expressed as `(3..8).contains(&x)`.
+    ///
+    /// **Why is this bad?** `contains` expresses the intent better and has fewer
+    /// failure modes (such as fencepost errors or using `||` instead of `&&`).
+    ///
+    /// **Known problems:** For floats, the negated form `x < 3.0 || x > 8.0` is `false` if `x`
+    /// is NaN, while `!(3.0..=8.0).contains(&x)` is `true`.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// // given
+    /// let x = 6;
+    ///
+    /// assert!(x >= 3 && x < 8);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = 6;
+    /// assert!((3..8).contains(&x));
+    /// ```
+    pub MANUAL_RANGE_CONTAINS,
+    style,
+    "manually reimplementing {`Range`, `RangeInclusive`}`::contains`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for zipping an iterator with the unbounded range `0..`.
+    ///
+    /// **Why is this bad?** The code is better expressed with `.enumerate()`.
+    ///
+    /// **Known problems:** `enumerate` yields the index first, so `iter.zip(0..)` can only be
+    /// rewritten automatically when its tuple pattern can be swapped, e.g. in a `for` loop.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let v = vec![1];
+    /// for (i, x) in (0..).zip(&v) {}
+    /// for (x, i) in v.iter().zip(0..) {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![1];
+    /// for (i, x) in v.iter().enumerate() {}
+    /// for (i, x) in v.iter().enumerate() {}
+    /// ```
+    pub MANUAL_ENUMERATE,
+    style,
+    "zipping an iterator with `0..` when `enumerate()` would do"
+}
+
 declare_lint_pass!(Ranges => [
     RANGE_ZIP_WITH_LEN,
     RANGE_PLUS_ONE,
     RANGE_MINUS_ONE,
     REVERSED_EMPTY_RANGES,
+    MANUAL_RANGE_CONTAINS,
+    MANUAL_ENUMERATE,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Ranges {
@@ -164,15 +223,198 @@ impl<'tcx> LateLintPass<'tcx> for Ranges {
                                             snippet(cx, iter_args[0].span, "_")));
                     }
                 }
+                check_zip_with_range_from(cx, expr, args);
             }
         }
 
+        if let ExprKind::Binary(ref op, ref l, ref r) = expr.kind {
+            check_possible_range_contains(cx, op.node, l, r, expr);
+        }
+
         check_exclusive_range_plus_one(cx, expr);
         check_inclusive_range_minus_one(cx, expr);
         check_reversed_empty_range(cx, expr);
     }
 }
 
+fn check_possible_range_contains(cx: &LateContext<'_>, op: BinOpKind, l: &Expr<'_>, r: &Expr<'_>, expr: &Expr<'_>) {
+    if in_macro(expr.span) || in_constant(cx, expr.hir_id) {
+        return;
+    }
+    let combine_and = match op {
+        BinOpKind::And => true,
+        BinOpKind::Or => false,
+        _ => return,
+    };
+    if_chain! {
+        if let Some(l_check) = check_range_bound(cx, l);
+        if let Some(r_check) = check_range_bound(cx, r);
+        // both checks must compare the same value, in opposite directions
+        if l_check.ordering != r_check.ordering;
+        if SpanlessEq::new(cx).ignore_fn().eq_expr(l_check.value, r_check.value);
+        let ty = cx.typeck_results().expr_ty(l_check.value);
+        if ty.is_integral() || ty.is_floating_point();
+        then {
+            // the lower bound is checked with `x >= lo` or, negated, with `x < lo`
+            let lower_ordering = if combine_and { Ordering::Greater } else { Ordering::Less };
+            let (lower, upper) = if l_check.ordering == lower_ordering {
+                (&l_check, &r_check)
+            } else {
+                (&r_check, &l_check)
+            };
+            // otherwise the range is empty and the expression constant
+            if Constant::partial_cmp(cx.tcx, ty, &lower.bound, &upper.bound) != Some(Ordering::Less) {
+                return;
+            }
+            // only `x >= lo` (or its negation `x < lo`) can be expressed with a range
+            if lower.inclusive != combine_and {
+                return;
+            }
+            let range_op = if upper.inclusive == combine_and { "..=" } else { ".." };
+            let range_name = if range_op == "..=" { "RangeInclusive" } else { "Range" };
+
+            let mut applicability = if !combine_and && ty.is_floating_point() {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            let value = Sugg::hir_with_applicability(cx, lower.value, "x", &mut applicability);
+            let lo = snippet_with_applicability(cx, lower.bound_span, "lo", &mut applicability);
+            let hi = snippet_with_applicability(cx, upper.bound_span, "hi", &mut applicability);
+            // `0...1.` doesn't parse
+            let space = if lo.ends_with('.') { " " } else { "" };
+            let negation = if combine_and { "" } else { "!" };
+            span_lint_and_sugg(
+                cx,
+                MANUAL_RANGE_CONTAINS,
+                expr.span,
+                &format!("manual `{}{}::contains` implementation", negation, range_name),
+                "use",
+                format!("{}({}{}{}{}).contains({})", negation, lo, space, range_op, hi, value.addr()),
+                applicability,
+            );
+        }
+    }
+}
+
+fn check_zip_with_range_from<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, args: &'tcx [Expr<'_>]) {
+    if in_macro(expr.span) {
+        return;
+    }
+    // `(0..).zip(iter)` yields the same items as `iter.enumerate()`, `iter.zip(0..)` swaps them
+    let (iter, swapped) = if is_zero_range_from(cx, &args[0]) {
+        (&args[1], false)
+    } else if is_zero_range_from(cx, &args[1]) {
+        (&args[0], true)
+    } else {
+        return;
+    };
+
+    let mut applicability = Applicability::MachineApplicable;
+    let mut iter_snippet = Sugg::hir_with_applicability(cx, iter, "_", &mut applicability)
+        .maybe_par()
+        .to_string();
+    let impls_iterator = get_trait_def_id(cx, &paths::ITERATOR).map_or(false, |id| {
+        implements_trait(cx, cx.typeck_results().expr_ty(iter), id, &[])
+    });
+    if !impls_iterator {
+        iter_snippet.push_str(".into_iter()");
+    }
+    let mut suggestions = vec![(expr.span, format!("{}.enumerate()", iter_snippet))];
+
+    if swapped {
+        // the tuple can only be reordered where it is destructured by a `for` loop
+        if_chain! {
+            if let Some(parent) = get_parent_expr(cx, expr);
+            if let Some(for_loop) = get_parent_expr(cx, parent);
+            if let Some((pat, arg, _)) = higher::for_loop(for_loop);
+            if arg.hir_id == expr.hir_id;
+            if let PatKind::Tuple([item_pat, index_pat], None) = pat.kind;
+            then {
+                let item = snippet_with_applicability(cx, item_pat.span, "_", &mut applicability);
+                let index = snippet_with_applicability(cx, index_pat.span, "_", &mut applicability);
+                suggestions.push((pat.span, format!("({}, {})", index, item)));
+            } else {
+                span_lint_and_help(
+                    cx,
+                    MANUAL_ENUMERATE,
+                    expr.span,
+                    "zipping an iterator with `0..`",
+                    None,
+                    &format!(
+                        "use `{}.enumerate()` and swap the elements of the resulting tuples",
+                        iter_snippet
+                    ),
+                );
+                return;
+            }
+        }
+    }
+
+    span_lint_and_then(
+        cx,
+        MANUAL_ENUMERATE,
+        expr.span,
+        "zipping an iterator with `0..`",
+        |diag| {
+            multispan_sugg_with_applicability(diag, "use `enumerate` instead", applicability, suggestions);
+        },
+    );
+}
+
+/// Checks whether `expr` is the range `0..` of `usize`, as yielded by `enumerate`.
+fn is_zero_range_from(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if_chain! {
+        if let Some(higher::Range { start: Some(start), end: None, .. }) = higher::range(cx, expr);
+        if is_integer_const(cx, start, 0);
+        then {
+            cx.typeck_results().expr_ty(start).kind == ty::Uint(UintTy::Usize)
+        } else {
+            false
+        }
+    }
+}
+
+/// A comparison of a value with a constant bound, normalized to `value <op> bound`.
+struct RangeBoundCheck<'a> {
+    value: &'a Expr<'a>,
+    bound: Constant,
+    bound_span: Span,
+    /// `Greater` if the value is checked to be greater than the bound, `Less` otherwise
+    ordering: Ordering,
+    inclusive: bool,
+}
+
+fn check_range_bound<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<RangeBoundCheck<'a>> {
+    if let ExprKind::Binary(ref op, ref l, ref r) = expr.kind {
+        let (ordering, inclusive) = match op.node {
+            BinOpKind::Gt => (Ordering::Greater, false),
+            BinOpKind::Ge => (Ordering::Greater, true),
+            BinOpKind::Lt => (Ordering::Less, false),
+            BinOpKind::Le => (Ordering::Less, true),
+            _ => return None,
+        };
+        if let Some((bound, _)) = constant(cx, cx.typeck_results(), r) {
+            return Some(RangeBoundCheck {
+                value: l,
+                bound,
+                bound_span: r.span,
+                ordering,
+                inclusive,
+            });
+        } else if let Some((bound, _)) = constant(cx, cx.typeck_results(), l) {
+            return Some(RangeBoundCheck {
+                value: r,
+                bound,
+                bound_span: l.span,
+                ordering: ordering.reverse(),
+                inclusive,
+            });
+        }
+    }
+    None
+}
+
 // exclusive range plus one: `x..(y+1)`
 fn check_exclusive_range_plus_one(cx: &LateContext<'_>, expr: &Expr<'_>) {
     if_chain! {
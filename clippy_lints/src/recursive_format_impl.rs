@@ -0,0 +1,230 @@
+use crate::utils::{match_def_path, match_function_call, paths, span_lint, trait_ref_of_method};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, ImplItem, ImplItemKind, MatchSource, PatKind, QPath, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Display` and `Debug` implementations which format `self`
+    /// with the trait they implement, e.g. by calling `self.to_string()` or `Display::fmt(self, f)`
+    /// in `Display::fmt` or by formatting `self` with `{}` in it.
+    ///
+    /// **Why is this bad?** The formatting calls the implementation again, which recurses
+    /// infinitely and overflows the stack.
+    ///
+    /// **Known problems:** Only direct uses of `self` are detected, not uses through other
+    /// functions or bindings.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,no_run
+    /// use std::fmt;
+    ///
+    /// struct Structure(i32);
+    ///
+    /// impl fmt::Display for Structure {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "{}", self.to_string())
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::fmt;
+    ///
+    /// struct Structure(i32);
+    ///
+    /// impl fmt::Display for Structure {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "{}", self.0)
+    ///     }
+    /// }
+    /// ```
+    pub RECURSIVE_FORMAT_IMPL,
+    correctness,
+    "`Display` or `Debug` implementations which format `self` with the same trait"
+}
+
+declare_lint_pass!(RecursiveFormatImpl => [RECURSIVE_FORMAT_IMPL]);
+
+impl<'tcx> LateLintPass<'tcx> for RecursiveFormatImpl {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx ImplItem<'_>) {
+        if_chain! {
+            if !impl_item.span.from_expansion();
+            if impl_item.ident.name == sym!(fmt);
+            if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
+            if let Some(trait_ref) = trait_ref_of_method(cx, impl_item.hir_id);
+            if let Some(trait_id) = trait_ref.trait_def_id();
+            if let Some(format_trait) = FormatTrait::from_def_id(cx, trait_id);
+            let body = cx.tcx.hir().body(body_id);
+            if let Some(param) = body.params.first();
+            if let PatKind::Binding(_, self_id, _, None) = param.pat.kind;
+            then {
+                let mut visitor = SelfFormatVisitor {
+                    cx,
+                    format_trait,
+                    self_id,
+                };
+                visitor.visit_body(body);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum FormatTrait {
+    Display,
+    Debug,
+}
+
+impl FormatTrait {
+    fn from_def_id(cx: &LateContext<'_>, def_id: DefId) -> Option<Self> {
+        if match_def_path(cx, def_id, &paths::DISPLAY_TRAIT) {
+            Some(Self::Display)
+        } else if match_def_path(cx, def_id, &paths::DEBUG_TRAIT) {
+            Some(Self::Debug)
+        } else {
+            None
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Display => "Display",
+            Self::Debug => "Debug",
+        }
+    }
+
+    fn fmt_method(self) -> &'static [&'static str] {
+        match self {
+            Self::Display => &paths::DISPLAY_FMT_METHOD,
+            Self::Debug => &paths::DEBUG_FMT_METHOD,
+        }
+    }
+}
+
+struct SelfFormatVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    format_trait: FormatTrait,
+    /// The `self` parameter of the `fmt` method.
+    self_id: HirId,
+}
+
+impl<'a, 'tcx> SelfFormatVisitor<'a, 'tcx> {
+    /// Checks whether the expression is `self`, possibly behind built-in references or
+    /// dereferences.
+    fn is_self(&self, mut expr: &Expr<'_>) -> bool {
+        loop {
+            match expr.kind {
+                ExprKind::AddrOf(BorrowKind::Ref, _, inner) => expr = inner,
+                ExprKind::Unary(UnOp::UnDeref, inner) if self.cx.typeck_results().expr_ty(inner).is_ref() => {
+                    expr = inner;
+                },
+                ExprKind::Path(QPath::Resolved(None, path)) => return path.res == Res::Local(self.self_id),
+                _ => return false,
+            }
+        }
+    }
+
+    fn is_fmt_method(&self, def_id: DefId) -> bool {
+        match_def_path(self.cx, def_id, self.format_trait.fmt_method())
+    }
+
+    /// Checks whether the expression is the `match` of the `format_args!` expansion and formats
+    /// `self` with the implemented trait.
+    fn formats_self_in_args(&self, expr: &Expr<'_>) -> bool {
+        if_chain! {
+            if let ExprKind::Match(scrutinee, arms, MatchSource::Normal) = expr.kind;
+            if let ExprKind::Tup(values) = scrutinee.kind;
+            if let [arm] = arms;
+            if let PatKind::Tuple(pats, None) = arm.pat.kind;
+            if let ExprKind::Array(args) = arm.body.kind;
+            then {
+                args.iter().any(|arg| {
+                    if_chain! {
+                        // `ArgumentV1::new(argN, Display::fmt)`
+                        if let Some(new_args) = match_function_call(self.cx, arg, &paths::FMT_ARGUMENTV1_NEW);
+                        if let [value, fmt] = new_args;
+                        if let ExprKind::Path(ref fmt_path) = fmt.kind;
+                        if let Some(fmt_id) = self.cx.qpath_res(fmt_path, fmt.hir_id).opt_def_id();
+                        if self.is_fmt_method(fmt_id);
+                        if let ExprKind::Path(QPath::Resolved(None, value_path)) = value.kind;
+                        if let Res::Local(binding_id) = value_path.res;
+                        if let Some(index) = pats.iter().position(|pat| pat.hir_id == binding_id);
+                        then {
+                            values.get(index).map_or(false, |value| self.is_self(value))
+                        } else {
+                            false
+                        }
+                    }
+                })
+            } else {
+                false
+            }
+        }
+    }
+
+    fn lint(&self, expr: &Expr<'_>, what: &str) {
+        span_lint(
+            self.cx,
+            RECURSIVE_FORMAT_IMPL,
+            expr.span.source_callsite(),
+            &format!(
+                "{} in `impl {}` will call `fmt` again and recurse infinitely",
+                what,
+                self.format_trait.name()
+            ),
+        );
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for SelfFormatVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::MethodCall(path, _, args, _) if self.is_self(&args[0]) => {
+                if let Some(def_id) = self.cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    if self.format_trait == FormatTrait::Display
+                        && match_def_path(self.cx, def_id, &paths::TO_STRING_METHOD)
+                    {
+                        self.lint(expr, "calling `to_string()` on `self`");
+                        return;
+                    } else if self.is_fmt_method(def_id) {
+                        self.lint(expr, &format!("calling `{}()` on `self`", path.ident));
+                        return;
+                    }
+                }
+            },
+            ExprKind::Call(func, args) if !args.is_empty() && self.is_self(&args[0]) => {
+                if_chain! {
+                    if let ExprKind::Path(ref qpath) = func.kind;
+                    if let Some(def_id) = self.cx.qpath_res(qpath, func.hir_id).opt_def_id();
+                    if self.is_fmt_method(def_id);
+                    then {
+                        self.lint(
+                            expr,
+                            &format!("calling `{}::fmt` on `self`", self.format_trait.name()),
+                        );
+                        return;
+                    }
+                }
+            },
+            _ => {
+                if self.formats_self_in_args(expr) {
+                    self.lint(expr, "formatting `self`");
+                    return;
+                }
+            },
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
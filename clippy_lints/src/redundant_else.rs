@@ -0,0 +1,132 @@
+use crate::utils::{in_macro, indent_of, snippet_block, span_lint_and_then};
+use rustc_ast::ast::{Block, Expr, ExprKind, StmtKind};
+use rustc_ast::util::classify::expr_requires_semi_to_be_stmt;
+use rustc_errors::Applicability;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `else` blocks that can be removed without changing semantics,
+    /// because the `if` block always diverges with `return`, `break`, `continue` or `Err(..)?`.
+    ///
+    /// **Why is this bad?** The `else` block adds needless indentation and rightward drift.
+    ///
+    /// **Known problems:** Moving the contents out of the `else` block changes the scope of
+    /// the variables declared in it.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # fn is_done() -> bool { true }
+    /// # fn do_work() {}
+    /// for _ in 0..10 {
+    ///     if is_done() {
+    ///         break;
+    ///     } else {
+    ///         do_work();
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn is_done() -> bool { true }
+    /// # fn do_work() {}
+    /// for _ in 0..10 {
+    ///     if is_done() {
+    ///         break;
+    ///     }
+    ///     do_work();
+    /// }
+    /// ```
+    pub REDUNDANT_ELSE,
+    pedantic,
+    "`else` branch that can be removed without changing semantics"
+}
+
+declare_lint_pass!(RedundantElse => [REDUNDANT_ELSE]);
+
+impl EarlyLintPass for RedundantElse {
+    fn check_block(&mut self, cx: &EarlyContext<'_>, block: &Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if in_macro(stmt.span) {
+                continue;
+            }
+            if let StmtKind::Expr(ref expr) = stmt.kind {
+                check_if(cx, expr, i + 1 == block.stmts.len());
+            }
+        }
+    }
+}
+
+fn check_if(cx: &EarlyContext<'_>, expr: &Expr, is_last_stmt: bool) {
+    let mut if_expr = expr;
+    // every `if` of an `else if` chain has to diverge for the final `else` to be redundant
+    while let ExprKind::If(_, ref then, Some(ref els)) = if_expr.kind {
+        if !block_diverges(then) {
+            return;
+        }
+        match els.kind {
+            ExprKind::If(..) => if_expr = els,
+            ExprKind::Block(ref els_block, None) => {
+                span_lint_and_then(cx, REDUNDANT_ELSE, els.span, "redundant else block", |diag| {
+                    diag.span_suggestion(
+                        els.span.with_lo(then.span.hi()),
+                        "remove the `else` block and move the contents out",
+                        else_contents(cx, expr, els_block, is_last_stmt),
+                        Applicability::MaybeIncorrect,
+                    );
+                });
+                return;
+            },
+            _ => return,
+        }
+    }
+}
+
+/// Returns the contents of the `else` block indented like the `if` expression.
+fn else_contents(cx: &EarlyContext<'_>, if_expr: &Expr, els_block: &Block, is_last_stmt: bool) -> String {
+    let (first, last) = match (els_block.stmts.first(), els_block.stmts.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return String::new(),
+    };
+    let mut contents = format!(
+        "\n{}{}",
+        " ".repeat(indent_of(cx, if_expr.span).unwrap_or(0)),
+        snippet_block(cx, first.span.to(last.span), "..", Some(if_expr.span))
+    );
+    // a trailing expression of type `()` needs a semicolon if other statements follow
+    if let StmtKind::Expr(ref tail) = last.kind {
+        if !is_last_stmt && expr_requires_semi_to_be_stmt(tail) {
+            contents.push(';');
+        }
+    }
+    contents
+}
+
+fn block_diverges(block: &Block) -> bool {
+    block.stmts.last().map_or(false, |stmt| match stmt.kind {
+        StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => expr_diverges(expr),
+        _ => false,
+    })
+}
+
+fn expr_diverges(expr: &Expr) -> bool {
+    match expr.kind {
+        ExprKind::Ret(_) | ExprKind::Break(..) | ExprKind::Continue(_) => true,
+        // `Err(..)?` always returns
+        ExprKind::Try(ref inner) => match inner.kind {
+            ExprKind::Call(ref func, _) => match func.kind {
+                ExprKind::Path(None, ref path) => path
+                    .segments
+                    .last()
+                    .map_or(false, |segment| segment.ident.as_str() == "Err"),
+                _ => false,
+            },
+            _ => false,
+        },
+        ExprKind::If(_, ref then, Some(ref els)) => block_diverges(then) && expr_diverges(els),
+        ExprKind::Match(_, ref arms) => !arms.is_empty() && arms.iter().all(|arm| expr_diverges(&arm.body)),
+        ExprKind::Block(ref block, None) => block_diverges(block),
+        ExprKind::Paren(ref inner) => expr_diverges(inner),
+        _ => false,
+    }
+}
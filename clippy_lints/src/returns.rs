@@ -131,7 +131,7 @@ impl Return {
                     if let Some(snippet) = snippet_opt(cx, inner_span) {
                         diag.span_suggestion(ret_span, "remove `return`", snippet, Applicability::MachineApplicable);
                     }
-                })
+                });
             },
             None => match replacement {
                 RetReplacement::Empty => {
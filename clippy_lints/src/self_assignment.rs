@@ -0,0 +1,63 @@
+use crate::utils::{in_macro, snippet, span_lint, SpanlessEq};
+use if_chain::if_chain;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for assignments of a place to itself, like `a = a` or
+    /// `self.x = self.x`.
+    ///
+    /// **Why is this bad?** The assignment has no effect. It is most likely a typo for assigning
+    /// a different field or variable.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Event {
+    ///     year: u32,
+    ///     month: u32,
+    /// }
+    ///
+    /// fn copy_date(event: &mut Event, other: &Event) {
+    ///     event.year = other.year;
+    ///     event.month = event.month;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # struct Event {
+    /// #     year: u32,
+    /// #     month: u32,
+    /// # }
+    /// fn copy_date(event: &mut Event, other: &Event) {
+    ///     event.year = other.year;
+    ///     event.month = other.month;
+    /// }
+    /// ```
+    pub SELF_ASSIGNMENT,
+    correctness,
+    "assigning a place to itself"
+}
+
+declare_lint_pass!(SelfAssignment => [SELF_ASSIGNMENT]);
+
+impl<'tcx> LateLintPass<'tcx> for SelfAssignment {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::Assign(lhs, rhs, _) = expr.kind;
+            // function calls may have side effects or return different places
+            if SpanlessEq::new(cx).ignore_fn().eq_expr(lhs, rhs);
+            then {
+                span_lint(
+                    cx,
+                    SELF_ASSIGNMENT,
+                    expr.span,
+                    &format!("self-assignment of `{}` to `{}`", snippet(cx, rhs.span, ".."), snippet(cx, lhs.span, "..")),
+                );
+            }
+        }
+    }
+}
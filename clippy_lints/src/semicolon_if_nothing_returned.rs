@@ -0,0 +1,62 @@
+use crate::utils::{snippet_with_macro_callsite, span_lint_and_sugg, sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Block, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Looks for blocks of expressions and fires if the last expression returns
+    /// `()` but is not followed by a semicolon.
+    ///
+    /// **Why is this bad?** The semicolon might be optional but when extending the block with new
+    /// code, it doesn't require a change in previous last line. It also makes it obvious that
+    /// the block doesn't evaluate to a value.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// fn main() {
+    ///     println!("Hello world")
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn main() {
+    ///     println!("Hello world");
+    /// }
+    /// ```
+    pub SEMICOLON_IF_NOTHING_RETURNED,
+    pedantic,
+    "add a semicolon if nothing is returned"
+}
+
+declare_lint_pass!(SemicolonIfNothingReturned => [SEMICOLON_IF_NOTHING_RETURNED]);
+
+impl<'tcx> LateLintPass<'tcx> for SemicolonIfNothingReturned {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if_chain! {
+            if !block.span.from_expansion();
+            if let Some(expr) = block.expr;
+            // filter out the desugared `for` loop
+            if !matches!(expr.kind, ExprKind::DropTemps(..));
+            if cx.typeck_results().expr_ty(expr).is_unit();
+            // blocks, `if`, `match` and loops are fine without a semicolon
+            if !snippet_with_macro_callsite(cx, expr.span, "}").ends_with('}');
+            then {
+                let sugg = sugg::Sugg::hir_with_macro_callsite(cx, expr, "..");
+                span_lint_and_sugg(
+                    cx,
+                    SEMICOLON_IF_NOTHING_RETURNED,
+                    expr.span,
+                    "consider adding a `;` to the last statement for consistent formatting",
+                    "add a `;` here",
+                    format!("{};", sugg),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::consts::{constant, Constant};
+use crate::utils::{sext, span_lint_and_sugg, sugg::Sugg};
+use if_chain::if_chain;
+use rustc_ast::ast::IntTy;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for casts of the remainder of a signed integer division to an
+    /// unsigned type, like `(i % len) as usize`, which is often used to compute an index.
+    ///
+    /// **Why is this bad?** The remainder has the sign of the dividend, so for a negative `i` it
+    /// is negative as well, and the cast wraps it around to a huge value. Used as an index, this
+    /// panics or accesses the wrong element. `rem_euclid` always returns a non-negative
+    /// remainder.
+    ///
+    /// **Known problems:** The lint doesn't know if the dividend is always non-negative, unless
+    /// it is a constant.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// fn wrapping_get(values: &[u8], i: isize) -> u8 {
+    ///     values[(i % values.len() as isize) as usize]
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn wrapping_get(values: &[u8], i: isize) -> u8 {
+    ///     values[i.rem_euclid(values.len() as isize) as usize]
+    /// }
+    /// ```
+    pub SIGNED_REM_AS_UNSIGNED,
+    pedantic,
+    "casting the remainder of a signed integer division to an unsigned type"
+}
+
+declare_lint_pass!(SignedRemAsUnsigned => [SIGNED_REM_AS_UNSIGNED]);
+
+impl<'tcx> LateLintPass<'tcx> for SignedRemAsUnsigned {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Cast(rem, _) = expr.kind;
+            if let ExprKind::Binary(op, lhs, rhs) = rem.kind;
+            if op.node == BinOpKind::Rem;
+            if let ty::Int(int_ty) = cx.typeck_results().expr_ty(rem).kind;
+            let cast_ty = cx.typeck_results().expr_ty(expr);
+            if let ty::Uint(_) = cast_ty.kind;
+            if !is_non_negative_constant(cx, lhs, int_ty);
+            then {
+                let mut applicability = Applicability::MaybeIncorrect;
+                let lhs = Sugg::hir_with_applicability(cx, lhs, "..", &mut applicability).maybe_par();
+                let rhs = Sugg::hir_with_applicability(cx, rhs, "..", &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    SIGNED_REM_AS_UNSIGNED,
+                    expr.span,
+                    &format!(
+                        "casting a signed remainder to `{}` wraps around if the dividend is negative",
+                        cast_ty
+                    ),
+                    "use `rem_euclid` to get a non-negative remainder",
+                    format!("{}.rem_euclid({}) as {}", lhs, rhs, cast_ty),
+                    applicability,
+                );
+            }
+        }
+    }
+}
+
+fn is_non_negative_constant(cx: &LateContext<'_>, expr: &Expr<'_>, int_ty: IntTy) -> bool {
+    match constant(cx, cx.typeck_results(), expr) {
+        Some((Constant::Int(value), _)) => sext(cx.tcx, value, int_ty) >= 0,
+        _ => false,
+    }
+}
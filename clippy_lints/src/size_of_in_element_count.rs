@@ -0,0 +1,165 @@
+use crate::utils::{match_def_path, paths, span_lint_and_help, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty, TypeAndMut};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `size_of::<T>()` or `size_of_val(..)` in the element count
+    /// argument of functions like `ptr::copy_nonoverlapping` or `slice::from_raw_parts`, and of
+    /// raw pointer methods like `add` or `copy_to`, where `T` is the type of the elements.
+    ///
+    /// **Why is this bad?** These functions expect a number of elements, not a number of bytes.
+    /// The count is multiplied by the size of the element type again, which accesses memory far
+    /// past the end of the buffer.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,no_run
+    /// # use std::ptr::copy_nonoverlapping;
+    /// # use std::mem::size_of;
+    /// const SIZE: usize = 128;
+    /// let x = [2u8; SIZE];
+    /// let mut y = [2u8; SIZE];
+    /// unsafe { copy_nonoverlapping(x.as_ptr(), y.as_mut_ptr(), size_of::<u8>() * SIZE) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::ptr::copy_nonoverlapping;
+    /// const SIZE: usize = 128;
+    /// let x = [2u8; SIZE];
+    /// let mut y = [2u8; SIZE];
+    /// unsafe { copy_nonoverlapping(x.as_ptr(), y.as_mut_ptr(), SIZE) };
+    /// ```
+    pub SIZE_OF_IN_ELEMENT_COUNT,
+    correctness,
+    "using `size_of::<T>()` or `size_of_val(..)` where a count of elements of `T` is expected"
+}
+
+declare_lint_pass!(SizeOfInElementCount => [SIZE_OF_IN_ELEMENT_COUNT]);
+
+/// Functions taking an element count, with the index of the count argument.
+const FUNCTIONS: [(&[&str], usize); 8] = [
+    (&paths::PTR_COPY, 2),
+    (&paths::PTR_COPY_NONOVERLAPPING, 2),
+    (&paths::PTR_SLICE_FROM_RAW_PARTS, 1),
+    (&paths::PTR_SLICE_FROM_RAW_PARTS_MUT, 1),
+    (&paths::PTR_SWAP_NONOVERLAPPING, 2),
+    (&paths::PTR_WRITE_BYTES, 2),
+    (&paths::SLICE_FROM_RAW_PARTS, 1),
+    (&paths::SLICE_FROM_RAW_PARTS_MUT, 1),
+];
+
+/// Raw pointer methods taking an element count, with the index of the count argument, counting
+/// the receiver.
+const METHODS: [(&str, usize); 11] = [
+    ("add", 1),
+    ("copy_from", 1),
+    ("copy_from_nonoverlapping", 1),
+    ("copy_to", 1),
+    ("copy_to_nonoverlapping", 1),
+    ("offset", 1),
+    ("sub", 1),
+    ("wrapping_add", 1),
+    ("wrapping_offset", 1),
+    ("wrapping_sub", 1),
+    ("write_bytes", 2),
+];
+
+impl<'tcx> LateLintPass<'tcx> for SizeOfInElementCount {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        if_chain! {
+            if let Some((count_expr, pointee_ty)) = get_count_and_pointee_ty(cx, expr);
+            if let Some(size_of_ty) = get_size_of_ty(cx, count_expr);
+            if size_of_ty == pointee_ty;
+            then {
+                span_lint_and_help(
+                    cx,
+                    SIZE_OF_IN_ELEMENT_COUNT,
+                    count_expr.span,
+                    &format!("found a count of bytes instead of a count of elements of `{}`", pointee_ty),
+                    None,
+                    "use a count of elements instead, it is multiplied by the size of the type",
+                );
+            }
+        }
+    }
+}
+
+/// Returns the element count argument and the element type of a call to one of the functions or
+/// methods taking an element count.
+fn get_count_and_pointee_ty<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+) -> Option<(&'tcx Expr<'tcx>, Ty<'tcx>)> {
+    match expr.kind {
+        ExprKind::Call(func, args) => {
+            if_chain! {
+                if let ExprKind::Path(ref qpath) = func.kind;
+                if let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id();
+                if let Some(&(_, index)) = FUNCTIONS.iter().find(|(path, _)| match_def_path(cx, def_id, path));
+                if let Some(count_expr) = args.get(index);
+                then {
+                    let pointee_ty = cx.typeck_results().node_substs(func.hir_id).type_at(0);
+                    Some((count_expr, pointee_ty))
+                } else {
+                    None
+                }
+            }
+        },
+        ExprKind::MethodCall(path, _, args, _) => {
+            if_chain! {
+                let name = path.ident.as_str();
+                if let Some(&(_, index)) = METHODS.iter().find(|(method, _)| *method == &*name);
+                if let ty::RawPtr(TypeAndMut { ty: pointee_ty, .. }) = cx.typeck_results().expr_ty(&args[0]).kind;
+                if let Some(count_expr) = args.get(index);
+                then {
+                    Some((count_expr, pointee_ty))
+                } else {
+                    None
+                }
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Returns the type whose size is multiplied into the count, if the count is built from
+/// `size_of::<T>()` or `size_of_val(..)` calls.
+fn get_size_of_ty<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<Ty<'tcx>> {
+    match expr.kind {
+        ExprKind::Call(func, args) => {
+            if_chain! {
+                if let ExprKind::Path(ref qpath) = func.kind;
+                if let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id();
+                then {
+                    if match_def_path(cx, def_id, &paths::MEM_SIZE_OF) {
+                        Some(cx.typeck_results().node_substs(func.hir_id).type_at(0))
+                    } else if match_def_path(cx, def_id, &paths::MEM_SIZE_OF_VAL) {
+                        // `size_of_val` of a slice or an array is the size of all its elements
+                        let ty = walk_ptrs_ty(cx.typeck_results().expr_ty(args.first()?));
+                        Some(ty.builtin_index().unwrap_or(ty))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            }
+        },
+        ExprKind::Binary(op, left, right) if op.node == BinOpKind::Mul => {
+            get_size_of_ty(cx, left).or_else(|| get_size_of_ty(cx, right))
+        },
+        // dividing a size by another size, like `size_of_val(..) / size_of::<T>()`, gives a count
+        ExprKind::Binary(op, left, right) if op.node == BinOpKind::Div && get_size_of_ty(cx, right).is_none() => {
+            get_size_of_ty(cx, left)
+        },
+        ExprKind::Cast(inner, _) => get_size_of_ty(cx, inner),
+        _ => None,
+    }
+}
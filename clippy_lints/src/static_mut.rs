@@ -0,0 +1,85 @@
+use crate::utils::span_lint_and_help;
+use rustc_ast::ast::{IntTy, UintTy};
+use rustc_hir::{Item, ItemKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `static mut` items.
+    ///
+    /// **Why is this bad?** Every access of a `static mut` is `unsafe`, and it's easy to cause
+    /// undefined behavior with them, e.g. by accessing them from multiple threads at once or by
+    /// holding two mutable references to them. Atomics, `Mutex`es and other synchronization
+    /// primitives can be put in a normal `static` instead and are safe to use.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// static mut COUNTER: usize = 0;
+    ///
+    /// fn increment() {
+    ///     unsafe { COUNTER += 1 };
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// fn increment() {
+    ///     COUNTER.fetch_add(1, Ordering::Relaxed);
+    /// }
+    /// ```
+    pub STATIC_MUT,
+    restriction,
+    "declaring a `static mut` item"
+}
+
+declare_lint_pass!(StaticMut => [STATIC_MUT]);
+
+impl<'tcx> LateLintPass<'tcx> for StaticMut {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if item.span.from_expansion() {
+            return;
+        }
+        if let ItemKind::Static(_, Mutability::Mut, _) = item.kind {
+            let ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(item.hir_id));
+            let help = match atomic_type(ty) {
+                Some(atomic) => format!("consider using an `{}` from `std::sync::atomic` instead", atomic),
+                None => "consider using a `Mutex` instead, initialized lazily if needed, e.g. with the `once_cell` or \
+                         `lazy_static` crate"
+                    .to_string(),
+            };
+            span_lint_and_help(cx, STATIC_MUT, item.span, "declaration of a `static mut`", None, &help);
+        }
+    }
+}
+
+/// Returns the name of the atomic type which can be used instead of the given type, if any.
+fn atomic_type(ty: Ty<'_>) -> Option<&'static str> {
+    match ty.kind {
+        ty::Bool => Some("AtomicBool"),
+        ty::RawPtr(_) => Some("AtomicPtr"),
+        ty::Int(int_ty) => match int_ty {
+            IntTy::Isize => Some("AtomicIsize"),
+            IntTy::I8 => Some("AtomicI8"),
+            IntTy::I16 => Some("AtomicI16"),
+            IntTy::I32 => Some("AtomicI32"),
+            IntTy::I64 => Some("AtomicI64"),
+            IntTy::I128 => None,
+        },
+        ty::Uint(uint_ty) => match uint_ty {
+            UintTy::Usize => Some("AtomicUsize"),
+            UintTy::U8 => Some("AtomicU8"),
+            UintTy::U16 => Some("AtomicU16"),
+            UintTy::U32 => Some("AtomicU32"),
+            UintTy::U64 => Some("AtomicU64"),
+            UintTy::U128 => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,82 @@
+use crate::utils::{in_async_body, match_function_call, paths, snippet, span_lint_and_help, span_lint_and_sugg};
+use rustc_errors::Applicability;
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `std::thread::sleep` inside of `async fn`s, async
+    /// blocks and async closures.
+    ///
+    /// **Why is this bad?** `std::thread::sleep` blocks the whole thread, so none of the other
+    /// tasks run by the executor on this thread can make progress while sleeping. Async runtimes
+    /// provide a sleep function returning a future, which should be awaited instead.
+    ///
+    /// The function suggested in place of `std::thread::sleep` can be configured with the
+    /// `async-sleep-path` option, e.g. `async-sleep-path = "tokio::time::sleep"`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// async fn foo() {
+    ///     std::thread::sleep(Duration::from_millis(10));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// # use std::time::Duration;
+    /// async fn foo() {
+    ///     tokio::time::sleep(Duration::from_millis(10)).await;
+    /// }
+    /// ```
+    pub THREAD_SLEEP_IN_ASYNC,
+    pedantic,
+    "calling `std::thread::sleep` inside of an async context"
+}
+
+#[derive(Clone, Debug)]
+pub struct ThreadSleepInAsync {
+    async_sleep_path: Option<String>,
+}
+
+impl ThreadSleepInAsync {
+    pub fn new(async_sleep_path: Option<String>) -> Self {
+        Self { async_sleep_path }
+    }
+}
+
+impl_lint_pass!(ThreadSleepInAsync => [THREAD_SLEEP_IN_ASYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for ThreadSleepInAsync {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() || !in_async_body(cx) {
+            return;
+        }
+        if let Some(args) = match_function_call(cx, expr, &paths::THREAD_SLEEP) {
+            let msg = "call to `std::thread::sleep` in an async context";
+            if let Some(async_sleep_path) = &self.async_sleep_path {
+                span_lint_and_sugg(
+                    cx,
+                    THREAD_SLEEP_IN_ASYNC,
+                    expr.span,
+                    msg,
+                    &format!("consider using `{}` instead", async_sleep_path),
+                    format!("{}({}).await", async_sleep_path, snippet(cx, args[0].span, "..")),
+                    Applicability::MaybeIncorrect,
+                );
+            } else {
+                span_lint_and_help(
+                    cx,
+                    THREAD_SLEEP_IN_ASYNC,
+                    expr.span,
+                    msg,
+                    None,
+                    "this blocks the executor thread, consider awaiting the sleep function of your async runtime instead",
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::utils::{in_macro, indent_of, match_def_path, paths, snippet, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{ImplItemKind, Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for direct implementations of `ToString`.
+    ///
+    /// **Why is this bad?** This trait is automatically implemented for any type which implements
+    /// the `Display` trait. As such, `ToString` shouldn't be implemented directly: `Display`
+    /// should be implemented instead, and you get the `ToString` implementation for free, as
+    /// well as support for `format!` and `{}`.
+    ///
+    /// **Known problems:** The suggested `Display` implementation still allocates the `String`
+    /// returned by the old `to_string` body, it should be rewritten to use `write!` directly.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// struct Point {
+    ///     x: usize,
+    ///     y: usize,
+    /// }
+    ///
+    /// impl ToString for Point {
+    ///     fn to_string(&self) -> String {
+    ///         format!("({}, {})", self.x, self.y)
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Point {
+    ///     x: usize,
+    ///     y: usize,
+    /// }
+    ///
+    /// impl std::fmt::Display for Point {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         write!(f, "({}, {})", self.x, self.y)
+    ///     }
+    /// }
+    /// ```
+    pub TO_STRING_TRAIT_IMPL,
+    style,
+    "check for direct implementations of `ToString`"
+}
+
+declare_lint_pass!(ToStringTraitImpl => [TO_STRING_TRAIT_IMPL]);
+
+impl<'tcx> LateLintPass<'tcx> for ToStringTraitImpl {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if_chain! {
+            if let ItemKind::Impl {
+                of_trait: Some(ref trait_ref),
+                self_ty,
+                items: [child],
+                ..
+            } = item.kind;
+            if !in_macro(item.span);
+            if let Some(trait_id) = trait_ref.trait_def_id();
+            if match_def_path(cx, trait_id, &paths::TO_STRING);
+            let impl_item = cx.tcx.hir().impl_item(child.id);
+            if let ImplItemKind::Fn(_, body_id) = impl_item.kind;
+            then {
+                let indent = indent_of(cx, item.span).unwrap_or(0);
+                // everything between `impl` and `ToString`, i.e. the generics
+                let impl_header = snippet(cx, item.span.until(trait_ref.path.span), "impl");
+                // the self type and the where clause
+                let self_snippet = snippet(cx, self_ty.span.to(impl_item.span.shrink_to_lo()), "..");
+                let self_snippet = self_snippet.trim_end().trim_end_matches('{').trim_end();
+                let body = cx.tcx.hir().body(body_id);
+                // the body moves one level deeper, into the `write!` call
+                let body_snippet = snippet(cx, body.value.span, "..")
+                    .lines()
+                    .enumerate()
+                    .map(|(i, line)| {
+                        if i == 0 || line.is_empty() {
+                            line.to_string()
+                        } else {
+                            format!("    {}", line)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let sugg = format!(
+                    "{impl_header}std::fmt::Display for {self_ty} {{\n\
+                     {indent}    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{\n\
+                     {indent}        write!(f, \"{{}}\", {body})\n\
+                     {indent}    }}\n\
+                     {indent}}}",
+                    impl_header = impl_header,
+                    self_ty = self_snippet,
+                    body = body_snippet,
+                    indent = " ".repeat(indent),
+                );
+                span_lint_and_sugg(
+                    cx,
+                    TO_STRING_TRAIT_IMPL,
+                    item.span,
+                    "direct implementation of `ToString`",
+                    "implement `Display` instead",
+                    sugg,
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+    }
+}
@@ -215,6 +215,52 @@ declare_clippy_lint! {
     "transmutes from a float to an integer"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from an integer to a pointer.
+    ///
+    /// **Why is this bad?** A pointer carries more than just its address, e.g. the allocation it
+    /// may be used to access. Transmuting hides that the pointer is created from a bare address,
+    /// which breaks tools like Miri, while an `as` cast makes it explicit.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// unsafe {
+    ///     let _: *const u32 = std::mem::transmute(0x1000_usize);
+    /// }
+    ///
+    /// // should be:
+    /// let _ = 0x1000_usize as *const u32;
+    /// ```
+    pub TRANSMUTE_INT_TO_PTR,
+    complexity,
+    "transmutes from an integer to a pointer"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from a pointer to an integer.
+    ///
+    /// **Why is this bad?** Transmutes are dangerous, and this hides that the pointer is reduced
+    /// to its address, while an `as` cast makes it explicit.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let ptr = &1u32 as *const u32;
+    /// unsafe {
+    ///     let _: usize = std::mem::transmute(ptr);
+    /// }
+    ///
+    /// // should be:
+    /// let _ = ptr as usize;
+    /// ```
+    pub TRANSMUTE_PTR_TO_INT,
+    complexity,
+    "transmutes from a pointer to an integer"
+}
+
 declare_clippy_lint! {
     /// **What it does:** Checks for transmutes from a pointer to a pointer, or
     /// from a reference to a reference.
@@ -280,6 +326,8 @@ declare_lint_pass!(Transmute => [
     TRANSMUTE_INT_TO_BOOL,
     TRANSMUTE_INT_TO_FLOAT,
     TRANSMUTE_FLOAT_TO_INT,
+    TRANSMUTE_INT_TO_PTR,
+    TRANSMUTE_PTR_TO_INT,
     UNSOUND_COLLECTION_TRANSMUTE,
 ]);
 
@@ -334,9 +382,10 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                             }
                         },
                     ),
-                    (ty::Int(_) | ty::Uint(_), ty::RawPtr(_)) => span_lint_and_then(
+                    (ty::Int(_) | ty::Uint(_), ty::RawPtr(to_ptr))
+                        if to_ptr.ty.is_sized(cx.tcx.at(e.span), cx.param_env) => span_lint_and_then(
                         cx,
-                        USELESS_TRANSMUTE,
+                        TRANSMUTE_INT_TO_PTR,
                         e.span,
                         "transmute from an integer to a pointer",
                         |diag| {
@@ -374,6 +423,23 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                             from_ty, to_ty
                         ),
                     ),
+                    (ty::RawPtr(from_ptr), ty::Int(_) | ty::Uint(_))
+                        if from_ptr.ty.is_sized(cx.tcx.at(e.span), cx.param_env) => span_lint_and_then(
+                        cx,
+                        TRANSMUTE_PTR_TO_INT,
+                        e.span,
+                        "transmute from a pointer to an integer",
+                        |diag| {
+                            if let Some(arg) = sugg::Sugg::hir_opt(cx, &args[0]) {
+                                diag.span_suggestion(
+                                    e.span,
+                                    "try",
+                                    arg.as_ty(&to_ty.to_string()).to_string(),
+                                    Applicability::Unspecified,
+                                );
+                            }
+                        },
+                    ),
                     (ty::RawPtr(from_pty), ty::Ref(_, to_ref_ty, mutbl)) => span_lint_and_then(
                         cx,
                         TRANSMUTE_PTR_TO_REF,
@@ -425,7 +491,7 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                                     Applicability::Unspecified,
                                 );
                             },
-                        )
+                        );
                     },
                     (ty::Ref(_, ty_from, from_mutbl), ty::Ref(_, ty_to, to_mutbl)) => {
                         if_chain! {
@@ -480,7 +546,7 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                                                 Applicability::Unspecified,
                                             );
                                         },
-                                    )
+                                    );
                                 }
                             }
                         }
@@ -513,7 +579,7 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                                     Applicability::Unspecified,
                                 );
                             },
-                        )
+                        );
                     },
                     (ty::Int(_) | ty::Uint(_), ty::Float(_)) => span_lint_and_then(
                         cx,
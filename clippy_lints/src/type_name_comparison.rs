@@ -0,0 +1,99 @@
+use crate::utils::{match_function_call, paths, snippet_opt, span_lint_and_help, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for comparisons of the strings returned by
+    /// `std::any::type_name`.
+    ///
+    /// **Why is this bad?** The returned name is only meant for diagnostics. It is explicitly
+    /// unspecified and may change between compiler versions. Different types can have the same
+    /// name, e.g. types from two versions of the same crate, so comparing the names doesn't
+    /// reliably tell whether two types are the same.
+    ///
+    /// **Known problems:** `TypeId::of` requires the types to be `'static`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// use std::any::type_name;
+    ///
+    /// fn is_string<T>() -> bool {
+    ///     type_name::<T>() == type_name::<String>()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::any::TypeId;
+    ///
+    /// fn is_string<T: 'static>() -> bool {
+    ///     TypeId::of::<T>() == TypeId::of::<String>()
+    /// }
+    /// ```
+    pub TYPE_NAME_COMPARISON,
+    correctness,
+    "comparing the results of `type_name` to check whether types are the same"
+}
+
+declare_lint_pass!(TypeNameComparison => [TYPE_NAME_COMPARISON]);
+
+impl<'tcx> LateLintPass<'tcx> for TypeNameComparison {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Binary(op, left, right) = expr.kind;
+            if op.node == BinOpKind::Eq || op.node == BinOpKind::Ne;
+            let left_is_type_name = match_function_call(cx, left, &paths::ANY_TYPE_NAME).is_some();
+            let right_is_type_name = match_function_call(cx, right, &paths::ANY_TYPE_NAME).is_some();
+            if left_is_type_name || right_is_type_name;
+            then {
+                let msg = "comparing the results of `type_name`, which are unspecified and may change";
+                if_chain! {
+                    if left_is_type_name && right_is_type_name;
+                    if let Some(left_sugg) = type_id_sugg(cx, left);
+                    if let Some(right_sugg) = type_id_sugg(cx, right);
+                    then {
+                        span_lint_and_sugg(
+                            cx,
+                            TYPE_NAME_COMPARISON,
+                            expr.span,
+                            msg,
+                            "compare the `TypeId`s instead",
+                            format!("{} {} {}", left_sugg, op.node.as_str(), right_sugg),
+                            Applicability::MaybeIncorrect,
+                        );
+                    } else {
+                        span_lint_and_help(
+                            cx,
+                            TYPE_NAME_COMPARISON,
+                            expr.span,
+                            msg,
+                            None,
+                            "to check whether two types are the same, compare their `TypeId`s instead",
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Turns a `type_name::<T>()` call into a `TypeId::of::<T>()` call, keeping the path and the
+/// generic arguments.
+fn type_id_sugg(cx: &LateContext<'_>, call: &Expr<'_>) -> Option<String> {
+    if_chain! {
+        if let ExprKind::Call(func, _) = call.kind;
+        if let ExprKind::Path(QPath::Resolved(None, path)) = func.kind;
+        if let Some(segment) = path.segments.last();
+        let ident_span = segment.ident.span;
+        if let Some(prefix) = snippet_opt(cx, func.span.with_hi(ident_span.lo()));
+        if let Some(suffix) = snippet_opt(cx, call.span.with_lo(ident_span.hi()));
+        then {
+            Some(format!("{}TypeId::of{}", prefix, suffix))
+        } else {
+            None
+        }
+    }
+}
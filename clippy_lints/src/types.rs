@@ -8,11 +8,12 @@ use if_chain::if_chain;
 use rustc_ast::ast::{FloatTy, IntTy, LitFloatType, LitIntType, LitKind, UintTy};
 use rustc_errors::{Applicability, DiagnosticBuilder};
 use rustc_hir as hir;
+use rustc_hir::def::Res;
 use rustc_hir::intravisit::{walk_body, walk_expr, walk_ty, FnKind, NestedVisitorMap, Visitor};
 use rustc_hir::{
-    BinOpKind, Block, Body, Expr, ExprKind, FnDecl, FnRetTy, FnSig, GenericArg, GenericParamKind, HirId, ImplItem,
-    ImplItemKind, Item, ItemKind, Lifetime, Local, MatchSource, MutTy, Mutability, QPath, Stmt, StmtKind, TraitFn,
-    TraitItem, TraitItemKind, TyKind, UnOp,
+    BinOpKind, BindingAnnotation, Block, Body, Expr, ExprKind, FnDecl, FnRetTy, FnSig, GenericArg, GenericParamKind,
+    HirId, ImplItem, ImplItemKind, Item, ItemKind, Lifetime, Local, MatchSource, MutTy, Mutability, Node, PatKind,
+    QPath, Stmt, StmtKind, TraitFn, TraitItem, TraitItemKind, TyKind, UnOp,
 };
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::map::Map;
@@ -28,11 +29,13 @@ use rustc_typeck::hir_ty_to_ty;
 
 use crate::consts::{constant, Constant};
 use crate::utils::paths;
+use crate::utils::sugg::Sugg;
 use crate::utils::{
-    clip, comparisons, differing_macro_contexts, higher, in_constant, indent_of, int_bits, is_type_diagnostic_item,
-    last_path_segment, match_def_path, match_path, method_chain_args, multispan_sugg, numeric_literal::NumericLiteral,
-    qpath_res, sext, snippet, snippet_block_with_applicability, snippet_opt, snippet_with_applicability,
-    snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, unsext,
+    clip, comparisons, differing_macro_contexts, get_parent_expr, higher, in_constant, indent_of, int_bits,
+    is_type_diagnostic_item, last_path_segment, match_def_path, match_path, method_chain_args, multispan_sugg,
+    numeric_literal::NumericLiteral, qpath_res, sext, snippet, snippet_block_with_applicability, snippet_opt,
+    snippet_with_applicability, snippet_with_macro_callsite, span_lint, span_lint_and_help, span_lint_and_sugg,
+    span_lint_and_then, unsext, SpanlessEq,
 };
 
 declare_clippy_lint! {
@@ -480,7 +483,7 @@ impl Types {
             TyKind::Rptr(ref lt, ref mut_ty) => self.check_ty_rptr(cx, hir_ty, is_local, lt, mut_ty),
             // recurse
             TyKind::Slice(ref ty) | TyKind::Array(ref ty, _) | TyKind::Ptr(MutTy { ref ty, .. }) => {
-                self.check_ty(cx, ty, is_local)
+                self.check_ty(cx, ty, is_local);
             },
             TyKind::Tup(tys) => {
                 for ty in tys {
@@ -859,7 +862,7 @@ fn lint_unit_args(cx: &LateContext<'_>, expr: &Expr<'_>, args_to_recover: &[&Exp
                     format!("{}\n", sugg.join("\n")),
                     applicability,
                 );
-                and = "...and "
+                and = "...and ";
             }
             db.multipart_suggestion(
                 &format!("{}use {}unit literal{} instead", and, singular, plural),
@@ -1119,6 +1122,34 @@ declare_clippy_lint! {
     "casting a function pointer to a numeric type not wide enough to store the address"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for casts between raw pointers to slices whose element types
+    /// have different sizes, e.g. `&[u8] as *const [u8] as *const [u32]`.
+    ///
+    /// **Why is this bad?** The cast keeps the number of elements of the slice, not its size in
+    /// bytes. If the new element type is larger, the resulting slice extends past the end of the
+    /// original one, and reading from it is undefined behavior.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bytes: &[u8] = &[0; 8];
+    /// let words = bytes as *const [u8] as *const [u32];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let bytes: &[u8] = &[0; 8];
+    /// let words = core::ptr::slice_from_raw_parts(
+    ///     bytes.as_ptr().cast::<u32>(),
+    ///     core::mem::size_of_val(bytes) / core::mem::size_of::<u32>(),
+    /// );
+    /// ```
+    pub CAST_SLICE_DIFFERENT_SIZES,
+    correctness,
+    "casting a slice pointer to a slice pointer with a different element size"
+}
+
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
 fn int_ty_to_nbits(typ: Ty<'_>, tcx: TyCtxt<'_>) -> u64 {
@@ -1364,6 +1395,7 @@ declare_lint_pass!(Casts => [
     CAST_PTR_ALIGNMENT,
     FN_TO_NUMERIC_CAST,
     FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
+    CAST_SLICE_DIFFERENT_SIZES,
 ]);
 
 // Check if the given type is either `core::ffi::c_void` or
@@ -1444,6 +1476,7 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
             }
 
             lint_cast_ptr_alignment(cx, expr, cast_from, cast_to);
+            lint_cast_slice_different_sizes(cx, expr, ex, cast_to);
         }
     }
 }
@@ -1538,6 +1571,107 @@ fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_f
     }
 }
 
+/// Returns the element type of a reference or raw pointer to a slice, and whether the reference
+/// or pointer is mutable.
+fn slice_ptr_elem_ty(ty: Ty<'_>) -> Option<(Ty<'_>, Mutability)> {
+    let (pointee, mutbl) = match ty.kind {
+        ty::Ref(_, pointee, mutbl) | ty::RawPtr(ty::TypeAndMut { ty: pointee, mutbl }) => (pointee, mutbl),
+        _ => return None,
+    };
+    if let ty::Slice(elem) = pointee.kind {
+        Some((elem, mutbl))
+    } else {
+        None
+    }
+}
+
+fn lint_cast_slice_different_sizes<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_to: Ty<'tcx>,
+) {
+    let (to_elem, to_mutbl) = match cast_to.kind {
+        ty::RawPtr(..) => match slice_ptr_elem_ty(cast_to) {
+            Some(elem) => elem,
+            None => return,
+        },
+        _ => return,
+    };
+    // only lint the outermost cast of a chain like `x as *const [u8] as *const [u32]`
+    if_chain! {
+        if let Some(parent) = get_parent_expr(cx, expr);
+        if let ExprKind::Cast(..) = parent.kind;
+        if slice_ptr_elem_ty(cx.typeck_results().expr_ty(parent)).is_some();
+        then {
+            return;
+        }
+    }
+    let mut src = cast_expr;
+    while let ExprKind::Cast(inner, _) = src.kind {
+        if slice_ptr_elem_ty(cx.typeck_results().expr_ty(inner)).is_none() {
+            break;
+        }
+        src = inner;
+    }
+    let src_ty = cx.typeck_results().expr_ty(src);
+    let from_elem = match slice_ptr_elem_ty(src_ty) {
+        Some((elem, _)) => elem,
+        None => return,
+    };
+    let (from_layout, to_layout) = match (cx.layout_of(from_elem), cx.layout_of(to_elem)) {
+        (Ok(from_layout), Ok(to_layout)) => (from_layout, to_layout),
+        _ => return,
+    };
+    // this also skips casts between two zero-sized element types
+    if from_layout.size == to_layout.size {
+        return;
+    }
+    let msg = format!(
+        "casting between slice pointers with different element sizes (`{}` has size {}, `{}` has size {})",
+        from_elem,
+        from_layout.size.bytes(),
+        to_elem,
+        to_layout.size.bytes(),
+    );
+    span_lint_and_then(cx, CAST_SLICE_DIFFERENT_SIZES, expr.span, &msg, |diag| {
+        let (ptr_method, from_raw_parts) = match to_mutbl {
+            Mutability::Not => ("as_ptr", "slice_from_raw_parts"),
+            Mutability::Mut => ("as_mut_ptr", "slice_from_raw_parts_mut"),
+        };
+        match src_ty.kind {
+            // `as_mut_ptr` needs a mutable reference, `as_ptr` works with either one, and the length
+            // can't be divided by the size of a zero-sized element type
+            ty::Ref(_, _, src_mutbl)
+                if !to_layout.is_zst() && (src_mutbl == to_mutbl || to_mutbl == Mutability::Not) =>
+            {
+                let mut applicability = Applicability::MaybeIncorrect;
+                let src_sugg = Sugg::hir_with_applicability(cx, src, "..", &mut applicability);
+                diag.span_suggestion(
+                    expr.span,
+                    "convert the length to the new element size",
+                    format!(
+                        "core::ptr::{}({}.{}().cast::<{}>(), core::mem::size_of_val({}) / core::mem::size_of::<{}>())",
+                        from_raw_parts,
+                        src_sugg.maybe_par(),
+                        ptr_method,
+                        to_elem,
+                        src_sugg,
+                        to_elem,
+                    ),
+                    applicability,
+                );
+            },
+            _ => {
+                diag.help(&format!(
+                    "use `core::ptr::{}` with the length converted to the new element size",
+                    from_raw_parts
+                ));
+            },
+        }
+    });
+}
+
 fn lint_fn_to_numeric_cast(
     cx: &LateContext<'_>,
     expr: &Expr<'_>,
@@ -1777,7 +1911,34 @@ declare_clippy_lint! {
     "casting a character literal to `u8` truncates"
 }
 
-declare_lint_pass!(CharLitAsU8 => [CHAR_LIT_AS_U8]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for casts of a `char` to `u8`, unless the `char` is checked
+    /// to be ASCII by an enclosing `if` or match guard, e.g. with `c.is_ascii()`.
+    /// Casts of character literals are covered by the `char_lit_as_u8` lint.
+    ///
+    /// **Why is this bad?** The cast silently drops the upper bits of non-ASCII characters,
+    /// e.g. `'ā' as u8` is `1`, which is rarely the intended result.
+    ///
+    /// **Known problems:** Only conditions of an enclosing `if` or match guard are taken into
+    /// account, so characters checked in another way, e.g. with an `assert!`, are still linted.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let c = 'a';
+    /// let byte = c as u8;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::convert::TryFrom;
+    /// # let c = 'a';
+    /// let byte = u8::try_from(u32::from(c)).ok();
+    /// ```
+    pub CHAR_AS_U8,
+    pedantic,
+    "casting a `char` which may not be ASCII to `u8` truncates"
+}
+
+declare_lint_pass!(CharLitAsU8 => [CHAR_LIT_AS_U8, CHAR_AS_U8]);
 
 impl<'tcx> LateLintPass<'tcx> for CharLitAsU8 {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
@@ -1810,6 +1971,71 @@ impl<'tcx> LateLintPass<'tcx> for CharLitAsU8 {
                 });
             }
         }
+
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Cast(e, _) = &expr.kind;
+            if !matches!(e.kind, ExprKind::Lit(_));
+            if cx.typeck_results().expr_ty(e).is_char();
+            if ty::Uint(UintTy::U8) == cx.typeck_results().expr_ty(expr).kind;
+            if !is_checked_ascii(cx, expr, e);
+            then {
+                let snippet = snippet(cx, e.span, "c");
+                span_lint_and_help(
+                    cx,
+                    CHAR_AS_U8,
+                    expr.span,
+                    "casting a `char` to `u8` truncates non-ASCII characters",
+                    None,
+                    &format!(
+                        "check `{0}.is_ascii()` first, or use `u8::try_from(u32::from({0}))` instead",
+                        snippet
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether the cast of `c` is inside of the `then` branch of an `if` or of a match arm
+/// whose condition checks that `c` is ASCII.
+fn is_checked_ascii(cx: &LateContext<'_>, cast: &Expr<'_>, c: &Expr<'_>) -> bool {
+    let mut child = cast.hir_id;
+    for (_, node) in cx.tcx.hir().parent_iter(cast.hir_id) {
+        match node {
+            Node::Expr(parent) => {
+                if let Some((cond, then, _)) = higher::if_block(parent) {
+                    if then.hir_id == child && checks_ascii(cx, cond, c) {
+                        return true;
+                    }
+                }
+                child = parent.hir_id;
+            },
+            Node::Arm(arm) => {
+                if let Some(hir::Guard::If(guard)) = arm.guard {
+                    if arm.body.hir_id == child && checks_ascii(cx, guard, c) {
+                        return true;
+                    }
+                }
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => return false,
+            _ => {},
+        }
+    }
+    false
+}
+
+/// Checks whether the condition, or one of the operands of `&&` in it, is a call to `c.is_ascii()`
+/// or one of the other `is_ascii_*` methods.
+fn checks_ascii(cx: &LateContext<'_>, cond: &Expr<'_>, c: &Expr<'_>) -> bool {
+    match cond.kind {
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::And => {
+            checks_ascii(cx, lhs, c) || checks_ascii(cx, rhs, c)
+        },
+        ExprKind::MethodCall(path, _, args, _) => {
+            path.ident.as_str().starts_with("is_ascii") && SpanlessEq::new(cx).eq_expr(&args[0], c)
+        },
+        _ => false,
     }
 }
 
@@ -2145,7 +2371,7 @@ fn upcast_comparison_bounds_err<'tcx>(
                 },
                 Rel::Eq | Rel::Ne => unreachable!(),
             } {
-                err_upcast_comparison(cx, span, lhs, true)
+                err_upcast_comparison(cx, span, lhs, true);
             } else if match rel {
                 Rel::Lt => {
                     if invert {
@@ -2163,7 +2389,7 @@ fn upcast_comparison_bounds_err<'tcx>(
                 },
                 Rel::Eq | Rel::Ne => unreachable!(),
             } {
-                err_upcast_comparison(cx, span, lhs, false)
+                err_upcast_comparison(cx, span, lhs, false);
             }
         }
     }
@@ -2546,7 +2772,9 @@ impl<'a, 'b, 'tcx> Visitor<'tcx> for ImplicitHasherConstructorVisitor<'a, 'b, 't
 }
 
 declare_clippy_lint! {
-    /// **What it does:** Checks for casts of `&T` to `&mut T` anywhere in the code.
+    /// **What it does:** Checks for casts of `&T` to `&mut T` anywhere in the code, i.e.
+    /// dereferences of a reference cast to a `*const` and then to a `*mut` pointer, either directly
+    /// or through an immutable local binding holding the `*const` pointer.
     ///
     /// **Why is this bad?** It’s basically guaranteed to be undefined behaviour.
     /// `UnsafeCell` is the only way to obtain aliasable data that is considered
@@ -2587,9 +2815,7 @@ impl<'tcx> LateLintPass<'tcx> for RefToMut {
             if let ExprKind::Unary(UnOp::UnDeref, e) = &expr.kind;
             if let ExprKind::Cast(e, t) = &e.kind;
             if let TyKind::Ptr(MutTy { mutbl: Mutability::Mut, .. }) = t.kind;
-            if let ExprKind::Cast(e, t) = &e.kind;
-            if let TyKind::Ptr(MutTy { mutbl: Mutability::Not, .. }) = t.kind;
-            if let ty::Ref(..) = cx.typeck_results().node_type(e.hir_id).kind;
+            if is_ptr_from_ref(cx, e);
             then {
                 span_lint(
                     cx,
@@ -2601,3 +2827,37 @@ impl<'tcx> LateLintPass<'tcx> for RefToMut {
         }
     }
 }
+
+/// Checks whether the expression is a reference cast to a `*const` pointer, or an immutable local
+/// binding initialized with such a cast or with a shared reference coerced to a pointer.
+fn is_ptr_from_ref(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Cast(e, t) => {
+            if let TyKind::Ptr(mut_ty) = &t.kind {
+                mut_ty.mutbl == Mutability::Not && matches!(cx.typeck_results().node_type(e.hir_id).kind, ty::Ref(..))
+            } else {
+                false
+            }
+        },
+        ExprKind::Path(QPath::Resolved(None, path)) => {
+            let map = cx.tcx.hir();
+            if_chain! {
+                if let Res::Local(id) = path.res;
+                if let Some(Node::Binding(pat)) = map.find(id);
+                if let PatKind::Binding(BindingAnnotation::Unannotated, ..) = pat.kind;
+                if let Some(Node::Local(local)) = map.find(map.get_parent_node(pat.hir_id));
+                if let Some(init) = local.init;
+                then {
+                    let is_coerced_ref = matches!(
+                        cx.typeck_results().node_type(init.hir_id).kind,
+                        ty::Ref(_, _, Mutability::Not)
+                    ) && matches!(cx.typeck_results().expr_ty_adjusted(init).kind, ty::RawPtr(_));
+                    is_coerced_ref || is_ptr_from_ref(cx, init)
+                } else {
+                    false
+                }
+            }
+        },
+        _ => false,
+    }
+}
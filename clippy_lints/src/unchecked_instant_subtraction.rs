@@ -0,0 +1,80 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{match_def_path, match_function_call, match_type, paths, snippet, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for subtractions of two `Instant`s and for calls to
+    /// `Instant::duration_since`, unless the instant the other one is subtracted from is
+    /// `Instant::now()`.
+    ///
+    /// **Why is this bad?** The subtraction panics if the second instant is later than the first
+    /// one, e.g. because the operands are swapped. Unless the first instant is the current time,
+    /// the order of the two instants is usually not obvious from the code.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::time::Instant;
+    /// # let start = Instant::now();
+    /// # let end = Instant::now();
+    /// let elapsed = end - start;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::time::Instant;
+    /// # let start = Instant::now();
+    /// # let end = Instant::now();
+    /// let elapsed = end.saturating_duration_since(start);
+    /// ```
+    pub UNCHECKED_INSTANT_SUBTRACTION,
+    pedantic,
+    "subtracting `Instant`s, which panics if they are in the wrong order"
+}
+
+declare_lint_pass!(UncheckedInstantSubtraction => [UNCHECKED_INSTANT_SUBTRACTION]);
+
+impl<'tcx> LateLintPass<'tcx> for UncheckedInstantSubtraction {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let (later, earlier) = match expr.kind {
+            ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Sub => (lhs, rhs),
+            ExprKind::MethodCall(_, _, args, _) if args.len() == 2 => {
+                match cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    Some(def_id) if match_def_path(cx, def_id, &paths::INSTANT_DURATION_SINCE) => (&args[0], &args[1]),
+                    _ => return,
+                }
+            },
+            _ => return,
+        };
+        if_chain! {
+            if match_type(cx, cx.typeck_results().expr_ty(later), &paths::INSTANT);
+            if match_type(cx, cx.typeck_results().expr_ty(earlier), &paths::INSTANT);
+            if match_function_call(cx, later, &paths::INSTANT_NOW).is_none();
+            then {
+                let mut applicability = Applicability::MaybeIncorrect;
+                let later_sugg = Sugg::hir_with_applicability(cx, later, "..", &mut applicability);
+                let earlier_sugg = Sugg::hir_with_applicability(cx, earlier, "..", &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    UNCHECKED_INSTANT_SUBTRACTION,
+                    expr.span,
+                    &format!(
+                        "this panics if `{}` is later than `{}`",
+                        snippet(cx, earlier.span, ".."),
+                        snippet(cx, later.span, "..")
+                    ),
+                    "if this can happen, saturate to zero instead",
+                    format!("{}.saturating_duration_since({})", later_sugg.maybe_par(), earlier_sugg),
+                    applicability,
+                );
+            }
+        }
+    }
+}
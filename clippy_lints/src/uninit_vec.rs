@@ -0,0 +1,340 @@
+use crate::utils::{
+    is_integer_const, match_def_path, match_function_call, match_trait_method, match_type, paths, span_lint_and_then,
+};
+use if_chain::if_chain;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, HirId, Mutability, PatKind, QPath, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Vec::set_len` calls on a vector created with
+    /// `Vec::with_capacity` or `Vec::new`, when the vector isn't used in between, so its
+    /// elements can't have been written.
+    ///
+    /// **Why is this bad?** The spare capacity of a vector is uninitialized, so `set_len` exposes
+    /// uninitialized values as elements of the vector, and reading them is undefined behavior.
+    ///
+    /// **Known problems:** Writes through pointers obtained before the vector is created are not
+    /// detected.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,no_run
+    /// let mut vec: Vec<u8> = Vec::with_capacity(1000);
+    /// unsafe {
+    ///     vec.set_len(1000);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let vec: Vec<u8> = vec![0; 1000];
+    /// ```
+    pub UNINIT_VEC,
+    correctness,
+    "`Vec::set_len` exposing the uninitialized spare capacity of a new vector"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for index assignments and `copy_from_slice` calls on a vector
+    /// created with `Vec::with_capacity` or `Vec::new`, when the vector isn't used in between.
+    ///
+    /// **Why is this bad?** The capacity of a vector is not its length. A new vector is empty, so
+    /// every index is out of bounds and the write panics.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,should_panic
+    /// let mut vec = Vec::with_capacity(10);
+    /// for i in 0..10 {
+    ///     vec[i] = i * 2;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut vec = vec![0; 10];
+    /// for i in 0..10 {
+    ///     vec[i] = i * 2;
+    /// }
+    /// ```
+    pub WRITE_TO_NEW_VEC,
+    correctness,
+    "index assignments on a new vector, which is still empty"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Read::read` and `Read::read_exact` calls into a vector
+    /// created with `Vec::with_capacity` or `Vec::new`, when the vector isn't used in between.
+    ///
+    /// **Why is this bad?** The buffer passed to these methods is the slice of the elements of the
+    /// vector, not its spare capacity. A new vector is empty, so nothing is read.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// # use std::io::Read;
+    /// # fn foo(mut reader: impl Read) -> std::io::Result<()> {
+    /// let mut buf = Vec::with_capacity(1024);
+    /// reader.read_exact(&mut buf)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::io::Read;
+    /// # fn foo(mut reader: impl Read) -> std::io::Result<()> {
+    /// let mut buf = vec![0; 1024];
+    /// reader.read_exact(&mut buf)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub READ_INTO_NEW_VEC,
+    correctness,
+    "reading into a new vector, which is still empty, so nothing is read"
+}
+
+declare_lint_pass!(UninitVec => [UNINIT_VEC, WRITE_TO_NEW_VEC, READ_INTO_NEW_VEC]);
+
+impl<'tcx> LateLintPass<'tcx> for UninitVec {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if !stmt.span.from_expansion();
+                if let StmtKind::Local(local) = stmt.kind;
+                if let PatKind::Binding(_, vec_id, _, None) = local.pat.kind;
+                if let Some(init) = local.init;
+                if is_new_vec(cx, init);
+                if let ty::Adt(_, substs) = cx.typeck_results().expr_ty(init).kind;
+                then {
+                    let rest = block.stmts[i + 1..]
+                        .iter()
+                        .filter_map(|stmt| match stmt.kind {
+                            StmtKind::Local(local) => local.init,
+                            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                            StmtKind::Item(_) => None,
+                        })
+                        .chain(block.expr);
+                    for expr in rest {
+                        let mut visitor = NewVecVisitor {
+                            cx,
+                            vec_id,
+                            found: None,
+                            used: false,
+                            whole_loop: false,
+                        };
+                        visitor.visit_expr(expr);
+                        match visitor.found {
+                            Some(NewVecUse::SetLen(set_len)) => {
+                                if !match_type(cx, substs.type_at(0), &paths::MEM_MAYBEUNINIT) {
+                                    lint_set_len(cx, init.span, set_len);
+                                }
+                                break;
+                            },
+                            Some(NewVecUse::Write(write, what)) => {
+                                lint_write(cx, init.span, write, what);
+                                break;
+                            },
+                            Some(NewVecUse::Read(read, method)) => {
+                                lint_read(cx, init.span, read, method);
+                                break;
+                            },
+                            None if visitor.used => break,
+                            None => {},
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn lint_set_len(cx: &LateContext<'_>, init: Span, set_len: Span) {
+    span_lint_and_then(
+        cx,
+        UNINIT_VEC,
+        set_len,
+        "calling `set_len()` on a new `Vec` exposes uninitialized memory",
+        |diag| {
+            diag.help("initialize the elements before calling `set_len()`, or use `MaybeUninit` as the element type");
+            diag.span_note(init, "the `Vec` is created here, without initializing its elements");
+        },
+    );
+}
+
+fn lint_write(cx: &LateContext<'_>, init: Span, write: Span, what: &str) {
+    span_lint_and_then(
+        cx,
+        WRITE_TO_NEW_VEC,
+        write,
+        &format!("{} on a new `Vec`, which is still empty", what),
+        |diag| {
+            diag.help(
+                "the capacity of a `Vec` is not its length, consider creating it with `vec![value; len]` \
+                 or calling `resize()` first",
+            );
+            diag.span_note(init, "the `Vec` is created here, with a length of zero");
+        },
+    );
+}
+
+fn lint_read(cx: &LateContext<'_>, init: Span, read: Span, method: &str) {
+    span_lint_and_then(
+        cx,
+        READ_INTO_NEW_VEC,
+        read,
+        &format!(
+            "calling `{}()` with a new `Vec`, which is still empty, reads nothing",
+            method
+        ),
+        |diag| {
+            diag.help("consider using `read_to_end()`, or resizing the `Vec` to the number of bytes to read first");
+            diag.span_note(init, "the `Vec` is created here, with a length of zero");
+        },
+    );
+}
+
+/// Checks whether the expression is a call to `Vec::with_capacity` or `Vec::new`.
+fn is_new_vec(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match_function_call(cx, expr, &paths::VEC_WITH_CAPACITY).is_some()
+        || match_function_call(cx, expr, &paths::VEC_NEW).is_some()
+}
+
+enum NewVecUse {
+    /// A `set_len` call with a non-zero length.
+    SetLen(Span),
+    /// A write to an element, with a description of how it is written.
+    Write(Span, &'static str),
+    /// A `read` or `read_exact` call into the vector, with the name of the method.
+    Read(Span, &'static str),
+}
+
+/// Looks for a `set_len` call on the vector, a write to one of its elements or a read into it, and
+/// checks whether the vector is used in any other way before it, which may initialize its elements.
+struct NewVecVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    vec_id: HirId,
+    found: Option<NewVecUse>,
+    used: bool,
+    /// Whether to keep looking for other uses after `found` is recorded, to check a whole loop.
+    whole_loop: bool,
+}
+
+impl<'a, 'tcx> NewVecVisitor<'a, 'tcx> {
+    fn is_vec(&self, expr: &Expr<'_>) -> bool {
+        matches!(expr.kind, ExprKind::Path(QPath::Resolved(None, path)) if path.res == Res::Local(self.vec_id))
+    }
+
+    /// Checks whether the expression is the vector or a slice of it, like `vec[..len]`.
+    fn is_vec_or_slice(&self, expr: &Expr<'_>) -> bool {
+        match expr.kind {
+            ExprKind::Index(base, _) => self.is_vec(base),
+            _ => self.is_vec(expr),
+        }
+    }
+
+    /// Checks whether the expression is a mutable borrow of the vector or a slice of it.
+    fn is_borrowed_vec(&self, expr: &Expr<'_>) -> bool {
+        matches!(expr.kind, ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, inner) if self.is_vec_or_slice(inner))
+    }
+
+    /// Checks whether the vector is used in the loop in any other way than the accesses looked for.
+    /// Such a use may initialize the elements in an earlier iteration, even if it comes later in
+    /// the loop body.
+    fn is_used_in_loop(&self, loop_expr: &'tcx Expr<'_>) -> bool {
+        let mut visitor = NewVecVisitor {
+            cx: self.cx,
+            vec_id: self.vec_id,
+            found: None,
+            used: false,
+            whole_loop: true,
+        };
+        walk_expr(&mut visitor, loop_expr);
+        visitor.used
+    }
+
+    /// Visits the expression which is evaluated before the vector is accessed, and records `found`
+    /// if the vector isn't used in it.
+    fn found_after(&mut self, expr: &'tcx Expr<'_>, found: NewVecUse) {
+        self.visit_expr(expr);
+        if !self.used {
+            self.found = Some(found);
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for NewVecVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
+        if self.used || (self.found.is_some() && !self.whole_loop) {
+            return;
+        }
+        match expr.kind {
+            ExprKind::Loop(..) if !self.whole_loop && self.is_used_in_loop(expr) => {
+                self.used = true;
+                return;
+            },
+            ExprKind::MethodCall(path, _, args, _)
+                if path.ident.name == sym!(copy_from_slice) && self.is_vec_or_slice(&args[0]) =>
+            {
+                self.found_after(&args[1], NewVecUse::Write(expr.span, "calling `copy_from_slice()`"));
+                return;
+            },
+            ExprKind::MethodCall(path, _, args, _) if args.len() == 2 && self.is_borrowed_vec(&args[1]) => {
+                let method = if path.ident.name == sym!(read) {
+                    Some("read")
+                } else if path.ident.name == sym!(read_exact) {
+                    Some("read_exact")
+                } else {
+                    None
+                };
+                if let Some(method) = method {
+                    if match_trait_method(self.cx, expr, &paths::IO_READ) {
+                        self.found_after(&args[0], NewVecUse::Read(expr.span, method));
+                        return;
+                    }
+                }
+            },
+            ExprKind::MethodCall(_, _, args, _) if self.is_vec(&args[0]) => {
+                if_chain! {
+                    if let Some(def_id) = self.cx.typeck_results().type_dependent_def_id(expr.hir_id);
+                    if match_def_path(self.cx, def_id, &paths::VEC_SET_LEN);
+                    then {
+                        // the length may be computed from the vector, like `vec.capacity()`, which
+                        // doesn't initialize it
+                        if !is_integer_const(self.cx, &args[1], 0) {
+                            self.found = Some(NewVecUse::SetLen(expr.span));
+                        }
+                        return;
+                    }
+                }
+            },
+            ExprKind::Assign(lhs, rhs, _) | ExprKind::AssignOp(_, lhs, rhs) => {
+                if let ExprKind::Index(base, _) = lhs.kind {
+                    if self.is_vec(base) {
+                        self.found_after(rhs, NewVecUse::Write(expr.span, "index assignment"));
+                        return;
+                    }
+                }
+            },
+            _ => {},
+        }
+        if self.is_vec(expr) {
+            self.used = true;
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
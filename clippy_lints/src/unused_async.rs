@@ -0,0 +1,92 @@
+use crate::utils::span_lint_and_help;
+use rustc_hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, HirId, IsAsync, MatchSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::map::Map;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `async` functions that don't contain any `.await`
+    /// expressions.
+    ///
+    /// **Why is this bad?** The `async` keyword turns the body of the function into a state
+    /// machine wrapped in a future, so callers have to `.await` it and pay for the generated
+    /// future even though the function never actually suspends. The signature also suggests that
+    /// the function is asynchronous when it isn't.
+    ///
+    /// **Known problems:** Removing `async` changes the signature of the function, which is a
+    /// breaking change for public functions and may not be possible if the function has to be
+    /// `async` to fit an interface.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// async fn get_random_number() -> i64 {
+    ///     4 // Chosen by fair dice roll. Guaranteed to be random.
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn get_random_number() -> i64 {
+    ///     4 // Chosen by fair dice roll. Guaranteed to be random.
+    /// }
+    /// ```
+    pub UNUSED_ASYNC,
+    pedantic,
+    "finds async functions with no await statements"
+}
+
+declare_lint_pass!(UnusedAsync => [UNUSED_ASYNC]);
+
+struct AsyncFnVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    found_await: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for AsyncFnVisitor<'a, 'tcx> {
+    type Map = Map<'tcx>;
+
+    fn visit_expr(&mut self, ex: &'tcx Expr<'tcx>) {
+        if let ExprKind::Match(_, _, MatchSource::AwaitDesugar) = ex.kind {
+            self.found_await = true;
+        }
+        walk_expr(self, ex);
+    }
+
+    fn nested_visit_map(&mut self) -> NestedVisitorMap<Self::Map> {
+        NestedVisitorMap::OnlyBodies(self.cx.tcx.hir())
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for UnusedAsync {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        fn_kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        _: HirId,
+    ) {
+        if span.from_expansion() {
+            return;
+        }
+        if let Some(header) = fn_kind.header() {
+            if let IsAsync::Async = header.asyncness {
+                let mut visitor = AsyncFnVisitor { cx, found_await: false };
+                visitor.visit_body(body);
+                if !visitor.found_await {
+                    span_lint_and_help(
+                        cx,
+                        UNUSED_ASYNC,
+                        span,
+                        "unused `async` for function with no await statements",
+                        None,
+                        "consider removing the `async` from this function",
+                    );
+                }
+            }
+        }
+    }
+}
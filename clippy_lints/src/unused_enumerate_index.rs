@@ -0,0 +1,111 @@
+use crate::utils::usage::is_unused;
+use crate::utils::{
+    higher, in_macro, match_trait_method, multispan_sugg_with_applicability, paths, snippet_with_applicability,
+    span_lint_and_then,
+};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `for` loops and closures over `iter.enumerate()` which ignore
+    /// the index.
+    ///
+    /// **Why is this bad?** The index is never used, so the call to `enumerate` and the tuple
+    /// pattern are just noise.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// for (_, x) in v.iter().enumerate() {
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// for x in v.iter() {
+    ///     println!("{}", x);
+    /// }
+    /// ```
+    pub UNUSED_ENUMERATE_INDEX,
+    style,
+    "using `.enumerate()` and immediately dropping the index"
+}
+
+declare_lint_pass!(UnusedEnumerateIndex => [UNUSED_ENUMERATE_INDEX]);
+
+/// Iterator methods whose closure is called with the items of the iterator by value and whose
+/// result doesn't contain the items anymore.
+const CLOSURE_METHODS: [&str; 7] = ["all", "any", "filter_map", "find_map", "flat_map", "for_each", "map"];
+
+impl<'tcx> LateLintPass<'tcx> for UnusedEnumerateIndex {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if in_macro(expr.span) {
+            return;
+        }
+        // `for (_, x) in iter.enumerate() { .. }`
+        if let Some((pat, arg, body)) = higher::for_loop(expr) {
+            check_enumerate(cx, pat, arg, body);
+            return;
+        }
+        // `iter.enumerate().map(|(_, x)| ..)`
+        if_chain! {
+            if let ExprKind::MethodCall(path, _, [recv, closure], _) = expr.kind;
+            if CLOSURE_METHODS.contains(&&*path.ident.as_str());
+            if match_trait_method(cx, expr, &paths::ITERATOR);
+            if let ExprKind::Closure(_, _, body_id, _, _) = closure.kind;
+            let body = cx.tcx.hir().body(body_id);
+            if let [param] = body.params;
+            then {
+                check_enumerate(cx, param.pat, recv, &body.value);
+            }
+        }
+    }
+}
+
+/// Lints if `arg` is a call to `Iterator::enumerate` whose index is ignored by `pat` in `body`.
+fn check_enumerate<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+    if_chain! {
+        if let PatKind::Tuple([index, item], None) = pat.kind;
+        if is_unused_index(index, body);
+        if let ExprKind::MethodCall(path, _, [iter], _) = arg.kind;
+        if path.ident.name == sym!(enumerate);
+        if match_trait_method(cx, arg, &paths::ITERATOR);
+        if !in_macro(arg.span);
+        then {
+            span_lint_and_then(
+                cx,
+                UNUSED_ENUMERATE_INDEX,
+                arg.span,
+                "you seem to use `.enumerate()` and immediately discard the index",
+                |diag| {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let iter_snippet = snippet_with_applicability(cx, iter.span, "..", &mut applicability);
+                    let item_snippet = snippet_with_applicability(cx, item.span, "..", &mut applicability);
+                    multispan_sugg_with_applicability(
+                        diag,
+                        "remove the `.enumerate()` call",
+                        applicability,
+                        vec![
+                            (pat.span, item_snippet.into_owned()),
+                            (arg.span, iter_snippet.into_owned()),
+                        ],
+                    );
+                },
+            );
+        }
+    }
+}
+
+fn is_unused_index<'tcx>(index: &'tcx Pat<'_>, body: &'tcx Expr<'_>) -> bool {
+    match index.kind {
+        PatKind::Wild => true,
+        PatKind::Binding(.., ident, None) => is_unused(&ident, body),
+        _ => false,
+    }
+}
@@ -14,6 +14,8 @@ declare_clippy_lint! {
     /// partial-write/read, use
     /// `write_all`/`read_exact` instead.
     ///
+    /// The amount is also considered unused if it is discarded with `let _ = ..`.
+    ///
     /// **Known problems:** Detects only common patterns.
     ///
     /// **Example:**
@@ -36,6 +38,14 @@ impl<'tcx> LateLintPass<'tcx> for UnusedIoAmount {
     fn check_stmt(&mut self, cx: &LateContext<'_>, s: &hir::Stmt<'_>) {
         let expr = match s.kind {
             hir::StmtKind::Semi(ref expr) | hir::StmtKind::Expr(ref expr) => &**expr,
+            hir::StmtKind::Local(ref local) if matches!(local.pat.kind, hir::PatKind::Wild) => match local.init {
+                Some(ref init) => {
+                    // unlike in a statement, an unused `Result` isn't warned about by `unused_must_use` here
+                    check_method_call(cx, init, init);
+                    &**init
+                },
+                None => return,
+            },
             _ => return,
         };
 
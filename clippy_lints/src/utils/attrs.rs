@@ -22,6 +22,7 @@ pub const BUILTIN_ATTRIBUTES: &[(&str, DeprecationStatus)] = &[
         DeprecationStatus::Replaced("cognitive_complexity"),
     ),
     ("dump", DeprecationStatus::None),
+    ("msrv", DeprecationStatus::None),
 ];
 
 pub struct LimitStack {
@@ -29,6 +30,8 @@ pub struct LimitStack {
 }
 
 impl Drop for LimitStack {
+    // an unbalanced stack is a bug in a lint pass, which should fail loudly in the ui tests
+    #[allow(clippy::panic_in_drop)]
     fn drop(&mut self) {
         assert_eq!(self.stack.len(), 1);
     }
@@ -110,11 +113,34 @@ pub fn get_attr<'a>(
     })
 }
 
+/// Returns the inner attribute `#![clippy::<name>]`, reporting errors for outer attributes with
+/// that name and for duplicates.
+pub fn get_unique_inner_attr(sess: &Session, attrs: &[ast::Attribute], name: &'static str) -> Option<ast::Attribute> {
+    let mut unique_attr: Option<&ast::Attribute> = None;
+    for attr in get_attr(sess, attrs, name) {
+        match attr.style {
+            ast::AttrStyle::Inner => {
+                if let Some(first) = unique_attr {
+                    sess.struct_span_err(attr.span, &format!("`{}` is defined multiple times", name))
+                        .span_note(first.span, "first definition found here")
+                        .emit();
+                } else {
+                    unique_attr = Some(attr);
+                }
+            },
+            ast::AttrStyle::Outer => {
+                sess.span_err(attr.span, &format!("`{}` cannot be an outer attribute", name));
+            },
+        }
+    }
+    unique_attr.cloned()
+}
+
 fn parse_attrs<F: FnMut(u64)>(sess: &Session, attrs: &[ast::Attribute], name: &'static str, mut f: F) {
     for attr in get_attr(sess, attrs, name) {
         if let Some(ref value) = attr.value_str() {
             if let Ok(value) = FromStr::from_str(&value.as_str()) {
-                f(value)
+                f(value);
             } else {
                 sess.span_err(attr.span, "not a number");
             }
@@ -164,6 +164,18 @@ define_Conf! {
     (max_fn_params_bools, "max_fn_params_bools": u64, 3),
     /// Lint: WILDCARD_IMPORTS. Whether to allow certain wildcard imports (prelude, super in tests).
     (warn_on_all_wildcard_imports, "warn_on_all_wildcard_imports": bool, false),
+    /// Lint: THREAD_SLEEP_IN_ASYNC. The path of the async sleep function to suggest instead of `std::thread::sleep`, e.g. `tokio::time::sleep`
+    (async_sleep_path, "async_sleep_path": Option<String>, None),
+    /// Lint: BLOCKING_IO_IN_ASYNC. The paths of additional blocking functions to lint, e.g. `["my_crate::sync_client::get"]`
+    (blocking_paths, "blocking_paths": Vec<String>, Vec::new()),
+    /// Lint: BLOCK_ON_IN_ASYNC. The paths of additional functions running a future to completion to lint, e.g. `["my_crate::runtime::block_on"]`
+    (block_on_paths, "block_on_paths": Vec<String>, Vec::new()),
+    /// Lint: MULTIPLE_UNSAFE_OPS_PER_BLOCK. The maximum number of unsafe operations an `unsafe` block can contain
+    (max_unsafe_ops_per_block, "max_unsafe_ops_per_block": u64, 1),
+    /// Lint: MISSING_DERIVE_COPY. Suppress lints whenever the suggested change would change the API of exported types
+    (avoid_breaking_exported_api, "avoid_breaking_exported_api": bool, true),
+    /// Lint: MANUAL_CLAMP, IF_THEN_SOME_ELSE_NONE, MANUAL_STRIP, MANUAL_IS_ASCII_CHECK, CLONED_INSTEAD_OF_COPIED, MANUAL_SPLIT_ONCE, ITER_KV_MAP, MANUAL_RETAIN. The minimum rust version that the project supports
+    (msrv, "msrv": Option<String>, None),
 }
 
 impl Default for Conf {
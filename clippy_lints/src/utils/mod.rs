@@ -1,6 +1,24 @@
 #[macro_use]
 pub mod sym;
 
+/// Implements `LateLintPass::enter_lint_attrs` for a lint pass with an `msrv: Option<Version>`
+/// field, overriding the configured MSRV with the one from a `#![clippy::msrv = "1.40"]`
+/// attribute.
+macro_rules! extract_msrv_attr {
+    (LateContext) => {
+        fn enter_lint_attrs(&mut self, cx: &rustc_lint::LateContext<'tcx>, attrs: &'tcx [rustc_ast::ast::Attribute]) {
+            use rustc_lint::LintContext;
+            if let Some(msrv_attr) = $crate::utils::get_unique_inner_attr(cx.sess(), attrs, "msrv") {
+                if let Some(msrv) = msrv_attr.value_str() {
+                    self.msrv = $crate::utils::parse_msrv(&msrv.as_str(), Some(cx.sess()), Some(msrv_attr.span));
+                } else {
+                    cx.sess().span_err(msrv_attr.span, "bad clippy attribute");
+                }
+            }
+        }
+    };
+}
+
 #[allow(clippy::module_name_repetitions)]
 pub mod ast_utils;
 pub mod attrs;
@@ -36,24 +54,95 @@ use rustc_hir::def_id::{DefId, CRATE_DEF_INDEX, LOCAL_CRATE};
 use rustc_hir::intravisit::{NestedVisitorMap, Visitor};
 use rustc_hir::Node;
 use rustc_hir::{
-    def, Arm, Block, Body, Constness, Crate, Expr, ExprKind, FnDecl, HirId, ImplItem, ImplItemKind, Item, ItemKind,
-    MatchSource, Param, Pat, PatKind, Path, PathSegment, QPath, TraitItem, TraitItemKind, TraitRef, TyKind, Unsafety,
+    def, Arm, Block, Body, Constness, Crate, Expr, ExprKind, FnDecl, GeneratorKind, HirId, ImplItem, ImplItemKind,
+    Item, ItemKind, MatchSource, Param, Pat, PatKind, Path, PathSegment, QPath, TraitItem, TraitItemKind, TraitRef,
+    TyKind, Unsafety,
 };
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::{LateContext, Level, Lint, LintContext};
 use rustc_middle::hir::map::Map;
 use rustc_middle::ty::{self, layout::IntegerExt, subst::GenericArg, Ty, TyCtxt, TypeFoldable};
+use rustc_session::Session;
 use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::source_map::original_sp;
 use rustc_span::symbol::{self, kw, Symbol};
 use rustc_span::{BytePos, Pos, Span, DUMMY_SP};
 use rustc_target::abi::Integer;
 use rustc_trait_selection::traits::query::normalize::AtExt;
+use semver::Version;
 use smallvec::SmallVec;
 
 use crate::consts::{constant, Constant};
 use crate::reexport::Name;
 
+/// Parses a minimum supported Rust version like `1.45` or `1.45.0`. If it isn't a valid version,
+/// an error is reported at `span` when a session is passed.
+pub fn parse_msrv(msrv: &str, sess: Option<&Session>, span: Option<Span>) -> Option<Version> {
+    // `1.45` is a valid Rust version, but not a valid semver version
+    if let Ok(version) = Version::parse(msrv).or_else(|_| Version::parse(&format!("{}.0", msrv))) {
+        return Some(version);
+    }
+    if let (Some(sess), Some(span)) = (sess, span) {
+        sess.span_err(span, &format!("`{}` is not a valid Rust version", msrv));
+    }
+    None
+}
+
+/// Checks whether the configured MSRV is at least `lint_msrv`. Without a configured MSRV, the
+/// latest Rust version is assumed.
+pub fn meets_msrv(msrv: Option<&Version>, lint_msrv: &Version) -> bool {
+    msrv.map_or(true, |msrv| msrv >= lint_msrv)
+}
+
+/// Checks whether the item can be used by the crate, i.e. whether it is stable or its feature is
+/// enabled. Without a configured MSRV, `meets_msrv` accepts any version, so this is needed before
+/// suggesting an API which may not be stable in the standard library the crate is compiled with.
+pub fn is_stable_or_enabled(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    match cx.tcx.lookup_stability(def_id) {
+        Some(stability) if stability.level.is_unstable() => cx
+            .tcx
+            .features()
+            .declared_lib_features
+            .iter()
+            .any(|&(name, _)| name == stability.feature),
+        _ => true,
+    }
+}
+
+/// Checks whether the trait or impl has an associated function with the given name which can be
+/// used by the crate, see `is_stable_or_enabled`.
+pub fn has_usable_assoc_fn(cx: &LateContext<'_>, container: DefId, name: &str) -> bool {
+    cx.tcx
+        .associated_items(container)
+        .in_definition_order()
+        .any(|item| {
+            item.kind == ty::AssocKind::Fn && item.ident.name.as_str() == name && is_stable_or_enabled(cx, item.def_id)
+        })
+}
+
+/// Checks whether the type has an inherent method with the given name which can be used by the
+/// crate, see `is_stable_or_enabled`. Only ADTs, `bool`, `str` and floats are supported.
+pub fn has_usable_inherent_method(cx: &LateContext<'_>, ty: Ty<'_>, name: &str) -> bool {
+    let lang_items = cx.tcx.lang_items();
+    let impls: Vec<DefId> = match ty.kind {
+        ty::Adt(adt, _) => cx.tcx.inherent_impls(adt.did).to_vec(),
+        ty::Bool => lang_items.bool_impl().into_iter().collect(),
+        ty::Str => lang_items.str_impl().into_iter().collect(),
+        ty::Float(ast::FloatTy::F32) => lang_items
+            .f32_impl()
+            .into_iter()
+            .chain(lang_items.f32_runtime_impl())
+            .collect(),
+        ty::Float(ast::FloatTy::F64) => lang_items
+            .f64_impl()
+            .into_iter()
+            .chain(lang_items.f64_runtime_impl())
+            .collect(),
+        _ => return false,
+    };
+    impls.into_iter().any(|impl_id| has_usable_assoc_fn(cx, impl_id, name))
+}
+
 /// Returns `true` if the two spans come from differing expansions (i.e., one is
 /// from a macro and one isn't).
 #[must_use]
@@ -98,6 +187,17 @@ pub fn in_constant(cx: &LateContext<'_>, id: HirId) -> bool {
     }
 }
 
+/// Returns `true` if the body currently being linted is the body of an `async fn`, an async block
+/// or an async closure.
+///
+/// Closures defined inside of such a body are not considered to be in an async context, since
+/// they may be called from elsewhere.
+pub fn in_async_body(cx: &LateContext<'_>) -> bool {
+    cx.enclosing_body.map_or(false, |body_id| {
+        matches!(cx.tcx.hir().body(body_id).generator_kind, Some(GeneratorKind::Async(_)))
+    })
+}
+
 /// Returns `true` if this `span` was expanded by any macro.
 #[must_use]
 pub fn in_macro(span: Span) -> bool {
@@ -399,7 +499,7 @@ pub fn method_chain_args<'a>(expr: &'a Expr<'_>, methods: &[&str]) -> Option<Vec
                     return None;
                 }
                 matched.push(&**args); // build up `matched` backwards
-                current = &args[0] // go to parent expression
+                current = &args[0]; // go to parent expression
             } else {
                 return None;
             }
@@ -949,6 +1049,29 @@ pub fn remove_blocks<'tcx>(mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
     expr
 }
 
+/// Checks whether the expression is the `else` branch of an `if` or `if let`, like the second
+/// `if` in `if a { .. } else if b { .. }`. It can't be replaced by an arbitrary expression then.
+pub fn is_else_clause(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match get_parent_expr(cx, expr) {
+        Some(Expr {
+            kind: ExprKind::Match(_, [_, else_arm], MatchSource::IfDesugar { .. } | MatchSource::IfLetDesugar { .. }),
+            ..
+        }) => else_arm.body.hir_id == expr.hir_id,
+        _ => false,
+    }
+}
+
+/// Checks whether the expression can be evaluated eagerly, i.e. it is cheap and has no side
+/// effects.
+pub fn is_cheap_expr(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Lit(_) | ExprKind::Path(_) => true,
+        ExprKind::Field(inner, _) | ExprKind::AddrOf(_, _, inner) => is_cheap_expr(inner),
+        ExprKind::Unary(_, inner) => matches!(inner.kind, ExprKind::Lit(_)),
+        _ => false,
+    }
+}
+
 pub fn is_self(slf: &Param<'_>) -> bool {
     if let PatKind::Binding(.., name, _) = slf.pat.kind {
         name.name == kw::SelfLower
@@ -0,0 +1,138 @@
+use crate::utils::usage::{is_unused, mutated_variables};
+use crate::utils::{in_macro, match_function_call, match_var, paths, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, HirId, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::Ident;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `push` immediately after creating a new `Vec`.
+    ///
+    /// **Why is this bad?** The `vec![]` macro is both more performant and easier to read than
+    /// multiple `push` calls.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut v = Vec::new();
+    /// v.push(0);
+    /// v.push(1);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![0, 1];
+    /// ```
+    pub VEC_INIT_THEN_PUSH,
+    perf,
+    "`push` immediately after `Vec` creation"
+}
+
+declare_lint_pass!(VecInitThenPush => [VEC_INIT_THEN_PUSH]);
+
+impl<'tcx> LateLintPass<'tcx> for VecInitThenPush {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if let StmtKind::Local(local) = stmt.kind;
+                if let PatKind::Binding(BindingAnnotation::Mutable, vec_id, ident, None) = local.pat.kind;
+                if let Some(init) = local.init;
+                if !in_macro(stmt.span);
+                if let Some(init_kind) = vec_init_kind(cx, init);
+                let pushed = pushed_values(&block.stmts[i + 1..], ident);
+                if !pushed.is_empty();
+                then {
+                    let rest = &block.stmts[i + 1 + pushed.len()..];
+                    let mutated_later = is_mutated(cx, vec_id, rest, block.expr);
+                    // the capacity may be needed for later pushes
+                    if init_kind == VecInitKind::WithCapacity && mutated_later {
+                        continue;
+                    }
+                    let last = &block.stmts[i + pushed.len()];
+
+                    let mut applicability = Applicability::MachineApplicable;
+                    let ty = local.ty.map_or(String::new(), |ty| {
+                        format!(": {}", snippet_with_applicability(cx, ty.span, "..", &mut applicability))
+                    });
+                    let values = pushed
+                        .iter()
+                        .map(|value| snippet_with_applicability(cx, value.span, "..", &mut applicability))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    span_lint_and_sugg(
+                        cx,
+                        VEC_INIT_THEN_PUSH,
+                        stmt.span.to(last.span),
+                        "calls to `push` immediately after creation",
+                        "consider using the `vec![]` macro",
+                        format!(
+                            "let {}{}{} = vec![{}];",
+                            if mutated_later { "mut " } else { "" },
+                            ident,
+                            ty,
+                            values
+                        ),
+                        applicability,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VecInitKind {
+    New,
+    WithCapacity,
+}
+
+fn vec_init_kind(cx: &LateContext<'_>, init: &Expr<'_>) -> Option<VecInitKind> {
+    if match_function_call(cx, init, &paths::VEC_NEW).is_some() {
+        Some(VecInitKind::New)
+    } else if match_function_call(cx, init, &paths::VEC_WITH_CAPACITY).is_some() {
+        Some(VecInitKind::WithCapacity)
+    } else {
+        None
+    }
+}
+
+/// Collects the values of the `vec.push(value);` statements at the start of `stmts`.
+fn pushed_values<'tcx>(stmts: &'tcx [Stmt<'tcx>], vec: Ident) -> Vec<&'tcx Expr<'tcx>> {
+    let mut values = Vec::new();
+    for stmt in stmts {
+        if_chain! {
+            if let StmtKind::Semi(expr) = stmt.kind;
+            if !in_macro(stmt.span);
+            if let ExprKind::MethodCall(path, _, [recv, value], _) = expr.kind;
+            if path.ident.as_str() == "push" && match_var(recv, vec.name);
+            // `v.push(v.len())` can't be moved into the `vec![]`
+            if is_unused(&vec, value);
+            then {
+                values.push(value);
+                continue;
+            }
+        }
+        break;
+    }
+    values
+}
+
+/// Checks whether the `Vec` is mutated in the statements and the expression following the pushes.
+fn is_mutated<'tcx>(
+    cx: &LateContext<'tcx>,
+    id: HirId,
+    stmts: &'tcx [Stmt<'tcx>],
+    expr: Option<&'tcx Expr<'tcx>>,
+) -> bool {
+    stmts
+        .iter()
+        .filter_map(|stmt| match stmt.kind {
+            StmtKind::Local(local) => local.init,
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+            StmtKind::Item(_) => None,
+        })
+        .chain(expr)
+        .any(|expr| mutated_variables(expr, cx).map_or(true, |mutated| mutated.contains(&id)))
+}
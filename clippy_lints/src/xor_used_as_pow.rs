@@ -0,0 +1,81 @@
+use crate::utils::{snippet_opt, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_target::abi::LayoutOf;
+use std::convert::TryFrom;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `2 ^ n` and `10 ^ n` where both operands are decimal integer
+    /// literals.
+    ///
+    /// **Why is this bad?** `^` is the bitwise XOR operator in Rust, not exponentiation, so
+    /// `2 ^ 10` is `8`, not `1024`. Bitwise operations are usually written with hexadecimal or
+    /// binary literals, so these expressions are most likely meant as powers.
+    ///
+    /// **Known problems:** Bitwise operations which are intentionally written with decimal literals
+    /// are linted as well.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let kibibyte = 2 ^ 10;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let kibibyte = 1024;
+    /// ```
+    pub XOR_USED_AS_POW,
+    style,
+    "using `^` with the base and exponent of a power, which is a bitwise XOR"
+}
+
+declare_lint_pass!(XorUsedAsPow => [XOR_USED_AS_POW]);
+
+impl<'tcx> LateLintPass<'tcx> for XorUsedAsPow {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if_chain! {
+            if !expr.span.from_expansion();
+            if let ExprKind::Binary(op, left, right) = expr.kind;
+            if op.node == BinOpKind::BitXor;
+            if let Some(base) = decimal_int_lit(cx, left);
+            if base == 2 || base == 10;
+            if let Some(exponent) = decimal_int_lit(cx, right);
+            if let Ok(exponent) = u32::try_from(exponent);
+            if let Some(power) = base.checked_pow(exponent);
+            let ty = cx.typeck_results().expr_ty(expr);
+            if let Ok(layout) = cx.layout_of(ty);
+            // only powers which fit into the type are likely to be meant as powers
+            if power <= u128::MAX >> (128 - layout.size.bits() + u64::from(ty.is_signed()));
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    XOR_USED_AS_POW,
+                    expr.span,
+                    "`^` is the bitwise XOR operator, not exponentiation",
+                    "if you meant to compute a power, write the result",
+                    power.to_string(),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the value of an integer literal written in decimal, like `10` or `10_u32`, but not
+/// `0xA`.
+fn decimal_int_lit(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<u128> {
+    if_chain! {
+        if let ExprKind::Lit(lit) = &expr.kind;
+        if let LitKind::Int(value, _) = lit.node;
+        if let Some(snippet) = snippet_opt(cx, lit.span);
+        if !snippet.starts_with("0x") && !snippet.starts_with("0b") && !snippet.starts_with("0o");
+        then {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
@@ -17,6 +17,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "types",
     },
+    Lint {
+        name: "allocation_in_format_impl",
+        group: "perf",
+        desc: "allocating a `String` only to write it to the formatter in a `fmt` implementation",
+        deprecation: None,
+        module: "allocation_in_format_impl",
+    },
     Lint {
         name: "almost_swapped",
         group: "correctness",
@@ -31,6 +38,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "approx_const",
     },
+    Lint {
+        name: "arc_mutex_never_mutated",
+        group: "nursery",
+        desc: "`Arc<Mutex<T>>` whose contents are never mutated",
+        deprecation: None,
+        module: "arc_mutex_never_mutated",
+    },
     Lint {
         name: "as_conversions",
         group: "restriction",
@@ -59,6 +73,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "await_holding_lock",
     },
+    Lint {
+        name: "await_holding_refcell_ref",
+        group: "pedantic",
+        desc: "Inside an async function, holding a RefCell ref while calling await",
+        deprecation: None,
+        module: "await_holding_lock",
+    },
     Lint {
         name: "bad_bit_mask",
         group: "correctness",
@@ -87,6 +108,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "attrs",
     },
+    Lint {
+        name: "block_on_in_async",
+        group: "correctness",
+        desc: "calling `block_on` inside of an async context",
+        deprecation: None,
+        module: "blocking_io_in_async",
+    },
+    Lint {
+        name: "blocking_io_in_async",
+        group: "pedantic",
+        desc: "calling blocking I/O functions inside of an async context",
+        deprecation: None,
+        module: "blocking_io_in_async",
+    },
     Lint {
         name: "blocks_in_if_conditions",
         group: "style",
@@ -101,6 +136,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "needless_bool",
     },
+    Lint {
+        name: "bool_to_int_with_if",
+        group: "style",
+        desc: "using `if` to convert a `bool` to an integer",
+        deprecation: None,
+        module: "bool_to_int_with_if",
+    },
     Lint {
         name: "borrow_interior_mutable_const",
         group: "correctness",
@@ -108,6 +150,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "non_copy_const",
     },
+    Lint {
+        name: "borrow_of_packed_field",
+        group: "correctness",
+        desc: "creating a reference to a possibly unaligned field of a packed struct",
+        deprecation: None,
+        module: "borrow_of_packed_field",
+    },
     Lint {
         name: "borrowed_box",
         group: "complexity",
@@ -143,6 +192,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "cargo_common_metadata",
     },
+    Lint {
+        name: "case_sensitive_file_extension_comparisons",
+        group: "pedantic",
+        desc: "checking a file extension with the case-sensitive `ends_with`",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "cast_lossless",
         group: "pedantic",
@@ -192,6 +248,27 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "types",
     },
+    Lint {
+        name: "cast_slice_different_sizes",
+        group: "correctness",
+        desc: "casting a slice pointer to a slice pointer with a different element size",
+        deprecation: None,
+        module: "types",
+    },
+    Lint {
+        name: "char_as_u8",
+        group: "pedantic",
+        desc: "casting a `char` which may not be ASCII to `u8` truncates",
+        deprecation: None,
+        module: "types",
+    },
+    Lint {
+        name: "char_count_as_byte_index",
+        group: "pedantic",
+        desc: "slicing a string with a character count, which is not a byte index",
+        deprecation: None,
+        module: "char_count_as_byte_index",
+    },
     Lint {
         name: "char_lit_as_u8",
         group: "complexity",
@@ -237,7 +314,14 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "clone_on_ref_ptr",
         group: "restriction",
-        desc: "using \'clone\' on a ref-counted pointer",
+        desc: "using 'clone' on a ref-counted pointer",
+        deprecation: None,
+        module: "methods",
+    },
+    Lint {
+        name: "cloned_instead_of_copied",
+        group: "pedantic",
+        desc: "used `cloned` where `copied` could be used instead",
         deprecation: None,
         module: "methods",
     },
@@ -283,6 +367,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "comparison_chain",
     },
+    Lint {
+        name: "condvar_wait_outside_loop",
+        group: "correctness",
+        desc: "calling `Condvar::wait` without re-checking the condition in a loop",
+        deprecation: None,
+        module: "condvar_wait_outside_loop",
+    },
     Lint {
         name: "copy_iterator",
         group: "pedantic",
@@ -297,6 +388,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "transmute",
     },
+    Lint {
+        name: "dangling_ptr_from_temporary",
+        group: "correctness",
+        desc: "storing the inner pointer of a temporary `String`, `Vec` or `CString`",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "dbg_macro",
         group: "restriction",
@@ -353,6 +451,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "reference",
     },
+    Lint {
+        name: "derivable_impls",
+        group: "complexity",
+        desc: "manual implementation of the `Default` trait which is equal to a derive",
+        deprecation: None,
+        module: "derivable_impls",
+    },
     Lint {
         name: "derive_hash_xor_eq",
         group: "correctness",
@@ -360,6 +465,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "derive",
     },
+    Lint {
+        name: "derive_ord_xor_partial_ord",
+        group: "correctness",
+        desc: "deriving `Ord` but implementing `PartialOrd` explicitly",
+        deprecation: None,
+        module: "derive",
+    },
     Lint {
         name: "diverging_sub_expression",
         group: "complexity",
@@ -381,6 +493,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "double_comparison",
     },
+    Lint {
+        name: "double_lock",
+        group: "correctness",
+        desc: "locking a lock while a guard of the same lock is still alive",
+        deprecation: None,
+        module: "double_lock",
+    },
     Lint {
         name: "double_must_use",
         group: "style",
@@ -402,6 +521,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "double_parens",
     },
+    Lint {
+        name: "double_sided_float_cmp",
+        group: "complexity",
+        desc: "checking the difference of two floats in both directions instead of using `abs()`",
+        deprecation: None,
+        module: "double_sided_float_cmp",
+    },
     Lint {
         name: "drop_bounds",
         group: "correctness",
@@ -468,7 +594,7 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "enum_clike_unportable_variant",
         group: "correctness",
-        desc: "C-like enums that are `repr(isize/usize)` and have values that don\'t fit into an `i32`",
+        desc: "C-like enums that are `repr(isize/usize)` and have values that don't fit into an `i32`",
         deprecation: None,
         module: "enum_clike",
     },
@@ -486,6 +612,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "enum_variants",
     },
+    Lint {
+        name: "env_set_var",
+        group: "pedantic",
+        desc: "modifying the environment when other threads may be running",
+        deprecation: None,
+        module: "env_set_var",
+    },
     Lint {
         name: "eq_op",
         group: "correctness",
@@ -493,6 +626,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "eq_op",
     },
+    Lint {
+        name: "equatable_if_let",
+        group: "nursery",
+        desc: "using `if let` where an equality comparison would do",
+        deprecation: None,
+        module: "equatable_if_let",
+    },
     Lint {
         name: "erasing_op",
         group: "correctness",
@@ -633,6 +773,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "flat_map_option",
+        group: "style",
+        desc: "call to `flat_map` where `filter_map` is sufficient",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "float_arithmetic",
         group: "restriction",
@@ -654,6 +801,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "misc",
     },
+    Lint {
+        name: "float_partial_cmp_unwrap",
+        group: "pedantic",
+        desc: "unwrapping `partial_cmp` on floats in a comparator, which panics on NaN",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "fn_address_comparisons",
         group: "correctness",
@@ -703,6 +857,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "drop_forget_ref",
     },
+    Lint {
+        name: "forget_needs_drop",
+        group: "style",
+        desc: "`mem::forget` usage on values which need to be dropped",
+        deprecation: None,
+        module: "mem_forget",
+    },
     Lint {
         name: "forget_ref",
         group: "correctness",
@@ -710,6 +871,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "drop_forget_ref",
     },
+    Lint {
+        name: "from_iter_instead_of_collect",
+        group: "style",
+        desc: "use `.collect()` instead of `::from_iter()`",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "future_not_send",
         group: "nursery",
@@ -766,6 +934,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "copies",
     },
+    Lint {
+        name: "if_then_some_else_none",
+        group: "style",
+        desc: "`if cond { Some(x) } else { None }` which could be written with `bool::then`",
+        deprecation: None,
+        module: "if_then_some_else_none",
+    },
     Lint {
         name: "ifs_same_cond",
         group: "correctness",
@@ -794,6 +969,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "implicit_saturating_sub",
     },
+    Lint {
+        name: "imprecise_duration_arithmetic",
+        group: "pedantic",
+        desc: "truncating a `Duration` with `as_secs()` or `as_millis()` before float arithmetic or division",
+        deprecation: None,
+        module: "duration_subsec",
+    },
     Lint {
         name: "imprecise_flops",
         group: "nursery",
@@ -808,6 +990,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "literal_representation",
     },
+    Lint {
+        name: "inconsistent_struct_constructor",
+        group: "pedantic",
+        desc: "the order of the field init expressions is inconsistent with the order in the struct definition",
+        deprecation: None,
+        module: "inconsistent_struct_constructor",
+    },
     Lint {
         name: "indexing_slicing",
         group: "restriction",
@@ -906,6 +1095,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "atomic_ordering",
     },
+    Lint {
+        name: "invalid_null_ptr_usage",
+        group: "correctness",
+        desc: "null pointers passed to functions which require non-null pointers",
+        deprecation: None,
+        module: "ptr",
+    },
     Lint {
         name: "invalid_regex",
         group: "correctness",
@@ -934,6 +1130,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "iter_kv_map",
+        group: "style",
+        desc: "iterating on a map and only using its keys or values",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "iter_next_loop",
         group: "correctness",
@@ -962,6 +1165,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "iter_over_hash_type",
+        group: "restriction",
+        desc: "iterating over a `HashMap` or `HashSet`, whose iteration order is unspecified",
+        deprecation: None,
+        module: "iter_over_hash_type",
+    },
     Lint {
         name: "iter_skip_next",
         group: "style",
@@ -969,6 +1179,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "iter_with_drain",
+        group: "style",
+        desc: "using `.drain(..)` on a `Vec` or `VecDeque` which could be consumed with `.into_iter()`",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "iterator_step_by_zero",
         group: "correctness",
@@ -976,6 +1193,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "join_absolute_path",
+        group: "style",
+        desc: "calling `Path::join` with an absolute path, which discards the base path",
+        deprecation: None,
+        module: "path_buf_push_overwrite",
+    },
     Lint {
         name: "just_underscores_and_digits",
         group: "style",
@@ -1032,6 +1256,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "let_and_return",
     },
+    Lint {
+        name: "let_underscore_future",
+        group: "correctness",
+        desc: "non-binding let on a future",
+        deprecation: None,
+        module: "let_underscore",
+    },
     Lint {
         name: "let_underscore_lock",
         group: "correctness",
@@ -1049,7 +1280,7 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "let_unit_value",
         group: "pedantic",
-        desc: "creating a `let` binding to a value of unit type, which usually can\'t be used afterwards",
+        desc: "creating a `let` binding to a value of unit type, which usually can't be used afterwards",
         deprecation: None,
         module: "types",
     },
@@ -1088,6 +1319,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "main_recursion",
     },
+    Lint {
+        name: "manual_assert",
+        group: "style",
+        desc: "`panic!` and only a `panic!` in `if`-then statement",
+        deprecation: None,
+        module: "manual_assert",
+    },
     Lint {
         name: "manual_async_fn",
         group: "style",
@@ -1095,6 +1333,48 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "manual_async_fn",
     },
+    Lint {
+        name: "manual_clamp",
+        group: "complexity",
+        desc: "manually restricting a value to a range instead of using `clamp`",
+        deprecation: None,
+        module: "manual_clamp",
+    },
+    Lint {
+        name: "manual_enumerate",
+        group: "style",
+        desc: "zipping an iterator with `0..` when `enumerate()` would do",
+        deprecation: None,
+        module: "ranges",
+    },
+    Lint {
+        name: "manual_filter",
+        group: "complexity",
+        desc: "reimplementing `Option::filter` with a `match` or `and_then`",
+        deprecation: None,
+        module: "manual_filter",
+    },
+    Lint {
+        name: "manual_flatten",
+        group: "style",
+        desc: "`for` loops with an `if let Some(..)` or `if let Ok(..)` which could iterate over a flattened iterator",
+        deprecation: None,
+        module: "loops",
+    },
+    Lint {
+        name: "manual_is_ascii_check",
+        group: "style",
+        desc: "checking whether a character lies in an ASCII range manually instead of using `is_ascii_*`",
+        deprecation: None,
+        module: "manual_is_ascii_check",
+    },
+    Lint {
+        name: "manual_map",
+        group: "style",
+        desc: "reimplementing `Option::map` with a `match` or `if let`",
+        deprecation: None,
+        module: "manual_map",
+    },
     Lint {
         name: "manual_memcpy",
         group: "perf",
@@ -1109,6 +1389,27 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "manual_non_exhaustive",
     },
+    Lint {
+        name: "manual_ok_or",
+        group: "pedantic",
+        desc: "reimplementing `Option::ok_or` or `Option::ok_or_else` with a `match` or `if let`",
+        deprecation: None,
+        module: "manual_ok_or",
+    },
+    Lint {
+        name: "manual_range_contains",
+        group: "style",
+        desc: "manually reimplementing {`Range`, `RangeInclusive`}`::contains`",
+        deprecation: None,
+        module: "ranges",
+    },
+    Lint {
+        name: "manual_retain",
+        group: "perf",
+        desc: "filtering a collection into a new one that replaces it, instead of using `retain()`",
+        deprecation: None,
+        module: "manual_retain",
+    },
     Lint {
         name: "manual_saturating_arithmetic",
         group: "style",
@@ -1116,6 +1417,41 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "manual_split_once",
+        group: "complexity",
+        desc: "replace `s.splitn(2, pat)` with `s.split_once(pat)`",
+        deprecation: None,
+        module: "manual_split_once",
+    },
+    Lint {
+        name: "manual_str_repeat",
+        group: "perf",
+        desc: "manually repeating a string instead of using `str::repeat`",
+        deprecation: None,
+        module: "manual_str_repeat",
+    },
+    Lint {
+        name: "manual_string_new",
+        group: "pedantic",
+        desc: "empty `String` created from an empty string literal instead of `String::new()`",
+        deprecation: None,
+        module: "manual_string_new",
+    },
+    Lint {
+        name: "manual_strip",
+        group: "complexity",
+        desc: "slicing off a tested prefix or suffix manually instead of using `strip_prefix` or `strip_suffix`",
+        deprecation: None,
+        module: "manual_strip",
+    },
+    Lint {
+        name: "manual_struct_update",
+        group: "style",
+        desc: "struct constructor copying fields one by one from another instance of the struct",
+        deprecation: None,
+        module: "manual_struct_update",
+    },
     Lint {
         name: "manual_swap",
         group: "complexity",
@@ -1123,6 +1459,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "swap",
     },
+    Lint {
+        name: "manual_unwrap_or",
+        group: "complexity",
+        desc: "reimplementing `unwrap_or`, `unwrap_or_else` or `unwrap_or_default` with a `match` or `if let`",
+        deprecation: None,
+        module: "manual_unwrap_or",
+    },
     Lint {
         name: "many_single_char_names",
         group: "style",
@@ -1291,6 +1634,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "attrs",
     },
+    Lint {
+        name: "misnamed_getters",
+        group: "style",
+        desc: "getter methods returning a different field than the one they are named after",
+        deprecation: None,
+        module: "misnamed_getters",
+    },
     Lint {
         name: "misrefactored_assign_op",
         group: "complexity",
@@ -1305,6 +1655,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "missing_const_for_fn",
     },
+    Lint {
+        name: "missing_derive_copy",
+        group: "pedantic",
+        desc: "type deriving `Clone` which is cloned and could derive `Copy` as well",
+        deprecation: None,
+        module: "missing_derive_copy",
+    },
     Lint {
         name: "missing_docs_in_private_items",
         group: "restriction",
@@ -1357,7 +1714,7 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "module_name_repetitions",
         group: "pedantic",
-        desc: "type names prefixed/postfixed with their containing module\'s name",
+        desc: "type names prefixed/postfixed with their containing module's name",
         deprecation: None,
         module: "enum_variants",
     },
@@ -1389,6 +1746,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "inherent_impl",
     },
+    Lint {
+        name: "multiple_unsafe_ops_per_block",
+        group: "restriction",
+        desc: "`unsafe` blocks containing more than the allowed number of unsafe operations",
+        deprecation: None,
+        module: "multiple_unsafe_ops_per_block",
+    },
     Lint {
         name: "must_use_candidate",
         group: "pedantic",
@@ -1494,6 +1858,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "doc",
     },
+    Lint {
+        name: "needless_for_each",
+        group: "pedantic",
+        desc: "using `for_each` where a `for` loop would be simpler",
+        deprecation: None,
+        module: "needless_for_each",
+    },
+    Lint {
+        name: "needless_late_init",
+        group: "style",
+        desc: "late initializations that can be replaced by a `let` statement with an initializer",
+        deprecation: None,
+        module: "needless_late_init",
+    },
     Lint {
         name: "needless_lifetimes",
         group: "complexity",
@@ -1508,6 +1886,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "needless_pass_by_value",
     },
+    Lint {
+        name: "needless_question_mark",
+        group: "style",
+        desc: "`Some(x?)` or `Ok(x?)` in return position which could just be `x`",
+        deprecation: None,
+        module: "needless_question_mark",
+    },
     Lint {
         name: "needless_range_loop",
         group: "style",
@@ -1578,6 +1963,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "unicode",
     },
+    Lint {
+        name: "non_octal_unix_permissions",
+        group: "correctness",
+        desc: "decimal literals which look like octal Unix permission modes",
+        deprecation: None,
+        module: "non_octal_unix_permissions",
+    },
     Lint {
         name: "nonminimal_bool",
         group: "complexity",
@@ -1599,6 +1991,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "functions",
     },
+    Lint {
+        name: "obfuscated_if_else",
+        group: "style",
+        desc: "use of `.then(..).unwrap_or(..)`, which can be written more clearly with `if .. else ..`",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "ok_expect",
         group: "style",
@@ -1629,7 +2028,7 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     },
     Lint {
         name: "option_if_let_else",
-        group: "pedantic",
+        group: "nursery",
         desc: "reimplementation of Option::map_or",
         deprecation: None,
         module: "option_if_let_else",
@@ -1683,6 +2082,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "panic_unimplemented",
     },
+    Lint {
+        name: "panic_in_drop",
+        group: "pedantic",
+        desc: "`Drop::drop` implementations which may panic",
+        deprecation: None,
+        module: "panic_in_drop",
+    },
+    Lint {
+        name: "panic_in_extern_fn",
+        group: "pedantic",
+        desc: "functions with a foreign ABI which may unwind into foreign code",
+        deprecation: None,
+        module: "panic_in_extern_fn",
+    },
     Lint {
         name: "panic_params",
         group: "style",
@@ -1809,6 +2222,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "ranges",
     },
+    Lint {
+        name: "read_into_new_vec",
+        group: "correctness",
+        desc: "reading into a new vector, which is still empty, so nothing is read",
+        deprecation: None,
+        module: "uninit_vec",
+    },
+    Lint {
+        name: "recursive_format_impl",
+        group: "correctness",
+        desc: "`Display` or `Debug` implementations which format `self` with the same trait",
+        deprecation: None,
+        module: "recursive_format_impl",
+    },
     Lint {
         name: "redundant_allocation",
         group: "perf",
@@ -1844,6 +2271,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "eta_reduction",
     },
+    Lint {
+        name: "redundant_else",
+        group: "pedantic",
+        desc: "`else` branch that can be removed without changing semantics",
+        deprecation: None,
+        module: "redundant_else",
+    },
     Lint {
         name: "redundant_field_names",
         group: "style",
@@ -1875,7 +2309,7 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "redundant_static_lifetimes",
         group: "style",
-        desc: "Using explicit `\'static` lifetime for constants or statics when elision rules would allow omitting them.",
+        desc: "Using explicit `'static` lifetime for constants or statics when elision rules would allow omitting them.",
         deprecation: None,
         module: "redundant_static_lifetimes",
     },
@@ -1935,6 +2369,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "self_assignment",
+        group: "correctness",
+        desc: "assigning a place to itself",
+        deprecation: None,
+        module: "self_assignment",
+    },
+    Lint {
+        name: "semicolon_if_nothing_returned",
+        group: "pedantic",
+        desc: "add a semicolon if nothing is returned",
+        deprecation: None,
+        module: "semicolon_if_nothing_returned",
+    },
     Lint {
         name: "serde_api_misuse",
         group: "correctness",
@@ -1977,6 +2425,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "signed_rem_as_unsigned",
+        group: "pedantic",
+        desc: "casting the remainder of a signed integer division to an unsigned type",
+        deprecation: None,
+        module: "signed_rem_as_unsigned",
+    },
     Lint {
         name: "similar_names",
         group: "pedantic",
@@ -1991,6 +2446,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "single_char_push_str",
+        group: "style",
+        desc: "`push_str()` used with a single-character string literal as parameter",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "single_component_path_imports",
         group: "style",
@@ -2008,10 +2470,17 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "single_match_else",
         group: "pedantic",
-        desc: "a `match` statement with two arms where the second arm\'s pattern is a placeholder instead of a specific match pattern",
+        desc: "a `match` statement with two arms where the second arm's pattern is a placeholder instead of a specific match pattern",
         deprecation: None,
         module: "matches",
     },
+    Lint {
+        name: "size_of_in_element_count",
+        group: "correctness",
+        desc: "using `size_of::<T>()` or `size_of_val(..)` where a count of elements of `T` is expected",
+        deprecation: None,
+        module: "size_of_in_element_count",
+    },
     Lint {
         name: "skip_while_next",
         group: "complexity",
@@ -2026,6 +2495,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "slow_vector_initialization",
     },
+    Lint {
+        name: "static_mut",
+        group: "restriction",
+        desc: "declaring a `static mut` item",
+        deprecation: None,
+        module: "static_mut",
+    },
     Lint {
         name: "string_add",
         group: "restriction",
@@ -2131,6 +2607,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "thread_sleep_in_async",
+        group: "pedantic",
+        desc: "calling `std::thread::sleep` inside of an async context",
+        deprecation: None,
+        module: "thread_sleep_in_async",
+    },
     Lint {
         name: "to_digit_is_some",
         group: "style",
@@ -2138,6 +2621,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "to_digit_is_some",
     },
+    Lint {
+        name: "to_string_trait_impl",
+        group: "style",
+        desc: "check for direct implementations of `ToString`",
+        deprecation: None,
+        module: "to_string_trait_impl",
+    },
     Lint {
         name: "todo",
         group: "restriction",
@@ -2201,6 +2691,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "transmute",
     },
+    Lint {
+        name: "transmute_int_to_ptr",
+        group: "complexity",
+        desc: "transmutes from an integer to a pointer",
+        deprecation: None,
+        module: "transmute",
+    },
+    Lint {
+        name: "transmute_ptr_to_int",
+        group: "complexity",
+        desc: "transmutes from a pointer to an integer",
+        deprecation: None,
+        module: "transmute",
+    },
     Lint {
         name: "transmute_ptr_to_ptr",
         group: "complexity",
@@ -2250,6 +2754,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "types",
     },
+    Lint {
+        name: "type_name_comparison",
+        group: "correctness",
+        desc: "comparing the results of `type_name` to check whether types are the same",
+        deprecation: None,
+        module: "type_name_comparison",
+    },
     Lint {
         name: "type_repetition_in_bounds",
         group: "pedantic",
@@ -2257,6 +2768,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "trait_bounds",
     },
+    Lint {
+        name: "unchecked_instant_subtraction",
+        group: "pedantic",
+        desc: "subtracting `Instant`s, which panics if they are in the wrong order",
+        deprecation: None,
+        module: "unchecked_instant_subtraction",
+    },
+    Lint {
+        name: "unchecked_integer_abs",
+        group: "restriction",
+        desc: "calling `abs()` on a signed integer which may be the minimum value",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "unicode_not_nfc",
         group: "pedantic",
@@ -2278,6 +2803,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "uninit_vec",
+        group: "correctness",
+        desc: "`Vec::set_len` exposing the uninitialized spare capacity of a new vector",
+        deprecation: None,
+        module: "uninit_vec",
+    },
     Lint {
         name: "unit_arg",
         group: "complexity",
@@ -2327,6 +2859,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "methods",
     },
+    Lint {
+        name: "unnecessary_lazy_evaluations",
+        group: "style",
+        desc: "using unnecessary lazy evaluation, which can be replaced with simpler eager evaluation",
+        deprecation: None,
+        module: "methods",
+    },
     Lint {
         name: "unnecessary_mut_passed",
         group: "style",
@@ -2418,6 +2957,20 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "transmute",
     },
+    Lint {
+        name: "unused_async",
+        group: "pedantic",
+        desc: "finds async functions with no await statements",
+        deprecation: None,
+        module: "unused_async",
+    },
+    Lint {
+        name: "unused_enumerate_index",
+        group: "style",
+        desc: "using `.enumerate()` and immediately dropping the index",
+        deprecation: None,
+        module: "unused_enumerate_index",
+    },
     Lint {
         name: "unused_io_amount",
         group: "correctness",
@@ -2428,7 +2981,7 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
     Lint {
         name: "unused_self",
         group: "pedantic",
-        desc: "methods that contain a `self` argument but don\'t use it",
+        desc: "methods that contain a `self` argument but don't use it",
         deprecation: None,
         module: "unused_self",
     },
@@ -2523,6 +3076,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "types",
     },
+    Lint {
+        name: "vec_init_then_push",
+        group: "perf",
+        desc: "`push` immediately after `Vec` creation",
+        deprecation: None,
+        module: "vec_init_then_push",
+    },
     Lint {
         name: "vec_resize_to_zero",
         group: "correctness",
@@ -2607,6 +3167,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "write",
     },
+    Lint {
+        name: "write_to_new_vec",
+        group: "correctness",
+        desc: "index assignments on a new vector, which is still empty",
+        deprecation: None,
+        module: "uninit_vec",
+    },
     Lint {
         name: "write_with_newline",
         group: "style",
@@ -2642,6 +3209,13 @@ pub static ref ALL_LINTS: Vec<Lint> = vec![
         deprecation: None,
         module: "transmute",
     },
+    Lint {
+        name: "xor_used_as_pow",
+        group: "style",
+        desc: "using `^` with the base and exponent of a power, which is a bitwise XOR",
+        deprecation: None,
+        module: "xor_used_as_pow",
+    },
     Lint {
         name: "zero_divided_by_zero",
         group: "complexity",
@@ -89,9 +89,10 @@ impl ClippyCmd {
             args.push(arg);
         }
 
-        if cargo_subcommand == "fix" && !unstable_options {
-            panic!("Usage of `--fix` requires `-Z unstable-options`");
-        }
+        assert!(
+            cargo_subcommand != "fix" || unstable_options,
+            "Usage of `--fix` requires `-Z unstable-options`"
+        );
 
         // Run the dogfood tests directly on nightly cargo. This is required due
         // to a bug in rustup.rs when running cargo on custom toolchains. See issue #3118.
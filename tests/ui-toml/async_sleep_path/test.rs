@@ -0,0 +1,10 @@
+// edition:2018
+#![warn(clippy::thread_sleep_in_async)]
+
+use std::time::Duration;
+
+async fn bad() {
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![warn(clippy::missing_derive_copy)]
+
+#[derive(Clone)]
+pub struct Exported(u8);
+
+fn take<T>(_: T) {}
+
+fn main() {
+    let exported = Exported(1);
+    take(exported.clone());
+    take(exported);
+}
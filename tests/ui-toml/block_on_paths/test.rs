@@ -0,0 +1,34 @@
+// edition:2018
+#![warn(clippy::block_on_in_async)]
+
+mod executor {
+    use std::future::Future;
+
+    pub fn block_on<F: Future>(_future: F) -> F::Output {
+        unimplemented!()
+    }
+}
+
+async fn foo() -> i32 {
+    42
+}
+
+async fn bad() -> i32 {
+    executor::block_on(foo())
+}
+
+async fn good() -> i32 {
+    foo().await
+}
+
+fn not_async() -> i32 {
+    executor::block_on(foo())
+}
+
+fn main() {
+    let _fut = async {
+        // the closure is expected to run on a thread that may block
+        let _ = std::thread::spawn(|| executor::block_on(foo()));
+        executor::block_on(foo())
+    };
+}
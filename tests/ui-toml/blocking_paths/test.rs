@@ -0,0 +1,18 @@
+// edition:2018
+#![warn(clippy::blocking_io_in_async)]
+
+mod sync_client {
+    pub fn get(_url: &str) -> String {
+        String::new()
+    }
+}
+
+async fn configured() -> String {
+    sync_client::get("http://example.com")
+}
+
+async fn default() -> std::io::Result<String> {
+    std::fs::read_to_string("foo.txt")
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![warn(clippy::multiple_unsafe_ops_per_block)]
+
+unsafe fn unsafe_fn() {}
+
+fn main() {
+    // no lint
+    unsafe {
+        unsafe_fn();
+        unsafe_fn();
+    }
+
+    unsafe {
+        unsafe_fn();
+        unsafe_fn();
+        unsafe_fn();
+    }
+}
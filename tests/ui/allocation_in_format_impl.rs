@@ -0,0 +1,57 @@
+#![warn(clippy::allocation_in_format_impl)]
+
+use std::fmt;
+
+struct Person {
+    name: String,
+    age: u32,
+}
+
+impl fmt::Display for Person {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.age.to_string())
+    }
+}
+
+impl fmt::Debug for Person {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format!("Person {{ name: {:?}, age: {} }}", self.name, self.age))
+    }
+}
+
+struct Pair(Person, Person);
+
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} and {}", format!("{}", self.0), self.1)
+    }
+}
+
+struct Padded(u32);
+
+impl fmt::Display for Padded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // ok, the width applies to the string
+        write!(f, "{:>5}", self.0.to_string())
+    }
+}
+
+struct Plain(u32);
+
+impl fmt::Display for Plain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // ok, the allocation isn't part of a formatting macro
+        let s = self.0.to_string();
+        f.write_str(&s)
+    }
+}
+
+fn main() {
+    // ok, outside of `fmt`
+    let person = Person {
+        name: String::from("Ferris"),
+        age: 5,
+    };
+    let mut s = String::new();
+    s.push_str(&format!("{}!", person.age.to_string()));
+}
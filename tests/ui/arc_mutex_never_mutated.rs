@@ -0,0 +1,145 @@
+#![warn(clippy::arc_mutex_never_mutated)]
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+struct ReadOnly {
+    name: Arc<Mutex<String>>,
+}
+
+impl ReadOnly {
+    fn name_len(&self) -> usize {
+        self.name.lock().unwrap().len()
+    }
+
+    fn spawn(&self) {
+        let name = Arc::clone(&self.name);
+        thread::spawn(move || {
+            let guard = name.lock().unwrap();
+            println!("{}", *guard);
+        });
+    }
+}
+
+struct Mutated {
+    name: Arc<Mutex<String>>,
+}
+
+impl Mutated {
+    fn name_len(&self) -> usize {
+        self.name.lock().unwrap().len()
+    }
+
+    fn spawn(&self) {
+        let name = self.name.clone();
+        thread::spawn(move || name.lock().unwrap().push('!'));
+    }
+}
+
+pub struct Public {
+    pub name: Arc<Mutex<String>>,
+}
+
+impl Public {
+    pub fn name_len(&self) -> usize {
+        self.name.lock().unwrap().len()
+    }
+}
+
+struct Escaping {
+    count: Arc<Mutex<u32>>,
+}
+
+fn increment(count: &Arc<Mutex<u32>>) {
+    *count.lock().unwrap() += 1;
+}
+
+impl Escaping {
+    fn count(&self) -> u32 {
+        increment(&self.count);
+        *self.count.lock().unwrap()
+    }
+}
+
+fn locals() {
+    let read_only = Arc::new(Mutex::new(vec![1, 2, 3]));
+    let clone = read_only.clone();
+    thread::spawn(move || clone.lock().unwrap().len());
+    let _first = read_only.lock().unwrap()[0];
+
+    let mutated = Arc::new(Mutex::new(vec![1, 2, 3]));
+    let clone = Arc::clone(&mutated);
+    thread::spawn(move || clone.lock().unwrap().push(4));
+    let _first = mutated.lock().unwrap()[0];
+
+    let assigned = Arc::new(Mutex::new(0));
+    *assigned.lock().unwrap() = 1;
+
+    let guard_mutated = Arc::new(Mutex::new(0));
+    let mut guard = guard_mutated.lock().unwrap();
+    *guard += 1;
+
+    // never locked
+    let _unused = Arc::new(Mutex::new(0));
+}
+
+impl MutatedBeforeDefinition {
+    fn push(&self) {
+        self.items.lock().unwrap().push(1);
+    }
+}
+
+struct MutatedBeforeDefinition {
+    items: Arc<Mutex<Vec<u32>>>,
+}
+
+impl MutatedBeforeDefinition {
+    fn len(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+}
+
+impl ReadBeforeDefinition {
+    fn len(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+}
+
+struct ReadBeforeDefinition {
+    items: Arc<Mutex<Vec<u32>>>,
+}
+
+struct SharedInit {
+    count: Arc<Mutex<u32>>,
+}
+
+impl SharedInit {
+    fn count(&self) -> u32 {
+        *self.count.lock().unwrap()
+    }
+}
+
+fn shared_init() {
+    let count = Arc::new(Mutex::new(0));
+    let shared = SharedInit { count: count.clone() };
+    *count.lock().unwrap() += 1;
+    let _ = shared.count();
+}
+
+struct FreshInit {
+    count: Arc<Mutex<u32>>,
+}
+
+impl FreshInit {
+    fn new() -> Self {
+        Self {
+            count: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    fn count(&self) -> u32 {
+        *self.count.lock().unwrap()
+    }
+}
+
+fn main() {}
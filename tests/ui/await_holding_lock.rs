@@ -1,7 +1,7 @@
 // edition:2018
 #![warn(clippy::await_holding_lock)]
 
-use std::sync::Mutex;
+use std::sync::{Mutex, RwLock};
 
 async fn bad(x: &Mutex<u32>) -> u32 {
     let guard = x.lock().unwrap();
@@ -54,6 +54,24 @@ fn block_bad(x: &Mutex<u32>) -> impl std::future::Future<Output = u32> + '_ {
     }
 }
 
+async fn read_bad(x: &RwLock<u32>) -> u32 {
+    let guard = x.read().unwrap();
+    baz().await
+}
+
+async fn write_bad(x: &RwLock<u32>) -> u32 {
+    let guard = x.write().unwrap();
+    baz().await
+}
+
+async fn read_good(x: &RwLock<u32>) -> u32 {
+    {
+        let guard = x.read().unwrap();
+        let y = *guard + 1;
+    }
+    baz().await
+}
+
 fn main() {
     let m = Mutex::new(100);
     good(&m);
@@ -61,4 +79,9 @@ fn main() {
     also_bad(&m);
     not_good(&m);
     block_bad(&m);
+
+    let rw = RwLock::new(100);
+    read_bad(&rw);
+    write_bad(&rw);
+    read_good(&rw);
 }
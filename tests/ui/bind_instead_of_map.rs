@@ -1,5 +1,6 @@
 // run-rustfix
 #![deny(clippy::bind_instead_of_map)]
+#![allow(clippy::if_then_some_else_none, clippy::manual_filter)]
 
 // need a main anyway, use it get rid of unused warnings too
 pub fn main() {
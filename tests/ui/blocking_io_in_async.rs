@@ -0,0 +1,38 @@
+// edition:2018
+#![warn(clippy::blocking_io_in_async)]
+
+use std::fs::{self, File};
+use std::io;
+use std::net::TcpStream;
+
+async fn fs() -> io::Result<()> {
+    let _ = fs::read_to_string("foo.txt")?;
+    fs::write("foo.txt", "foo")?;
+    let _ = File::open("foo.txt")?;
+    std::fs::remove_file("foo.txt")
+}
+
+async fn net() -> io::Result<TcpStream> {
+    TcpStream::connect("127.0.0.1:8080")
+}
+
+async fn stdin() -> io::Result<String> {
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    Ok(line)
+}
+
+fn not_async() -> io::Result<String> {
+    fs::read_to_string("foo.txt")
+}
+
+async fn closure() {
+    // the closure may be called from outside of the async context
+    let _ = || fs::read_to_string("foo.txt");
+}
+
+fn main() {
+    let _fut = async {
+        let _ = fs::read("foo.txt");
+    };
+}
@@ -0,0 +1,47 @@
+#![warn(clippy::borrow_of_packed_field)]
+#![allow(safe_packed_borrows)]
+
+struct Inner {
+    x: u16,
+}
+
+#[repr(packed)]
+struct Packed {
+    tag: u8,
+    value: u32,
+    bytes: [u8; 4],
+    inner: Inner,
+}
+
+#[repr(packed(2))]
+struct Packed2 {
+    tag: u8,
+    half: u16,
+    value: u32,
+}
+
+fn main() {
+    let mut packed = Packed {
+        tag: 0,
+        value: 1,
+        bytes: [0; 4],
+        inner: Inner { x: 2 },
+    };
+    let packed2 = Packed2 { tag: 0, half: 1, value: 2 };
+    unsafe {
+        let _ = &packed.value;
+        let _ = &mut packed.value;
+        let _ = &packed.inner.x;
+        let _ = packed.value.to_string();
+        let _ = &packed2.value;
+    }
+    let _ = &packed.value;
+
+    // no lint
+    let _ = &packed.tag;
+    let _ = &packed.bytes;
+    let _ = &packed2.half;
+    let value = packed.value;
+    let _ = &value;
+    let _ = packed.value + 1;
+}
@@ -0,0 +1,28 @@
+#![warn(clippy::case_sensitive_file_extension_comparisons)]
+
+use std::path::Path;
+
+fn is_rust(file_name: &str) -> bool {
+    file_name.ends_with(".rs")
+}
+
+fn is_toml(file_name: String) -> bool {
+    file_name.ends_with(".TOML")
+}
+
+fn main() {
+    let file_name = "Cargo.toml";
+
+    // not file extensions
+    let _ = file_name.ends_with(".123");
+    let _ = file_name.ends_with("toml");
+    let _ = file_name.ends_with(".tar.gz");
+    let _ = file_name.ends_with(".extension");
+    let _ = file_name.ends_with('.');
+
+    // not a string
+    let _ = Path::new(file_name).ends_with(".toml");
+
+    let _ = is_rust(file_name);
+    let _ = is_toml(file_name.to_string());
+}
@@ -29,3 +29,19 @@ fn main() {
         *(value as *const i16 as *mut i16) = 42;
     }
 }
+
+fn through_binding(r: &i32, mut_ref: &mut i32) {
+    let ptr = r as *const i32;
+    let coerced: *const i32 = r;
+    unsafe {
+        // Should be warned against
+        *(ptr as *mut i32) += 1;
+        let _ = &mut *(coerced as *mut i32);
+        // Shouldn't be warned against
+        let mut_ptr: *const i32 = mut_ref;
+        *(mut_ptr as *mut i32) = 1;
+        let mut reassigned = r as *const i32;
+        reassigned = std::ptr::null();
+        let _ = &mut *(reassigned as *mut i32);
+    }
+}
@@ -0,0 +1,27 @@
+#![warn(clippy::cast_slice_different_sizes)]
+
+fn main() {
+    let mut array = [0u8; 8];
+    let bytes: &[u8] = &array;
+
+    let _words = bytes as *const [u8] as *const [u32];
+    let _halves = &array[..] as *const [u8] as *const [u16];
+    let _chain = bytes as *const [u8] as *const [u16] as *const [u32];
+
+    let ptr: *const [u8] = bytes;
+    let _longs = ptr as *const [u64];
+
+    // same element size
+    let _signed = bytes as *const [u8] as *const [i8];
+    let _ints = &[0u32; 2] as &[u32] as *const [u32] as *const [i32];
+    let _same = bytes as *const [u8];
+    let units: &[()] = &[(); 4];
+    let _empty = units as *const [()] as *const [[u8; 0]];
+
+    // zero-sized elements on one side
+    let _to_units = bytes as *const [u8] as *const [()];
+    let _from_units = units as *const [()] as *const [u8];
+
+    let bytes_mut: &mut [u8] = &mut array;
+    let _words_mut = bytes_mut as *mut [u8] as *mut [u32];
+}
@@ -0,0 +1,38 @@
+#![warn(clippy::char_as_u8)]
+
+fn bad(c: char, chars: &[char]) {
+    let _ = c as u8;
+    let _ = chars[0] as u8;
+    let _ = c.to_ascii_uppercase() as u8;
+    if c.is_alphabetic() {
+        let _ = c as u8;
+    }
+    if c.is_ascii() {
+        // `chars[0]` is not checked
+        let _ = chars[0] as u8;
+    } else {
+        let _ = c as u8;
+    }
+}
+
+fn good(c: char, chars: &[char]) {
+    let _ = c as u32;
+    let _ = b'a' as char;
+    if c.is_ascii() {
+        let _ = c as u8;
+    }
+    if c.is_ascii() {
+        let _: Vec<u8> = chars.iter().map(|_| c as u8).collect();
+    }
+    if !chars.is_empty() && chars[0].is_ascii_digit() {
+        let _ = (chars[0] as u8 - b'0') as u32;
+    }
+    match Some(c) {
+        Some(x) if x.is_ascii_lowercase() => {
+            let _ = x as u8;
+        },
+        _ => {},
+    }
+}
+
+fn main() {}
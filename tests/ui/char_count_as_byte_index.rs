@@ -0,0 +1,21 @@
+#![warn(clippy::char_count_as_byte_index)]
+
+fn main() {
+    let s = "héllo, wörld";
+    let string = String::from(s);
+
+    // lint
+    let _ = &s[..s.chars().count() - 1];
+    let _ = &string[1..string.chars().count()];
+    let _ = &s[s.chars().position(|c| c == ',').unwrap()..];
+    let n = 2;
+    let _ = &s[n..s.chars().count() - n];
+
+    // ok
+    let _ = &s[..s.len() - 1];
+    let _ = &s[..s.find(',').unwrap()];
+    let _ = &s[..s.char_indices().nth(2).map_or(0, |(i, _)| i)];
+    let v = vec![1, 2, 3];
+    let _ = &v[..s.chars().count() % 3];
+    let _: String = s.chars().take(s.chars().count() - 1).collect();
+}
@@ -0,0 +1,28 @@
+// run-rustfix
+#![feature(custom_inner_attributes)]
+#![warn(clippy::cloned_instead_of_copied)]
+#![allow(dead_code)]
+
+fn main() {
+    // yay
+    let _ = [1].iter().cloned();
+    let _ = vec!["hi"].iter().cloned();
+    let _ = Some(&1).cloned();
+
+    // nay
+    let _ = [String::new()].iter().cloned();
+    let _ = Some(&String::new()).cloned();
+}
+
+fn msrv_1_35() {
+    #![clippy::msrv = "1.35"]
+    // `copied` was stabilized in 1.36 for iterators and 1.35 for options
+    let _ = [1].iter().cloned();
+    let _ = Some(&1).cloned();
+}
+
+fn msrv_1_34() {
+    #![clippy::msrv = "1.34"]
+    let _ = [1].iter().cloned();
+    let _ = Some(&1).cloned();
+}
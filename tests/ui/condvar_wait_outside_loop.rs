@@ -0,0 +1,36 @@
+#![warn(clippy::condvar_wait_outside_loop)]
+
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+fn bad(lock: &Mutex<bool>, cvar: &Condvar) {
+    let mut started = lock.lock().unwrap();
+    if !*started {
+        started = cvar.wait(started).unwrap();
+    }
+    let _guard = cvar.wait_timeout(started, Duration::from_millis(10)).unwrap();
+}
+
+fn good(lock: &Mutex<bool>, cvar: &Condvar) {
+    let mut started = lock.lock().unwrap();
+    while !*started {
+        started = cvar.wait(started).unwrap();
+    }
+    loop {
+        let (guard, _) = cvar.wait_timeout(started, Duration::from_millis(10)).unwrap();
+        started = guard;
+        if *started {
+            break;
+        }
+    }
+    let _guard = cvar.wait_while(started, |started| !*started).unwrap();
+}
+
+fn closure_in_loop(lock: &Mutex<bool>, cvar: &Condvar) {
+    for _ in 0..2 {
+        // the loop doesn't re-check the condition in the closure
+        let _ = || cvar.wait(lock.lock().unwrap());
+    }
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+#![warn(clippy::dangling_ptr_from_temporary)]
+#![allow(clippy::temporary_cstring_as_ptr)]
+
+use std::ffi::CString;
+
+fn ffi(_: *const u8) {}
+
+fn bad(early: bool) -> *const u8 {
+    let _ptr = String::from("foo").as_ptr();
+    let _ptr = vec![1u8, 2, 3].as_ptr();
+    let _ptr = CString::new("foo").unwrap().as_bytes().as_ptr();
+    let _ptr = format!("{}", 42).as_bytes().as_ptr() as *const i8;
+
+    let mut ptr = std::ptr::null();
+    ptr = String::from_utf8(vec![b'a']).unwrap().as_ptr();
+
+    if early {
+        return String::from("foo").as_ptr();
+    }
+    ptr
+}
+
+fn good() {
+    // used in the same statement
+    ffi(String::from("foo").as_ptr());
+    let _len = unsafe { *vec![1u8].as_ptr() };
+
+    // not a temporary
+    let string = String::from("foo");
+    let _ptr = string.as_ptr();
+    let strings = vec![String::from("foo")];
+    let _ptr = strings[0].as_ptr();
+
+    // linted by `temporary_cstring_as_ptr`
+    let _ptr = CString::new("foo").unwrap().as_ptr();
+}
+
+fn main() {}
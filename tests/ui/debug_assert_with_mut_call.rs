@@ -114,6 +114,22 @@ fn misc() {
         bool_mut(&mut x);
         x > 10
     })());
+
+    // assignments
+    let mut counter = 0_u32;
+    debug_assert!({
+        counter += 1;
+        counter < 10
+    });
+    let mut y = 0;
+    debug_assert_eq!(
+        {
+            y = 3;
+            y
+        },
+        3
+    );
+    debug_assert!(counter < 10);
 }
 
 async fn debug_await() {
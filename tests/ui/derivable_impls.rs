@@ -0,0 +1,100 @@
+// run-rustfix
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+struct FooDefault<'a> {
+    a: bool,
+    b: i32,
+    c: u64,
+    d: Vec<i32>,
+    e: FooNoDefault,
+    f: HashMap<i32, i32>,
+    g: (i32, String),
+    h: Option<i32>,
+    i: &'a str,
+    j: f64,
+}
+
+impl std::default::Default for FooDefault<'_> {
+    fn default() -> Self {
+        Self {
+            a: false,
+            b: 0,
+            c: 0u64,
+            d: Vec::new(),
+            e: Default::default(),
+            f: HashMap::new(),
+            g: (0, String::new()),
+            h: None,
+            i: "",
+            j: 0.0,
+        }
+    }
+}
+
+struct TupleDefault(bool, i32, u64);
+
+impl std::default::Default for TupleDefault {
+    fn default() -> Self {
+        Self(false, 0, 0u64)
+    }
+}
+
+struct FooNoDefault {
+    a: bool,
+}
+
+impl std::default::Default for FooNoDefault {
+    fn default() -> Self {
+        Self { a: true }
+    }
+}
+
+struct Generic<T> {
+    a: Option<T>,
+}
+
+impl<T> Default for Generic<T> {
+    fn default() -> Self {
+        Self { a: None }
+    }
+}
+
+struct UserNew(i32);
+
+impl UserNew {
+    fn new() -> Self {
+        Self(1)
+    }
+}
+
+impl Default for UserNew {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct WithUserNew {
+    a: UserNew,
+}
+
+impl Default for WithUserNew {
+    fn default() -> Self {
+        Self { a: UserNew::new() }
+    }
+}
+
+struct Documented {
+    a: bool,
+}
+
+/// Creates a value with `a` set to `false`.
+impl Default for Documented {
+    fn default() -> Self {
+        Self { a: false }
+    }
+}
+
+fn main() {}
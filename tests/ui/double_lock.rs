@@ -0,0 +1,92 @@
+#![warn(clippy::double_lock)]
+
+use std::sync::{Mutex, RwLock};
+
+fn let_bound(m: &Mutex<Vec<u8>>) {
+    let guard = m.lock().unwrap();
+    let first = guard[0];
+    m.lock().unwrap().push(first);
+}
+
+fn let_bound_result(m: &Mutex<Vec<u8>>) {
+    let guard = m.lock();
+    let _len = m.lock().unwrap().len();
+}
+
+fn rwlock(rw: &RwLock<u8>) {
+    let read = rw.read().unwrap();
+    *rw.write().unwrap() += *read;
+}
+
+fn scrutinee(m: &Mutex<Option<u8>>, rw: &RwLock<Vec<u8>>) {
+    if let Some(x) = *m.lock().unwrap() {
+        *m.lock().unwrap() = Some(x + 1);
+    }
+    match m.lock().unwrap().is_some() {
+        true => {},
+        false => *m.lock().unwrap() = Some(0),
+    }
+    for x in rw.read().unwrap().iter() {
+        rw.write().unwrap().push(*x);
+    }
+}
+
+fn dropped(m: &Mutex<Vec<u8>>) {
+    let guard = m.lock().unwrap();
+    let first = guard[0];
+    drop(guard);
+    m.lock().unwrap().push(first);
+}
+
+fn moved(m: &Mutex<Vec<u8>>) {
+    let guard = m.lock().unwrap();
+    let other = guard;
+    m.lock().unwrap().push(other[0]);
+}
+
+fn temporary(m: &Mutex<Vec<u8>>) {
+    let first = m.lock().unwrap()[0];
+    m.lock().unwrap().push(first);
+    if m.lock().unwrap().is_empty() {
+        m.lock().unwrap().push(0);
+    }
+}
+
+fn different_locks(a: &Mutex<u8>, b: &Mutex<u8>) {
+    let guard = a.lock().unwrap();
+    *b.lock().unwrap() = *guard;
+}
+
+fn two_reads(rw: &RwLock<u8>) {
+    let first = rw.read().unwrap();
+    let _second = rw.read().unwrap();
+}
+
+struct Cache {
+    values: RwLock<Vec<u8>>,
+}
+
+impl Cache {
+    fn insert_missing(&self, x: u8) {
+        let values = self.values.read().unwrap();
+        if !values.contains(&x) {
+            self.values.write().unwrap().push(x);
+        }
+    }
+
+    fn insert_missing_after_drop(&self, x: u8) {
+        let values = self.values.read().unwrap();
+        let missing = !values.contains(&x);
+        drop(values);
+        if missing {
+            self.values.write().unwrap().push(x);
+        }
+    }
+}
+
+fn closure(m: &Mutex<u8>) {
+    let _guard = m.lock().unwrap();
+    let _ = || *m.lock().unwrap();
+}
+
+fn main() {}
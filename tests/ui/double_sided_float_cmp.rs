@@ -0,0 +1,35 @@
+// run-rustfix
+#![warn(clippy::double_sided_float_cmp)]
+#![allow(unused, clippy::many_single_char_names)]
+
+const TOLERANCE: f64 = 0.01;
+
+fn main() {
+    let a = 0.3f64;
+    let b = 0.1f64 + 0.2;
+    let x = 1.0f32;
+    let y = 2.0f32;
+
+    let _ = (a - b) < f64::EPSILON && (b - a) < f64::EPSILON;
+    let _ = (x - y) <= f32::EPSILON && (y - x) <= f32::EPSILON;
+    let _ = f64::EPSILON > (a - b) && (b - a) < f64::EPSILON;
+    let _ = (a - b) > TOLERANCE || (b - a) > TOLERANCE;
+    let _ = (a - b) >= TOLERANCE || TOLERANCE <= (b - a);
+    let _ = (a * 2.0 - b) < TOLERANCE && (b - a * 2.0) < TOLERANCE;
+
+    // mixed operators
+    let _ = (a - b) < f64::EPSILON && (b - a) <= f64::EPSILON;
+    // not equivalent to a comparison of the absolute difference
+    let _ = (a - b) < f64::EPSILON || (b - a) < f64::EPSILON;
+    let _ = (a - b) > f64::EPSILON && (b - a) > f64::EPSILON;
+    // different tolerances
+    let _ = (a - b) < f64::EPSILON && (b - a) < TOLERANCE;
+    // different operands
+    let _ = (a - b) < f64::EPSILON && (b - x as f64) < f64::EPSILON;
+    // integers
+    let i = 1;
+    let j = 2;
+    let _ = (i - j) < 1 && (j - i) < 1;
+    // already simplified
+    let _ = (a - b).abs() < f64::EPSILON;
+}
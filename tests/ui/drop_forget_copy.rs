@@ -1,5 +1,11 @@
 #![warn(clippy::drop_copy, clippy::forget_copy)]
-#![allow(clippy::toplevel_ref_arg, clippy::drop_ref, clippy::forget_ref, unused_mut)]
+#![allow(
+    clippy::toplevel_ref_arg,
+    clippy::drop_ref,
+    clippy::forget_ref,
+    clippy::forget_needs_drop,
+    unused_mut
+)]
 
 use std::mem::{drop, forget};
 use std::vec::Vec;
@@ -0,0 +1,31 @@
+#![warn(clippy::env_set_var)]
+
+use std::env;
+use std::thread;
+
+fn configure() {
+    env::set_var("RUST_LOG", "debug");
+    env::remove_var("RUST_BACKTRACE");
+}
+
+fn main() {
+    // ok, no thread is spawned yet
+    env::set_var("RUST_LOG", "info");
+    if env::var("RUST_BACKTRACE").is_err() {
+        env::remove_var("RUST_LIB_BACKTRACE");
+    }
+
+    for key in &["A", "B"] {
+        env::set_var(key, "1");
+    }
+    let set = || env::set_var("C", "1");
+    set();
+
+    let handle = thread::spawn(configure);
+    env::set_var("RUST_LOG", "warn");
+    handle.join().unwrap();
+
+    let handle = thread::Builder::new().spawn(configure).unwrap();
+    handle.join().unwrap();
+    env::remove_var("RUST_LOG")
+}
@@ -0,0 +1,47 @@
+// run-rustfix
+
+#![allow(unused_variables, dead_code)]
+#![warn(clippy::equatable_if_let)]
+
+#[derive(PartialEq)]
+enum Enum {
+    TupleVariant(i32, u64),
+    RecordVariant { a: i64, b: u32 },
+    UnitVariant,
+}
+
+#[derive(PartialEq)]
+struct Struct {
+    a: i32,
+    b: bool,
+}
+
+enum NotPartialEq {
+    A,
+    B,
+}
+
+fn main() {
+    let a = 2;
+    let b = 3;
+    let c = Some(2);
+    let d = Struct { a: 2, b: false };
+    let e = Enum::UnitVariant;
+    let f = NotPartialEq::A;
+
+    // true
+    if let 2 = a {}
+    if let Enum::TupleVariant(32, 64) = e {}
+    if let Enum::RecordVariant { a: 64, b: 32 } = e {}
+    if let Some(2) = c {}
+    if let Struct { a: 2, b: false } = d {}
+
+    // false
+    if let 2 | 3 = a {}
+    if let x @ 2 = a {}
+    if let Struct { a, b: false } = d {}
+    if let Struct { a: 2, .. } = d {}
+    if let Enum::TupleVariant(32, ..) = e {}
+    if let NotPartialEq::A = f {}
+    if let 2..=4 = b {}
+}
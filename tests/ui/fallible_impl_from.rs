@@ -1,4 +1,5 @@
 #![deny(clippy::fallible_impl_from)]
+#![allow(clippy::manual_assert)]
 
 // docs example
 struct Foo(i32);
@@ -1,4 +1,5 @@
 #![warn(clippy::all, clippy::pedantic)]
+#![allow(clippy::if_then_some_else_none)]
 
 fn main() {
     let a = ["1", "lol", "3", "NaN", "5"];
@@ -0,0 +1,15 @@
+// run-rustfix
+#![warn(clippy::flat_map_option)]
+#![allow(clippy::redundant_closure, clippy::unnecessary_filter_map)]
+
+fn main() {
+    let c = |x| Some(x);
+    let _ = [1].iter().flat_map(c);
+    let _ = [1].iter().flat_map(Some);
+    let _ = ["1"].iter().flat_map(|s| s.parse::<i32>().ok());
+
+    let _ = ["1"].iter().flat_map(|s| s.parse::<i32>());
+
+    // returns an iterator
+    let _ = [1].iter().flat_map(|x| vec![*x]);
+}
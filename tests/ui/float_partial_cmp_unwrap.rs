@@ -0,0 +1,20 @@
+#![warn(clippy::float_partial_cmp_unwrap)]
+
+use std::cmp::Ordering;
+
+fn main() {
+    let mut values = vec![1.0_f64, 0.5, 2.0];
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    values.sort_unstable_by(|a, b| b.partial_cmp(a).expect("NaN"));
+    let _ = values.iter().max_by(|a, b| a.partial_cmp(b).unwrap());
+    let _ = values.iter().copied().min_by(|a, b| { a.partial_cmp(b).unwrap() });
+    let _ = values.binary_search_by(|probe| probe.partial_cmp(&1.0).unwrap());
+    let mut singles = vec![1.0_f32];
+    singles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    // ok
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or_else(|| a.is_nan().cmp(&b.is_nan())));
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let mut ints = vec![1, 2];
+    ints.sort_by(|a, b| a.partial_cmp(b).unwrap());
+}
@@ -1,5 +1,6 @@
 // run-rustfix
 #![warn(clippy::suboptimal_flops)]
+#![allow(clippy::manual_struct_update)]
 
 struct A {
     a: f64,
@@ -0,0 +1,36 @@
+#![warn(clippy::forget_needs_drop)]
+
+use std::fs::File;
+use std::mem::{self, ManuallyDrop};
+use std::sync::Mutex;
+
+struct Droppable;
+
+impl Drop for Droppable {
+    fn drop(&mut self) {}
+}
+
+struct ContainsVec {
+    values: Vec<u8>,
+}
+
+fn forget_generic<T>(value: T) {
+    // no lint, `T` may not need to be dropped
+    mem::forget(value);
+}
+
+fn main() {
+    let mutex = Mutex::new(0);
+    mem::forget(mutex.lock().unwrap());
+    mem::forget(Droppable);
+    mem::forget(ContainsVec { values: vec![1] });
+    mem::forget((String::new(), 1));
+
+    if let Ok(file) = File::open("foo.txt") {
+        mem::forget(file);
+    }
+
+    // no lint
+    mem::forget(1);
+    mem::forget(ManuallyDrop::new(String::new()));
+}
@@ -0,0 +1,24 @@
+// run-rustfix
+
+#![warn(clippy::from_iter_instead_of_collect)]
+
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+fn main() {
+    let iter_expr = std::iter::repeat(5).take(5);
+    let _ = Vec::from_iter(iter_expr);
+
+    let _ = HashMap::<usize, &i8>::from_iter(vec![5, 5, 5, 5].iter().enumerate());
+
+    let _ = HashMap::from_iter(vec![(1, 'a'), (2, 'b')].into_iter());
+
+    let _ = String::from_iter("abc".chars().rev());
+
+    let _ = Vec::<u8>::from_iter(0..5);
+
+    let _: Vec<u8> = FromIterator::from_iter(0..5);
+
+    // don't lint, `collect` needs an iterator
+    let _ = Vec::from_iter(vec![42u32]);
+}
@@ -0,0 +1,59 @@
+#![feature(bool_to_option)]
+#![warn(clippy::if_then_some_else_none)]
+#![allow(dead_code)]
+
+fn is_ready() -> bool {
+    true
+}
+
+fn get_value() -> u32 {
+    42
+}
+
+fn main() {
+    let count = 3;
+    let flag = false;
+
+    // Should lint
+    let _ = if count > 0 { Some(42 / count) } else { None };
+    let _ = if is_ready() { Some(get_value()) } else { None };
+    let _ = if !flag { Some("ready") } else { None };
+    let _ = if is_ready() && count > 1 {
+        Some(get_value() + count as u32)
+    } else {
+        None
+    };
+
+    // Should not lint
+    let _ = if count > 0 { None } else { Some(count) };
+    let _ = if count > 0 { Some(count) } else { Some(0) };
+    let _ = if flag {
+        println!("flag is set");
+        Some(count)
+    } else {
+        None
+    };
+    let _ = if flag {
+        Some(0)
+    } else if count > 0 {
+        Some(count)
+    } else {
+        None
+    };
+    for i in 0..count {
+        let _ = if i > 1 {
+            Some(if i > 2 { break } else { i })
+        } else {
+            None
+        };
+    }
+    let _ = if flag { Some(vec![count]) } else { None };
+}
+
+const fn in_const(flag: bool) -> Option<u32> {
+    if flag {
+        Some(1)
+    } else {
+        None
+    }
+}
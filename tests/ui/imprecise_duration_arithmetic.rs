@@ -0,0 +1,27 @@
+#![warn(clippy::imprecise_duration_arithmetic)]
+
+use std::time::Duration;
+
+fn main() {
+    let dur = Duration::new(5, 300_000_000);
+    let bytes = 1024_u64;
+
+    // truncating conversions to floats
+    let _ = dur.as_secs() as f64;
+    let _ = dur.as_secs() as f32;
+    let _ = bytes as f64 / dur.as_secs() as f64;
+    let _ = (&dur).as_secs() as f64;
+    let _ = dur.as_millis() as f64;
+
+    // integer division by a truncated duration
+    let _ = bytes / dur.as_secs();
+    let _ = bytes as u128 / dur.as_millis();
+    let _ = 1000 / dur.as_millis() as u64;
+
+    // ok
+    let _ = dur.as_secs_f64();
+    let _ = bytes as f64 / dur.as_secs_f64();
+    let _ = dur.as_secs() as u128;
+    let _ = dur.as_secs() / 60;
+    let _ = dur.subsec_nanos() as f64;
+}
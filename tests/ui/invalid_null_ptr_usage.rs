@@ -0,0 +1,17 @@
+// run-rustfix
+#![warn(clippy::invalid_null_ptr_usage)]
+#![allow(clippy::zero_ptr)]
+
+fn main() {
+    unsafe {
+        let _slice: &[usize] = std::slice::from_raw_parts(std::ptr::null(), 0);
+        let _slice: &[usize] = std::slice::from_raw_parts(0 as *const _, 0);
+        let _slice: &mut [usize] = std::slice::from_raw_parts_mut(std::ptr::null_mut(), 0);
+
+        let _nonnull: std::ptr::NonNull<usize> = std::ptr::NonNull::new_unchecked(std::ptr::null_mut());
+
+        std::ptr::copy::<usize>(std::ptr::null(), std::ptr::NonNull::dangling().as_ptr(), 0);
+        std::ptr::copy::<usize>(std::ptr::NonNull::dangling().as_ptr(), std::ptr::null_mut(), 0);
+        std::ptr::write_bytes::<usize>(std::ptr::null_mut(), 42, 0);
+    }
+}
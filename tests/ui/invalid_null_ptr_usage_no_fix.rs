@@ -0,0 +1,21 @@
+#![warn(clippy::invalid_null_ptr_usage)]
+
+use std::ffi::CStr;
+use std::ptr;
+
+fn main() {
+    unsafe {
+        let _a: usize = ptr::read(ptr::null());
+        let _b: usize = ptr::read_unaligned(ptr::null());
+        let _c: usize = ptr::replace(ptr::null_mut(), 42);
+        ptr::write(ptr::null_mut::<usize>(), 42);
+        ptr::swap::<usize>(ptr::null_mut(), &mut 42);
+        let _d = CStr::from_ptr(ptr::null());
+        let _e: Box<usize> = Box::from_raw(ptr::null_mut());
+
+        // no lint: valid pointers
+        let mut x = 0_usize;
+        let _f: usize = ptr::read(&x);
+        ptr::write(&mut x, 42);
+    }
+}
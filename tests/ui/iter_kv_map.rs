@@ -0,0 +1,26 @@
+// run-rustfix
+
+#![warn(clippy::iter_kv_map)]
+#![allow(clippy::redundant_clone)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn main() {
+    let map: HashMap<u32, u32> = HashMap::new();
+
+    let _ = map.iter().map(|(key, _)| key).collect::<Vec<_>>();
+    let _ = map.iter().map(|(_, value)| value).collect::<Vec<_>>();
+    let _ = map.iter().map(|(_, v)| v + 2).collect::<Vec<_>>();
+
+    let btree_map: BTreeMap<u32, u32> = BTreeMap::new();
+
+    let _ = btree_map.iter().map(|(key, _)| key).collect::<Vec<_>>();
+
+    // don't lint, both the key and the value are used
+    let _ = map.iter().map(|(key, value)| key + value).collect::<Vec<_>>();
+    // don't lint, not a map
+    let _ = vec![(1, 2)].iter().map(|(key, _)| key).collect::<Vec<_>>();
+    // don't lint, `into_keys` and `into_values` aren't stable yet
+    let _ = map.clone().into_iter().map(|(key, _)| key).collect::<Vec<_>>();
+    let _ = btree_map.clone().into_iter().map(|(_, value)| value).collect::<Vec<_>>();
+}
@@ -0,0 +1,35 @@
+#![warn(clippy::iter_over_hash_type)]
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+fn main() {
+    let mut map: HashMap<&str, u32> = HashMap::new();
+    let mut set: HashSet<u32> = HashSet::new();
+    let floats: HashMap<&str, f64> = HashMap::new();
+
+    // loops
+    for _ in &map {}
+    for _ in &mut map {}
+    for _ in set.clone() {}
+    for _ in map.keys() {}
+
+    // iterator methods
+    let _ = set.iter().next();
+    let _: Vec<_> = map.values().collect();
+    let _: Vec<_> = set.iter().map(|x| x * 2).collect();
+    map.values_mut().for_each(|x| *x += 1);
+    let _: Vec<_> = map.drain().collect();
+    let _: f64 = floats.values().sum();
+
+    // ok
+    let _ = map.iter().count();
+    let _: u32 = map.values().sum();
+    let _: u32 = set.iter().product();
+    let _ = set.iter().copied().filter(|x| x % 2 == 0).any(|x| x > 10);
+    let _: HashSet<u32> = map.values().copied().collect();
+    let _: BTreeMap<_, _> = map.iter().collect();
+    let _: BTreeSet<_> = set.iter().collect();
+    let _ = map.len();
+    let _ = set.contains(&1);
+    for _ in &BTreeSet::<u32>::new() {}
+}
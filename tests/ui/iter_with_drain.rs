@@ -0,0 +1,59 @@
+// run-rustfix
+#![warn(clippy::iter_with_drain)]
+#![allow(unused_mut)]
+
+use std::collections::VecDeque;
+
+fn make_vec() -> Vec<u32> {
+    vec![1, 2, 3]
+}
+
+struct S {
+    v: Vec<u32>,
+}
+
+fn vec_param(mut v: Vec<u32>) -> u32 {
+    v.drain(..).sum()
+}
+
+fn vec_ref_param(v: &mut Vec<u32>) -> u32 {
+    // don't lint, the `Vec` is borrowed
+    v.drain(..).sum()
+}
+
+fn main() {
+    let mut v = vec![String::from("a"), String::from("b")];
+    let _: Vec<String> = v.drain(..).collect();
+
+    let mut deque: VecDeque<u32> = VecDeque::new();
+    for _ in deque.drain(..) {}
+
+    let _ = make_vec().drain(..).sum::<u32>();
+    let _ = vec_param(make_vec());
+
+    // don't lint, the `Vec` is used afterwards
+    let mut v = make_vec();
+    let _ = v.drain(..).sum::<u32>();
+    v.push(1);
+
+    // don't lint, only a part of the `Vec` is drained
+    let mut v = make_vec();
+    let _ = v.drain(1..).sum::<u32>();
+
+    // don't lint, fields can't be moved out of
+    let mut s = S { v: make_vec() };
+    let _ = s.v.drain(..).sum::<u32>();
+
+    // don't lint, the `Vec` is drained in every iteration of the loop
+    let mut v = Vec::new();
+    for i in 0..3 {
+        v.push(i);
+        let _ = v.drain(..).sum::<u32>();
+    }
+
+    // don't lint, the `Vec` is only cleared
+    let mut v = make_vec();
+    v.drain(..);
+
+    let _ = vec_ref_param(&mut make_vec());
+}
@@ -20,6 +20,9 @@ fn main() {
     // check const eval
     let v1 = vec![1, 2, 3];
     let _ = v1.iter().step_by(2 / 3);
+    const STEP: usize = 0;
+    let _ = v1.iter().step_by(STEP);
+    let _ = v1.iter().step_by(STEP * 2);
 }
 
 struct NotIterator;
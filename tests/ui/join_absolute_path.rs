@@ -0,0 +1,17 @@
+#![warn(clippy::join_absolute_path)]
+
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let base = Path::new("/home/user");
+    let _ = base.join("/etc/passwd");
+
+    let buf = PathBuf::from("/home/user");
+    let _ = buf.join("//srv");
+
+    // ok
+    let _ = base.join(".config");
+    let _ = buf.join("etc/passwd");
+    let _ = base.join(Path::new("/etc"));
+    let _ = base.join("notes:txt");
+}
@@ -0,0 +1,13 @@
+// ignore-windows
+
+#![warn(clippy::join_absolute_path)]
+
+use std::path::Path;
+
+fn main() {
+    let base = Path::new("/home/user");
+
+    // ok: `\` and drive prefixes are part of the file name outside of Windows
+    let _ = base.join("\\Windows");
+    let _ = base.join("C:\\Windows");
+}
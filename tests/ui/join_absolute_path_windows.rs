@@ -0,0 +1,17 @@
+// ignore-linux
+// ignore-macos
+
+#![warn(clippy::join_absolute_path)]
+
+use std::path::Path;
+
+fn main() {
+    let base = Path::new("C:\\Users\\user");
+    let _ = base.join("\\Windows");
+    let _ = base.join("C:\\Windows");
+    let _ = base.join("/Windows");
+
+    // ok
+    let _ = base.join("Documents");
+    let _ = base.join("notes:txt");
+}
@@ -0,0 +1,25 @@
+// edition:2018
+#![warn(clippy::let_underscore_future)]
+
+use std::future::Future;
+
+async fn some_async_fn() {}
+
+fn custom() -> impl Future<Output = ()> {
+    some_async_fn()
+}
+
+fn do_something_to_future(future: &mut impl Future<Output = ()>) {}
+
+fn main() {
+    let _ = some_async_fn();
+    let _ = custom();
+
+    let mut future = some_async_fn();
+    do_something_to_future(&mut future);
+    let _ = future;
+
+    // a reference to a future is not a future
+    let future = some_async_fn();
+    let _ = &future;
+}
@@ -0,0 +1,47 @@
+// run-rustfix
+
+#![warn(clippy::manual_assert)]
+#![allow(dead_code)]
+
+fn is_bad(n: u32) -> bool {
+    n > 3
+}
+
+fn tail(n: u32) {
+    if is_bad(n) {
+        panic!("n is too large: {}", n)
+    }
+}
+
+fn main() {
+    let a = vec![1, 2, 3];
+    let n = 2;
+    if !a.is_empty() {
+        panic!("qaqaq{:?}", a);
+    }
+    if a.is_empty() {
+        panic!("qwqwq");
+    }
+    if is_bad(n) {
+        panic!()
+    }
+    tail(n);
+
+    // has an `else` branch
+    if a.len() == 3 {
+        println!("qwq");
+    } else {
+        panic!("qaqaq");
+    }
+    // more than a `panic!`
+    if a.len() > 3 {
+        println!("qwq");
+        panic!("qaqaq");
+    }
+    // `else if` clause
+    if a.len() == 1 {
+        println!("qwq");
+    } else if a.len() == 2 {
+        panic!("qaqaq");
+    }
+}
@@ -1,7 +1,7 @@
 // run-rustfix
 // edition:2018
 #![warn(clippy::manual_async_fn)]
-#![allow(unused)]
+#![allow(unused, clippy::needless_lifetimes)]
 
 use std::future::Future;
 
@@ -33,6 +33,24 @@ async fn already_async() -> impl Future<Output = i32> {
     async { 42 }
 }
 
+fn elided(_: &i32) -> impl Future<Output = i32> + '_ {
+    async { 42 }
+}
+
+fn explicit<'a, 'b>(_: &'a i32, _: &'b i32) -> impl Future<Output = i32> + 'a + 'b {
+    async { 42 }
+}
+
+// should be ignored, the future doesn't capture the lifetime of the argument
+fn elided_not_bound(_: &i32) -> impl Future<Output = i32> {
+    async { 42 }
+}
+
+// should be ignored, the future only captures one of the lifetimes
+fn explicit_not_bound<'a, 'b>(_: &'a i32, _: &'b i32) -> impl Future<Output = i32> + 'a {
+    async { 42 }
+}
+
 struct S {}
 impl S {
     fn inh_fut() -> impl Future<Output = i32> {
@@ -51,14 +69,19 @@ impl S {
         }
     }
 
-    fn meth_fut(&self) -> impl Future<Output = i32> {
+    fn meth_fut(&self) -> impl Future<Output = i32> + '_ {
         async { 42 }
     }
 
-    fn empty_fut(&self) -> impl Future<Output = ()> {
+    fn empty_fut(&self) -> impl Future<Output = ()> + '_ {
         async {}
     }
 
+    // should be ignored, the future doesn't capture the lifetime of `self`
+    fn meth_fut_not_bound(&self) -> impl Future<Output = i32> {
+        async { 42 }
+    }
+
     // should be ignored
     fn not_fut(&self) -> i32 {
         42
@@ -0,0 +1,82 @@
+// run-rustfix
+
+#![feature(clamp, custom_inner_attributes)]
+#![warn(clippy::manual_clamp)]
+
+const LOWER: i32 = 0;
+const UPPER: i32 = 100;
+
+fn main() {
+    let input = 42i32;
+    let float = 0.5f64;
+    let (lo, hi) = (10i32, 20i32);
+    let min_float = 0.25f64;
+
+    let _ = if input < 0 {
+        0
+    } else if input > 100 {
+        100
+    } else {
+        input
+    };
+
+    let _ = if input >= UPPER {
+        UPPER
+    } else if LOWER > input {
+        LOWER
+    } else {
+        input
+    };
+
+    let _ = match input {
+        _ if input < lo => lo,
+        _ if input > hi => hi,
+        _ => input,
+    };
+
+    let _ = input.max(0).min(100);
+    let _ = input.min(hi).max(lo);
+    let _ = float.max(0.0).min(1.0);
+    let _ = if float < min_float {
+        min_float
+    } else if float > 1.0 {
+        1.0
+    } else {
+        float
+    };
+
+    // `clamp` would always panic
+    let _ = input.max(100).min(0);
+
+    // not clamp-like
+    let _ = if input < 0 {
+        0
+    } else if input > 100 {
+        99
+    } else {
+        input
+    };
+    let _ = if input < 0 {
+        0
+    } else if input < 100 {
+        100
+    } else {
+        input
+    };
+    let _ = input.max(0).max(100);
+    let _ = input.max(0);
+}
+
+fn msrv_1_49() {
+    #![clippy::msrv = "1.49"]
+
+    let input = 42i32;
+    let _ = input.max(0).min(100);
+}
+
+fn msrv_1_50() {
+    #![clippy::msrv = "1.50"]
+
+    let input = 42i32;
+    let _ = input.max(0).min(100);
+}
@@ -0,0 +1,39 @@
+// run-rustfix
+#![warn(clippy::manual_enumerate)]
+#![allow(dead_code, unused_variables)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    for (i, x) in (0..).zip(v.iter()) {
+        let _ = v[i] + x;
+    }
+
+    for (i, x) in (0..).zip(&v) {
+        let _ = v[i] + x;
+    }
+
+    for (x, i) in v.iter().zip(0..) {
+        let _ = v[i] + x;
+    }
+
+    let _: Vec<(usize, &i32)> = (0..).zip(v.iter()).collect();
+    let _: Vec<(&i32, usize)> = v.iter().zip(0..).collect();
+}
+
+fn should_not_lint() {
+    let v = vec![1, 2, 3];
+
+    // the index is not a `usize`
+    for (i, x) in (0u32..).zip(v.iter()) {}
+
+    // the range doesn't start at zero
+    for (i, x) in (1..).zip(v.iter()) {
+        let _ = v[i] + x;
+    }
+
+    // the range is bounded
+    for (i, x) in (0..3).zip(v.iter()) {
+        let _ = v[i] + x;
+    }
+}
@@ -0,0 +1,72 @@
+// run-rustfix
+#![warn(clippy::manual_filter)]
+#![allow(dead_code, clippy::if_then_some_else_none)]
+
+fn is_even(x: &u32) -> bool {
+    x % 2 == 0
+}
+
+fn main() {
+    let o: Option<u32> = Some(1);
+
+    let _ = match o {
+        Some(x) if x > 1 => Some(x),
+        _ => None,
+    };
+
+    let _ = match o {
+        Some(x) if is_even(&x) => Some(x),
+        _ => None,
+    };
+
+    let _ = o.and_then(|x| if x > 1 { Some(x) } else { None });
+
+    let _ = o.and_then(|x| if is_even(&x) { Some(x) } else { None });
+
+    #[rustfmt::skip]
+    let _ = o.and_then(|x| {
+        if x != 3 {
+            Some(x)
+        } else {
+            None
+        }
+    });
+}
+
+fn maybe_incorrect() {
+    let s: Option<String> = None;
+    let _ = match s {
+        Some(s) if s.len() > 3 => Some(s),
+        _ => None,
+    };
+}
+
+fn should_not_lint(o: Option<u32>) -> Option<u32> {
+    // no guard
+    let _ = match o {
+        Some(x) => Some(x),
+        _ => None,
+    };
+
+    // the value is transformed
+    let _ = match o {
+        Some(x) if x > 1 => Some(x + 1),
+        _ => None,
+    };
+    let _ = o.and_then(|x| if x > 1 { Some(x + 1) } else { None });
+
+    // the branches are swapped
+    let _ = o.and_then(|x| if x > 1 { None } else { Some(x) });
+
+    // control flow in the condition
+    let _ = o.and_then(|x| if x > 1 || (return None) { Some(x) } else { None });
+
+    // a reference
+    let r = &o;
+    let _ = match r {
+        Some(x) if *x > 1 => Some(x),
+        _ => None,
+    };
+
+    None
+}
@@ -0,0 +1,79 @@
+// run-rustfix
+#![warn(clippy::manual_flatten)]
+#![allow(dead_code)]
+
+fn consume<T>(_: T) {}
+
+fn main() {
+    let options = vec![Some(1), None, Some(3)];
+    for x in options {
+        if let Some(y) = x {
+            consume(y);
+        }
+    }
+
+    let results: Vec<Result<u32, ()>> = vec![Ok(1), Err(())];
+    for x in &results {
+        if let Ok(y) = x {
+            consume(y);
+        }
+    }
+
+    for x in "1 2 x".split(' ').map(str::parse::<u32>) {
+        if let Ok(n) = x {
+            consume(n);
+        } else {
+        }
+    }
+
+    let mut options = vec![Some(1), None];
+    for x in &mut options {
+        if let Some(y) = x {
+            *y += 1;
+            consume(y);
+        };
+    }
+}
+
+fn should_not_lint() {
+    let options = vec![Some(1), None, Some(3)];
+
+    // the `None` items are handled
+    for x in &options {
+        if let Some(y) = x {
+            consume(y);
+        } else {
+            consume(0);
+        }
+    }
+
+    // the loop variable is used
+    for x in &options {
+        if let Some(y) = x {
+            consume((x, y));
+        }
+    }
+
+    // a refutable inner pattern
+    for x in &options {
+        if let Some(1) = x {
+            consume(1);
+        }
+    }
+
+    // another statement
+    for x in &options {
+        consume(x);
+        if let Some(y) = x {
+            consume(y);
+        }
+    }
+
+    // a different value is matched
+    let other = Some(2);
+    for _x in &options {
+        if let Some(y) = other {
+            consume(y);
+        }
+    }
+}
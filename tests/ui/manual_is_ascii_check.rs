@@ -0,0 +1,51 @@
+// run-rustfix
+
+#![feature(custom_inner_attributes)]
+#![allow(unused, dead_code)]
+#![warn(clippy::manual_is_ascii_check)]
+
+fn main() {
+    let c = 'x';
+    let b = b'x';
+
+    let _ = c >= 'a' && c <= 'z';
+    let _ = 'A' <= c && 'Z' >= c;
+    let _ = ('0'..='9').contains(&c);
+    let _ = (b'a'..=b'z').contains(&b);
+    let _ = matches!(c, 'a'..='z' | 'A'..='Z');
+    let _ = matches!(b, b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z');
+    let _ = matches!(c.to_ascii_lowercase(), 'a'..='f' | '0'..='9' | 'A'..='F');
+
+    // Should not lint
+    let _ = c >= 'a' && c <= 'y';
+    let _ = ('a'..'z').contains(&c);
+    let _ = matches!(c, 'a'..='z' | '0'..='9');
+    let _ = matches!(c, 'a'..='z' if c != 'x');
+    let _ = c > 'a' && c < 'z';
+}
+
+fn msrv_1_23() {
+    #![clippy::msrv = "1.23"]
+
+    let c = 'x';
+    let _ = ('a'..='z').contains(&c);
+}
+
+fn msrv_1_24() {
+    #![clippy::msrv = "1.24"]
+
+    let c = 'x';
+    let _ = ('a'..='z').contains(&c);
+}
+
+const fn msrv_1_46_const(c: char) -> bool {
+    #![clippy::msrv = "1.46"]
+
+    matches!(c, '0'..='9')
+}
+
+const fn msrv_1_47_const(c: char) -> bool {
+    #![clippy::msrv = "1.47"]
+
+    matches!(c, '0'..='9')
+}
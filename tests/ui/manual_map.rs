@@ -0,0 +1,95 @@
+// run-rustfix
+
+#![warn(clippy::manual_map)]
+#![allow(clippy::unit_arg, dead_code)]
+
+fn add_one(x: i32) -> i32 {
+    x + 1
+}
+
+fn main() {
+    let _ = match Some(0) {
+        Some(x) => Some(x + 1),
+        None => None,
+    };
+
+    let _ = match Some(0) {
+        None => None,
+        Some(x) => Some(add_one(x)),
+    };
+
+    let _ = match Some(0) {
+        Some(x) => Some(add_one(x)),
+        _ => None,
+    };
+
+    let _ = if let Some(x) = Some(0) { Some(!x) } else { None };
+
+    let s = Some(String::new());
+    let _ = match &s {
+        Some(x) => Some(x.len()),
+        None => None,
+    };
+
+    let _ = match s {
+        Some(ref x) => Some(x.len()),
+        None => None,
+    };
+
+    let mut v = Some(vec![0]);
+    let _ = match v {
+        Some(ref mut x) => Some(x.push(1)),
+        None => None,
+    };
+
+    let _ = match Some((0, 1)) {
+        Some((x, y)) => Some(x + y),
+        None => None,
+    };
+
+    let _ = match Some(0) {
+        Some(x) => Some(if x > 0 { x } else { 0 }),
+        None => None,
+    };
+
+    // Should not lint
+    let _ = match Some(0) {
+        Some(x) => Some(x),
+        None => None,
+    };
+
+    let _ = match Some(0) {
+        Some(0) => Some(1),
+        _ => None,
+    };
+
+    let _ = match Some(Some(0)) {
+        Some(Some(x)) => Some(x),
+        _ => None,
+    };
+
+    let _ = match Some(0) {
+        Some(x) if x > 0 => Some(x + 1),
+        _ => None,
+    };
+
+    let _ = match Some(0) {
+        Some(x) => Some(vec![x]),
+        None => None,
+    };
+
+    let _ = if false {
+        None
+    } else if let Some(x) = Some(0) {
+        Some(x + 1)
+    } else {
+        None
+    };
+}
+
+fn early_return(o: Option<i32>) -> Option<i32> {
+    match o {
+        Some(x) => Some(if x > 0 { return None } else { x }),
+        None => None,
+    }
+}
@@ -0,0 +1,62 @@
+// run-rustfix
+#![warn(clippy::manual_ok_or)]
+#![allow(dead_code)]
+
+struct Error(u32);
+
+fn main() {
+    let o: Option<u32> = Some(1);
+
+    let _: Result<u32, &str> = match o {
+        Some(x) => Ok(x),
+        None => Err("none"),
+    };
+
+    let _: Result<u32, u32> = match o {
+        None => Err(0),
+        Some(x) => Ok(x),
+    };
+
+    let _: Result<u32, Error> = match o {
+        Some(x) => Ok(x),
+        _ => Err(Error(1)),
+    };
+
+    let _: Result<u32, &str> = if let Some(x) = o { Ok(x) } else { Err("none") };
+
+    let _: Result<u32, String> = match o {
+        Some(x) => Ok(x),
+        None => Err(String::from("none")),
+    };
+
+    let _: Result<u32, String> = if let Some(x) = o { Ok(x) } else { Err("none".to_owned()) };
+}
+
+fn should_not_lint(o: Option<u32>) -> Result<u32, &'static str> {
+    // guards
+    let _: Result<u32, &str> = match o {
+        Some(x) if x > 1 => Ok(x),
+        _ => Err("none"),
+    };
+
+    // the value is transformed
+    let _: Result<u32, &str> = match o {
+        Some(x) => Ok(x + 1),
+        None => Err("none"),
+    };
+
+    // the error is coerced
+    let string = String::from("none");
+    let _: Result<u32, &str> = match o {
+        Some(x) => Ok(x),
+        None => Err(&string),
+    };
+
+    // macros
+    let _: Result<u32, String> = match o {
+        Some(x) => Ok(x),
+        None => Err(format!("{:?}", o)),
+    };
+
+    Ok(0)
+}
@@ -0,0 +1,59 @@
+// run-rustfix
+#![feature(custom_inner_attributes)]
+#![warn(clippy::manual_retain)]
+#![allow(dead_code, unused_assignments, unused_variables)]
+
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque};
+
+fn main() {
+    let mut vec = vec![0, 1, 2];
+    vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+    vec = vec.iter().copied().filter(|x| x % 2 == 0).collect();
+
+    let mut strings = vec![String::new()];
+    strings = strings.iter().cloned().filter(|s| !s.is_empty()).collect();
+
+    let limit = 2;
+    let mut vec_deque: VecDeque<u32> = VecDeque::new();
+    vec_deque = vec_deque.into_iter().filter(move |x| *x < limit).collect();
+
+    let mut hash_set: HashSet<u32> = HashSet::new();
+    hash_set = hash_set.into_iter().filter(|x| x % 2 == 0).collect();
+
+    let mut hash_map: HashMap<u32, u32> = HashMap::new();
+    hash_map = hash_map.into_iter().filter(|&(k, v)| k != v).collect();
+    // the value is passed as `&mut V` to the predicate of `retain`
+    hash_map = hash_map.into_iter().filter(|(k, _)| *k > 2).collect();
+
+    let mut s = String::from("hello, world");
+    s = s.chars().filter(|&c| c != 'l').collect();
+
+    // don't lint, the result is assigned to a different collection
+    let mut other = vec![0];
+    other = vec.iter().copied().filter(|x| x % 2 == 0).collect();
+
+    // don't lint, `BinaryHeap::retain` is unstable
+    let mut heap: BinaryHeap<u32> = BinaryHeap::new();
+    heap = heap.into_iter().filter(|x| x % 2 == 0).collect();
+
+    // don't lint, `BTreeSet::retain` and `BTreeMap::retain` aren't stable yet
+    let mut btree_set: BTreeSet<u32> = BTreeSet::new();
+    btree_set = btree_set.iter().copied().filter(|x| x % 2 == 0).collect();
+    let mut btree_map: BTreeMap<u32, u32> = BTreeMap::new();
+    btree_map = btree_map.into_iter().filter(|&(k, _)| k % 2 == 0).collect();
+
+    // don't lint, `retain` doesn't support mapping the elements
+    vec = vec.into_iter().filter(|x| x % 2 == 0).map(|x| x + 1).collect();
+}
+
+fn msrv_1_52() {
+    #![clippy::msrv = "1.52"]
+    // `BTreeMap::retain` and `BTreeSet::retain` were stabilized in 1.53
+    let mut btree_set: BTreeSet<u32> = BTreeSet::new();
+    btree_set = btree_set.into_iter().filter(|x| x % 2 == 0).collect();
+    let mut btree_map: BTreeMap<u32, u32> = BTreeMap::new();
+    btree_map = btree_map.into_iter().filter(|&(k, _)| k % 2 == 0).collect();
+
+    let mut vec = vec![0, 1, 2];
+    vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+}
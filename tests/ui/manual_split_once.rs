@@ -0,0 +1,46 @@
+// run-rustfix
+#![feature(custom_inner_attributes, str_split_once)]
+#![warn(clippy::manual_split_once)]
+#![allow(dead_code)]
+
+fn parse(s: &str) -> Option<(&str, &str)> {
+    let mut iter = s.splitn(2, '=');
+    let key = iter.next()?;
+    let value = iter.next()?;
+    Some((key, value))
+}
+
+fn parse_unwrap(s: &str) -> (&str, &str) {
+    let mut iter = s.splitn(2, "::");
+    let key = iter.next().unwrap();
+    let value = iter.next().unwrap();
+    (key, value)
+}
+
+fn parse_reverse(s: &str) -> Option<(&str, &str)> {
+    let mut iter = s.rsplitn(2, '.');
+    let extension = iter.next()?;
+    let name = iter.next()?;
+    Some((name, extension))
+}
+
+fn main() {
+    let _ = "key=value".splitn(2, '=').nth(1);
+    let _ = "a.b.c".rsplitn(2, '.').nth(1);
+
+    // not two parts
+    let _ = "a.b.c".splitn(3, '.').nth(1);
+}
+
+fn iter_used_afterwards(s: &str) -> Option<(&str, &str)> {
+    let mut iter = s.splitn(2, '=');
+    let key = iter.next()?;
+    let value = iter.next()?;
+    assert!(iter.next().is_none());
+    Some((key, value))
+}
+
+fn msrv_1_51(s: &str) -> Option<&str> {
+    #![clippy::msrv = "1.51"]
+    s.splitn(2, '=').nth(1)
+}
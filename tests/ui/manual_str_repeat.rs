@@ -0,0 +1,31 @@
+// run-rustfix
+#![warn(clippy::manual_str_repeat)]
+#![allow(dead_code)]
+
+fn main() {
+    let s = "ab";
+    let c = 'x';
+    let n: usize = 3;
+
+    let _: String = (0..10).map(|_| "ab").collect();
+    let _: String = (0..n).map(|_| s).collect();
+    let _: String = (0..n).map(|_| 'x').collect();
+    let _ = (0..n).map(|_| c).collect::<String>();
+
+    let mut buf = String::new();
+    for _ in 0..n {
+        buf.push_str(s);
+    }
+    for _ in 0..n {
+        buf.push('"');
+    }
+
+    // the closure uses its argument
+    let _: String = (0..n).map(|i| if i == 0 { "a" } else { "b" }).collect();
+    // the repeated value may change
+    let _: String = (0..n).map(|_| s.trim()).collect();
+    // the range doesn't start at zero
+    for _ in 1..n {
+        buf.push_str(s);
+    }
+}
@@ -0,0 +1,23 @@
+// run-rustfix
+#![warn(clippy::manual_string_new)]
+#![allow(clippy::useless_format)]
+
+macro_rules! create_empty_string {
+    () => {
+        "".to_string()
+    };
+}
+
+fn main() {
+    let _ = "".to_string();
+    let _ = "".to_owned();
+    let _: String = String::from("");
+    let _ = format!("");
+
+    // Should not lint
+    let _ = "a".to_string();
+    let _ = String::from("a");
+    let _ = format!("{}", "");
+    let _ = String::new();
+    let _ = create_empty_string!();
+}
@@ -0,0 +1,71 @@
+#![feature(custom_inner_attributes)]
+#![warn(clippy::manual_strip)]
+#![allow(dead_code)]
+
+fn main() {
+    let s = "abc";
+
+    if s.starts_with("ab") {
+        str::to_string(&s["ab".len()..]);
+        s["ab".len()..].to_string();
+
+        str::to_string(&s[2..]);
+        s[2..].to_string();
+    }
+
+    if s.ends_with("bc") {
+        str::to_string(&s[..s.len() - "bc".len()]);
+        s[..s.len() - 2].to_string();
+    }
+
+    // Character patterns
+    if s.starts_with('a') {
+        str::to_string(&s[1..]);
+    }
+
+    // Variable prefix
+    let prefix = "ab";
+    if s.starts_with(prefix) {
+        str::to_string(&s[prefix.len()..]);
+    }
+
+    // Truncating a `String`
+    let mut string = String::from("abc");
+    if string.ends_with("bc") {
+        string.truncate(string.len() - 2);
+    }
+
+    // Should not lint: the length doesn't match the pattern
+    if s.starts_with("ab") {
+        str::to_string(&s[1..]);
+    }
+
+    // Should not lint: a different string is sliced
+    let t = "abc";
+    if s.starts_with("ab") {
+        str::to_string(&t[2..]);
+    }
+
+    // Should not lint: the target isn't a path
+    if s.to_string().starts_with("ab") {
+        str::to_string(&s[2..]);
+    }
+}
+
+fn msrv_1_44() {
+    #![clippy::msrv = "1.44"]
+
+    let s = "abc";
+    if s.starts_with("ab") {
+        str::to_string(&s[2..]);
+    }
+}
+
+fn msrv_1_45() {
+    #![clippy::msrv = "1.45"]
+
+    let s = "abc";
+    if s.starts_with("ab") {
+        str::to_string(&s[2..]);
+    }
+}
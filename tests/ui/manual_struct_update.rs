@@ -0,0 +1,83 @@
+#![warn(clippy::manual_struct_update)]
+#![allow(dead_code)]
+
+#[derive(Clone)]
+struct Foo {
+    a: i32,
+    b: i32,
+    c: String,
+}
+
+struct Wrapper {
+    foo: Foo,
+}
+
+fn make() -> Foo {
+    Foo {
+        a: 1,
+        b: 2,
+        c: String::new(),
+    }
+}
+
+fn by_value(base: Foo) -> Foo {
+    Foo {
+        a: base.a,
+        b: 1,
+        c: base.c,
+    }
+}
+
+fn by_ref(base: &Foo) -> Foo {
+    Foo {
+        a: base.a,
+        b: base.b,
+        c: String::new(),
+    }
+}
+
+fn cloned(base: &Foo) -> Foo {
+    Foo {
+        a: 1,
+        b: base.b,
+        c: base.c.clone(),
+    }
+}
+
+fn nested(w: Wrapper) -> Foo {
+    Foo {
+        a: 1,
+        b: w.foo.b,
+        c: w.foo.c,
+    }
+}
+
+fn main() {
+    let base = make();
+    let other = make();
+
+    // all fields are copied
+    let _ = Foo {
+        a: base.a,
+        b: base.b,
+        c: base.c.clone(),
+    };
+    // fields are copied from different instances
+    let _ = Foo {
+        a: base.a,
+        b: other.b,
+        c: String::new(),
+    };
+    // the base has side effects
+    let _ = Foo {
+        a: make().a,
+        b: make().b,
+        c: String::new(),
+    };
+    // already uses the struct update syntax
+    let _ = Foo {
+        c: String::new(),
+        ..base
+    };
+    drop(base);
+}
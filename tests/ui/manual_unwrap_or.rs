@@ -0,0 +1,111 @@
+// run-rustfix
+#![warn(clippy::manual_unwrap_or)]
+#![allow(dead_code, unused_variables)]
+
+fn fallback() -> u32 {
+    42
+}
+
+fn option() {
+    let o: Option<u32> = Some(1);
+
+    let _ = match o {
+        Some(x) => x,
+        None => 0,
+    };
+
+    let _ = match o {
+        None => 0,
+        Some(x) => x,
+    };
+
+    let _ = match o {
+        Some(x) => x,
+        _ => u32::MAX,
+    };
+
+    let _ = if let Some(x) = o { x } else { 1 };
+
+    let _ = match o {
+        Some(x) => x,
+        None => fallback() + 1,
+    };
+
+    let v: Option<Vec<u32>> = None;
+    let _ = match v {
+        Some(v) => v,
+        None => Vec::new(),
+    };
+
+    let s: Option<String> = None;
+    let _ = if let Some(s) = s { s } else { Default::default() };
+}
+
+fn result() {
+    let r: Result<u32, &str> = Ok(1);
+
+    let _ = match r {
+        Ok(x) => x,
+        Err(_) => 0,
+    };
+
+    let _ = if let Ok(x) = r { x } else { fallback() * 2 };
+
+    let _ = match r {
+        Ok(x) => x,
+        _ => u32::default(),
+    };
+}
+
+fn should_not_lint(o: Option<u32>, r: Result<u32, u32>) -> u32 {
+    // guards
+    let _ = match o {
+        Some(x) if x > 1 => x,
+        _ => 0,
+    };
+
+    // the error is used
+    let _ = match r {
+        Ok(x) => x,
+        Err(e) => e,
+    };
+
+    // control flow in the fallback
+    let _ = match o {
+        Some(x) => x,
+        None => return 0,
+    };
+
+    // the value is transformed
+    let _ = match o {
+        Some(x) => x + 1,
+        None => 0,
+    };
+
+    // the fallback is coerced
+    let s: Option<&str> = None;
+    let string = String::new();
+    let _: &str = match s {
+        Some(s) => s,
+        None => &string,
+    };
+
+    // `else if`
+    let _ = if o.is_none() {
+        0
+    } else if let Some(x) = o {
+        x
+    } else {
+        1
+    };
+
+    // macros
+    let _ = match o {
+        Some(x) => x,
+        None => unreachable!(),
+    };
+
+    0
+}
+
+fn main() {}
@@ -16,6 +16,7 @@
     clippy::useless_format,
     clippy::wrong_self_convention,
     clippy::unused_self,
+    clippy::unused_async,
     unused
 )]
 
@@ -0,0 +1,9 @@
+#![feature(bool_to_option, clamp, custom_inner_attributes)]
+#![clippy::msrv = "1.49"]
+#![warn(clippy::manual_clamp, clippy::if_then_some_else_none)]
+
+fn main() {
+    let input = 42i32;
+    let _ = input.max(0).min(100);
+    let _ = if input > 0 { Some(input) } else { None };
+}
@@ -0,0 +1,70 @@
+#![warn(clippy::misnamed_getters)]
+#![allow(dead_code)]
+
+struct Config {
+    timeout: u64,
+    retries: u64,
+    name: String,
+    label: String,
+    count: u32,
+}
+
+impl Config {
+    fn timeout(&self) -> u64 {
+        self.retries
+    }
+
+    fn name(&self) -> &String {
+        &self.label
+    }
+
+    fn name_mut(&mut self) -> &mut String {
+        &mut self.label
+    }
+
+    // correct getters
+    fn retries(&self) -> u64 {
+        self.retries
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn label_mut(&mut self) -> &mut String {
+        &mut self.label
+    }
+
+    // no field with the name of the method
+    fn attempts(&self) -> u64 {
+        self.retries
+    }
+
+    // the field with the name of the method has a different type
+    fn count(&self) -> u64 {
+        self.timeout
+    }
+}
+
+struct Wrapper<T> {
+    first: T,
+    second: T,
+}
+
+impl<T> Wrapper<T> {
+    fn first(&self) -> &T {
+        &self.second
+    }
+}
+
+trait Timeout {
+    fn timeout(&self) -> u64;
+}
+
+impl Timeout for Config {
+    fn timeout(&self) -> u64 {
+        self.retries
+    }
+}
+
+fn main() {}
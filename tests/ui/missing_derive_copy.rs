@@ -0,0 +1,68 @@
+#![warn(clippy::missing_derive_copy)]
+#![allow(dead_code)]
+
+#[derive(Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Clone, Debug)]
+enum Direction {
+    Up,
+    Down(u8),
+}
+
+// has a field which isn't `Copy`
+#[derive(Clone)]
+struct Named {
+    name: String,
+}
+
+// never cloned
+#[derive(Clone)]
+struct NeverCloned(u8);
+
+// exported types aren't linted by default
+#[derive(Clone)]
+pub struct Exported(u8);
+
+// `T` might not be `Copy`
+#[derive(Clone)]
+struct Generic<T>(T);
+
+// implements `Drop`, so it can't be `Copy`
+#[derive(Clone)]
+struct Dropped(u8);
+
+impl Drop for Dropped {
+    fn drop(&mut self) {}
+}
+
+fn take<T>(_: T) {}
+
+fn main() {
+    let point = Point { x: 1, y: 2 };
+    take(point.clone());
+    take(point);
+
+    let direction = Direction::Down(1);
+    take(direction.clone());
+    take(direction);
+
+    let named = Named { name: String::new() };
+    take(named.clone());
+    take(named);
+
+    let exported = Exported(1);
+    take(exported.clone());
+    take(exported);
+
+    let generic = Generic(1);
+    take(generic.clone());
+    take(generic);
+
+    let dropped = Dropped(1);
+    take(dropped.clone());
+    take(dropped);
+}
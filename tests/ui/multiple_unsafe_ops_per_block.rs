@@ -0,0 +1,70 @@
+#![warn(clippy::multiple_unsafe_ops_per_block)]
+
+static mut COUNTER: u32 = 0;
+
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+unsafe fn unsafe_fn() {}
+
+struct Foo;
+
+impl Foo {
+    unsafe fn unsafe_method(&self) {}
+}
+
+fn pointers(ptr: *const u32, ptr_mut: *mut u32) {
+    unsafe {
+        *ptr_mut = *ptr;
+    }
+}
+
+fn calls(foo: &Foo, fn_ptr: unsafe fn()) {
+    unsafe {
+        unsafe_fn();
+        foo.unsafe_method();
+        fn_ptr();
+    }
+}
+
+fn statics_and_unions(bits: Bits) -> u32 {
+    unsafe {
+        COUNTER += 1;
+        bits.int + COUNTER
+    }
+}
+
+fn in_closure() {
+    unsafe {
+        [1, 2].iter().for_each(|_| unsafe_fn());
+        unsafe_fn();
+    }
+}
+
+// no lint
+fn single_ops(ptr: *const u32, mut bits: Bits) {
+    let _ = unsafe { *ptr };
+    unsafe { unsafe_fn() };
+    // writing to a union field is safe
+    unsafe {
+        bits.float = 1.0;
+        let _ = bits.int;
+    }
+    // nested blocks are checked on their own
+    unsafe {
+        unsafe_fn();
+        unsafe {
+            unsafe_fn();
+        }
+    }
+    // safe calls
+    unsafe {
+        let _ = std::ptr::null::<u32>();
+        let _ = ptr.is_null();
+        unsafe_fn();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+#![allow(unused)]
+#![warn(clippy::needless_late_init)]
+
+fn get() -> u32 {
+    4
+}
+
+fn main() {
+    let a;
+    a = "zero";
+
+    let b: u32;
+    b = get();
+
+    let mut c;
+    if get() > 3 {
+        c = 1;
+    } else {
+        c = 2;
+    }
+    c += 1;
+
+    let d;
+    match get() {
+        1 => d = "one",
+        _ => d = "other",
+    }
+
+    let e;
+    if let Some(n) = Some(get()) {
+        e = n;
+    } else {
+        return;
+    }
+
+    // not all branches assign
+    let f;
+    if get() > 3 {
+        f = 1;
+    }
+
+    // the assignment isn't at the end of the branch
+    let g: u32;
+    if get() > 3 {
+        g = 1;
+        get();
+    } else {
+        g = 2;
+    }
+
+    // assigned in a loop
+    let h;
+    loop {
+        h = 1;
+        break;
+    }
+
+    // not directly followed by the assignment
+    let i;
+    get();
+    i = 1;
+}
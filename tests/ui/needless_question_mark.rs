@@ -0,0 +1,47 @@
+// run-rustfix
+#![warn(clippy::needless_question_mark)]
+#![allow(clippy::needless_return, dead_code)]
+
+struct TO {
+    magic: Option<usize>,
+}
+
+struct TR {
+    magic: Result<usize, bool>,
+}
+
+fn simple_option_bad1(to: TO) -> Option<usize> {
+    // return as a statement
+    return Some(to.magic?);
+}
+
+fn simple_option_bad2(to: TO) -> Option<usize> {
+    // as a block tail
+    Some(to.magic?)
+}
+
+fn simple_option_bad3(to: TO) -> Option<usize> {
+    // as a closure body
+    let f = |to: TO| Some(to.magic?);
+    f(to)
+}
+
+fn simple_result_bad1(tr: TR) -> Result<usize, bool> {
+    return Ok(tr.magic?);
+}
+
+fn simple_result_bad2(tr: TR) -> Result<usize, bool> {
+    Ok(tr.magic?)
+}
+
+fn simple_option_good(to: TO) -> Option<usize> {
+    // the value is changed
+    Some(to.magic? + 1)
+}
+
+fn result_conversion_good(tr: Result<usize, u8>) -> Result<usize, u32> {
+    // the question mark converts the error type
+    Ok(tr?)
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+// ignore-windows
+// run-rustfix
+#![warn(clippy::non_octal_unix_permissions)]
+#![allow(clippy::zero_prefixed_literal)]
+
+use std::fs::{DirBuilder, File, OpenOptions, Permissions};
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+
+fn main() {
+    let _ = OpenOptions::new().mode(644);
+    let _ = OpenOptions::new().mode(0o644);
+
+    let mut builder = DirBuilder::new();
+    builder.mode(0755);
+    builder.mode(0o755);
+
+    let _ = Permissions::from_mode(1777);
+    let _ = Permissions::from_mode(0o1777);
+
+    if let Ok(file) = File::open("foo") {
+        if let Ok(metadata) = file.metadata() {
+            let mut permissions = metadata.permissions();
+            permissions.set_mode(600);
+            permissions.set_mode(0o600);
+            // not a permission mode
+            permissions.set_mode(1289);
+            permissions.set_mode(0x1ff);
+            permissions.set_mode(0);
+        }
+    }
+}
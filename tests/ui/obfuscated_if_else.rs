@@ -0,0 +1,23 @@
+#![warn(clippy::obfuscated_if_else)]
+#![allow(clippy::unnecessary_lazy_evaluations)]
+
+fn compute() -> u32 {
+    42
+}
+
+fn main() {
+    let x = 3;
+    let flag = true;
+
+    let _ = flag.then(|| "a").unwrap_or("b");
+    let _ = (x > 2).then(|| compute() + 2).unwrap_or(5);
+    let _ = flag.then(|| {
+        let y = compute();
+        y + 1
+    }).unwrap_or(x);
+
+    // `unwrap_or_else` is lazy
+    let _ = flag.then(|| "a").unwrap_or_else(|| "b");
+    // not `bool::then`
+    let _ = Some(1).map(|v| v + 1).unwrap_or(0);
+}
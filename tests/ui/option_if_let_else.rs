@@ -80,6 +80,35 @@ fn negative_tests(arg: Option<u32>) -> u32 {
     7
 }
 
+// don't lint, `map_or` shouldn't be used for its side effects only
+fn side_effects(arg: Option<u32>) {
+    if let Some(x) = arg {
+        println!("{}", x);
+    } else {
+        println!("none");
+    }
+}
+
+// don't lint, both closures would borrow `v` mutably
+fn borrow_conflict(arg: Option<u32>, v: &mut Vec<u32>) -> usize {
+    if let Some(x) = arg {
+        v.push(x);
+        v.len()
+    } else {
+        v.clear();
+        0
+    }
+}
+
+// don't lint, `arg` would be moved into `map_or_else` before the closures are called
+fn moved_option(arg: Option<String>) -> usize {
+    if let Some(s) = arg {
+        s.len()
+    } else {
+        std::mem::size_of_val(&arg)
+    }
+}
+
 fn main() {
     let optional = Some(5);
     let _ = if let Some(x) = optional { x + 2 } else { 5 };
@@ -89,4 +118,7 @@ fn main() {
     let _ = longer_body(None);
     test_map_or_else(None);
     let _ = negative_tests(None);
+    side_effects(None);
+    let _ = borrow_conflict(None, &mut Vec::new());
+    let _ = moved_option(None);
 }
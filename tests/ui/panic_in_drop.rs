@@ -0,0 +1,60 @@
+#![warn(clippy::panic_in_drop)]
+
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+struct Unwrap(Mutex<Vec<u8>>);
+
+impl Drop for Unwrap {
+    fn drop(&mut self) {
+        self.0.lock().unwrap().clear();
+    }
+}
+
+struct Index(Vec<u8>, usize);
+
+impl Drop for Index {
+    fn drop(&mut self) {
+        let last = self.0[self.1];
+        let _ = self.0.iter().position(|&x| x == last).expect("not found");
+    }
+}
+
+struct Assertion(RefCell<u8>);
+
+impl Drop for Assertion {
+    fn drop(&mut self) {
+        assert_eq!(*self.0.borrow(), 0, "not reset");
+    }
+}
+
+struct Assertions(Vec<u8>);
+
+impl Drop for Assertions {
+    fn drop(&mut self) {
+        assert!(self.0.is_empty());
+        assert_eq!(self.0.len(), 0);
+    }
+}
+
+// no lint
+struct Logged(Mutex<Vec<u8>>);
+
+impl Drop for Logged {
+    fn drop(&mut self) {
+        match self.0.lock() {
+            Ok(mut guard) => guard.clear(),
+            Err(e) => eprintln!("poisoned: {}", e),
+        }
+    }
+}
+
+struct NotDrop(Vec<u8>);
+
+impl NotDrop {
+    fn drop(&mut self) {
+        self.0[0] = 0;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,52 @@
+#![warn(clippy::panic_in_extern_fn)]
+
+use std::panic::catch_unwind;
+use std::process::abort;
+
+extern "C" fn unwrap(digit: u32) -> u32 {
+    std::char::from_digit(digit, 10).unwrap() as u32
+}
+
+extern "C" fn expect_and_index(index: usize) -> u8 {
+    let index = index.checked_sub(1).expect("index out of range");
+    [1, 2, 3][index]
+}
+
+extern "C" fn explicit_panic(value: u8) -> u8 {
+    match value {
+        0 => panic!("zero"),
+        _ => value,
+    }
+}
+
+extern "system" fn assertion(value: u8) {
+    assert!(value > 0, "zero");
+}
+
+extern "C" fn in_closure(digit: u32) -> u32 {
+    let to_char = |digit| std::char::from_digit(digit, 10).unwrap();
+    to_char(digit) as u32
+}
+
+// no lint
+extern "C" fn caught(digit: u32) -> u32 {
+    catch_unwind(|| std::char::from_digit(digit, 10).unwrap() as u32).unwrap_or_else(|_| abort())
+}
+
+extern "C" fn infallible(digit: u32) -> u32 {
+    std::char::from_digit(digit, 10).map_or(0, |c| c as u32)
+}
+
+fn rust_abi(digit: u32) -> u32 {
+    std::char::from_digit(digit, 10).unwrap() as u32
+}
+
+struct Foo([u8; 3]);
+
+impl Foo {
+    extern "C" fn method(&self, index: usize) -> u8 {
+        self.0[index]
+    }
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-#![allow(unused, clippy::many_single_char_names, clippy::redundant_clone)]
+#![allow(unused, clippy::many_single_char_names, clippy::redundant_clone, clippy::manual_assert)]
 #![warn(clippy::ptr_arg)]
 
 use std::borrow::Cow;
@@ -0,0 +1,39 @@
+// run-rustfix
+
+#[warn(clippy::manual_range_contains)]
+#[allow(unused)]
+#[allow(clippy::no_effect)]
+#[allow(clippy::short_circuit_statement)]
+#[allow(clippy::unnecessary_operation)]
+fn main() {
+    let x = 9_u32;
+
+    // order shouldn't matter
+    x >= 8 && x < 12;
+    x < 12 && x >= 8;
+    12 > x && 8 <= x;
+
+    x >= 21 && x <= 42;
+    x <= 42 && x >= 21;
+    21 <= x && x <= 42;
+
+    // also with inverted bounds
+    x < 8 || x >= 12;
+    x > 42 || x < 21;
+
+    // not a range.contains
+    x > 8 && x < 12; // lower bound not inclusive
+    x < 8 && x <= 12; // same direction
+    x >= 12 && 12 >= x; // same bounds
+    x < 8 && x > 12; // wrong direction
+
+    x <= 8 || x >= 12;
+    x >= 8 || x >= 12;
+    x < 12 || 12 < x;
+    x >= 8 || x <= 12;
+
+    // floats
+    let y = 3.;
+    y >= 0. && y < 1.;
+    y < 0. || y > 1.;
+}
@@ -0,0 +1,48 @@
+#![warn(clippy::read_into_new_vec)]
+
+use std::io::{self, Read};
+
+fn read(mut reader: impl Read) -> io::Result<usize> {
+    let mut buf: Vec<u8> = Vec::with_capacity(1024);
+    reader.read_exact(&mut buf)?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    let n = reader.read(&mut buf)?;
+
+    let mut buf: Vec<u8> = Vec::with_capacity(1024);
+    reader.read_exact(&mut buf[..16])?;
+
+    // no lint: the vector is resized first
+    let mut buf: Vec<u8> = Vec::with_capacity(1024);
+    buf.resize(1024, 0);
+    reader.read_exact(&mut buf)?;
+
+    // no lint: `read_to_end` appends to the vector
+    let mut buf: Vec<u8> = Vec::new();
+    reader.read_to_end(&mut buf)?;
+
+    // no lint: the vector is created with a length
+    let mut buf = vec![0u8; 1024];
+    reader.read_exact(&mut buf)?;
+
+    // no lint: the vector is resized later in the loop, before reading into it in the next iteration
+    let mut buf: Vec<u8> = Vec::new();
+    for i in 0..4 {
+        if i > 0 {
+            reader.read_exact(&mut buf)?;
+        }
+        buf.resize(16, 0);
+    }
+
+    // reading into a new vector in a loop should be detected
+    let mut buf: Vec<u8> = Vec::with_capacity(1024);
+    for _ in 0..4 {
+        reader.read_exact(&mut buf)?;
+    }
+
+    Ok(n)
+}
+
+fn main() {
+    let _ = read(io::empty());
+}
@@ -0,0 +1,85 @@
+#![warn(clippy::recursive_format_impl)]
+#![allow(unconditional_recursion, clippy::allocation_in_format_impl)]
+
+use std::fmt;
+
+struct ToStr;
+
+impl fmt::Display for ToStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+struct FormatSelf(u8);
+
+impl fmt::Display for FormatSelf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.0, self)
+    }
+}
+
+struct FormatDerefSelf;
+
+impl fmt::Debug for FormatDerefSelf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", &*self)
+    }
+}
+
+struct CallFmt;
+
+impl fmt::Display for CallFmt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+struct MethodFmt;
+
+impl fmt::Debug for MethodFmt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt(f)
+    }
+}
+
+// no lint
+struct Field(u8);
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.to_string())
+    }
+}
+
+struct OtherTrait;
+
+impl fmt::Display for OtherTrait {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl fmt::Debug for OtherTrait {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "display: {}", self.to_string())
+    }
+}
+
+struct Wrapper(Box<u8>);
+
+impl std::ops::Deref for Wrapper {
+    type Target = u8;
+
+    fn deref(&self) -> &u8 {
+        &self.0
+    }
+}
+
+impl fmt::Display for Wrapper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,93 @@
+#![warn(clippy::redundant_else)]
+#![allow(clippy::needless_return, clippy::try_err)]
+
+fn foo() -> bool {
+    true
+}
+
+fn bar() {}
+
+fn lint() -> Result<u32, ()> {
+    loop {
+        // break
+        if foo() {
+            println!("Love your neighbor;");
+            break;
+        } else {
+            bar();
+        }
+        // continue
+        if foo() {
+            println!("He that lies down with Dogs,");
+            continue;
+        } else {
+            bar();
+        }
+        // else if
+        if foo() {
+            return Ok(1);
+        } else if foo() {
+            return Ok(2);
+        } else {
+            bar();
+        }
+        // `Err(..)?`
+        if foo() {
+            Err(())?;
+        } else {
+            bar();
+        }
+        // all arms of a nested `match` diverge
+        if foo() {
+            match foo() {
+                true => return Ok(3),
+                false => break,
+            }
+        } else {
+            bar();
+        }
+        // a trailing expression followed by other statements
+        if foo() {
+            return Err(());
+        } else {
+            bar()
+        }
+        bar();
+    }
+    Ok(0)
+}
+
+fn no_lint() {
+    // `else if` without `else`
+    if foo() {
+        return;
+    } else if foo() {
+        bar();
+    }
+    // not every branch diverges
+    if foo() {
+        return;
+    } else if foo() {
+        bar();
+    } else {
+        bar();
+        bar();
+    }
+    // the `if` doesn't diverge
+    if foo() {
+        bar();
+    } else {
+        return;
+    }
+    // not a statement
+    let _ = if foo() {
+        return;
+    } else {
+        1
+    };
+}
+
+fn main() {
+    let _ = lint();
+    no_lint();
+}
@@ -98,6 +98,7 @@ fn main() {
 
     issue5504();
     issue5697();
+    matches_macro();
 
     let _ = if let Some(_) = gen_opt() {
         1
@@ -186,4 +187,23 @@ const fn issue5697() {
         Some(_) => false,
         None => true,
     };
+
+    let _ = matches!(Some(42), Some(_));
+}
+
+fn matches_macro() {
+    let opt = Some(42);
+    let res: Result<i32, i32> = Ok(42);
+
+    let _ = matches!(opt, Some(_));
+    let _ = matches!(opt, None);
+    let _ = matches!(res, Ok(_));
+    let _ = matches!(&res, Err(_));
+    let _ = !matches!(opt, None);
+    let _ = !matches!(res, Err(_));
+
+    // not linted
+    let _ = matches!(opt, Some(42));
+    let _ = matches!(opt, Some(x) if x > 0);
+    let _ = matches!(res, Ok(_) | Err(_));
 }
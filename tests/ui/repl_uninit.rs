@@ -1,5 +1,5 @@
-#![allow(deprecated, invalid_value)]
 #![warn(clippy::all)]
+#![allow(deprecated, invalid_value, clippy::forget_needs_drop)]
 
 use std::mem;
 
@@ -0,0 +1,36 @@
+#![warn(clippy::self_assignment)]
+
+pub struct S {
+    a: i32,
+    b: [i32; 3],
+}
+
+fn index() -> usize {
+    0
+}
+
+macro_rules! assign_to_itself {
+    ($x:expr) => {
+        $x = $x
+    };
+}
+
+pub fn positives(mut a: i32, s: &mut S, v: &mut Vec<i32>, mut t: (i32, i32)) -> i32 {
+    a = a;
+    s.a = s.a;
+    s.b[1] = s.b[1];
+    v[0] = v[0];
+    t.0 = t.0;
+    a + t.0
+}
+
+pub fn negatives(mut a: i32, s: &mut S, v: &mut Vec<i32>) -> i32 {
+    a = -a;
+    s.a = s.b[0];
+    s.b[0] = s.b[1];
+    v[index()] = v[index()];
+    assign_to_itself!(a);
+    a
+}
+
+fn main() {}
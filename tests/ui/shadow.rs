@@ -9,7 +9,8 @@
     unused_parens,
     unused_variables,
     clippy::missing_docs_in_private_items,
-    clippy::single_match
+    clippy::single_match,
+    clippy::needless_late_init
 )]
 
 fn id<T>(x: T) -> T {
@@ -0,0 +1,18 @@
+#![warn(clippy::signed_rem_as_unsigned)]
+
+fn main() {
+    let values = [1, 2, 3];
+    let i: isize = -1;
+    let len = values.len() as isize;
+    let _ = values[(i % len) as usize];
+    let _ = (i % 3) as u32;
+    let j: i32 = -7;
+    let _ = (-j % 4) as u8;
+
+    // no lint
+    let _ = (5 % len) as usize;
+    let _ = (values.len() % 2) as u32;
+    let _ = (i % len) as i64;
+    let _ = (i % len + 1) as usize;
+    let _ = i.rem_euclid(len) as usize;
+}
@@ -0,0 +1,20 @@
+// run-rustfix
+#![warn(clippy::single_char_push_str)]
+
+fn main() {
+    let mut string = String::new();
+    string.push_str("R");
+    string.push_str("'");
+
+    string.push('u');
+    string.push_str("st");
+    string.push_str("");
+    string.push_str(r##"l"##);
+
+    string.push_str("\n");
+    string.push_str("\"");
+    string.push_str(r"\");
+
+    let string_ref = &mut string;
+    string_ref.push_str("!");
+}
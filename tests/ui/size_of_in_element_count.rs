@@ -0,0 +1,32 @@
+#![warn(clippy::size_of_in_element_count)]
+
+use std::mem::{size_of, size_of_val};
+use std::ptr::{copy, copy_nonoverlapping, write_bytes};
+use std::slice::{from_raw_parts, from_raw_parts_mut};
+
+fn main() {
+    const SIZE: usize = 128;
+    const HALF_SIZE: usize = SIZE / 2;
+    let x = [2u8; SIZE];
+    let mut y = [2u8; SIZE];
+
+    // lint
+    unsafe { copy_nonoverlapping::<u8>(x.as_ptr(), y.as_mut_ptr(), size_of::<u8>()) };
+    unsafe { copy_nonoverlapping(x.as_ptr(), y.as_mut_ptr(), size_of_val(&x[0])) };
+    unsafe { copy(x.as_ptr(), y.as_mut_ptr(), size_of::<u8>() * SIZE) };
+    unsafe { write_bytes(y.as_mut_ptr(), 0u8, size_of::<u8>() * SIZE) };
+    let _ = unsafe { from_raw_parts(y.as_ptr(), size_of_val(&y)) };
+    let _ = unsafe { from_raw_parts_mut(y.as_mut_ptr(), SIZE * size_of::<u8>()) };
+    unsafe { y.as_mut_ptr().copy_from(x.as_ptr(), size_of::<u8>() * HALF_SIZE) };
+    let _ = unsafe { y.as_ptr().add(size_of::<u8>()) };
+    unsafe { copy(x.as_ptr(), y.as_mut_ptr(), HALF_SIZE * size_of_val(&x[0]) * 2) };
+
+    // no lint: counts of elements
+    unsafe { copy_nonoverlapping(x.as_ptr(), y.as_mut_ptr(), SIZE) };
+    let _ = unsafe { from_raw_parts(y.as_ptr(), size_of_val(&y) / size_of::<u8>()) };
+    let _ = unsafe { y.as_ptr().add(HALF_SIZE) };
+
+    // no lint: the size of a different type
+    let z = [0u32; 4];
+    let _ = unsafe { from_raw_parts(z.as_ptr() as *const u8, size_of_val(&z)) };
+}
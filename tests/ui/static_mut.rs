@@ -0,0 +1,17 @@
+#![warn(clippy::static_mut)]
+
+static mut COUNTER: usize = 0;
+static mut FLAG: bool = false;
+static mut OFFSET: i64 = 0;
+static mut PTR: *mut u8 = std::ptr::null_mut();
+static mut NAMES: Vec<String> = Vec::new();
+static mut WIDE: u128 = 0;
+
+// no lint
+static IMMUTABLE: usize = 0;
+
+extern "C" {
+    static mut FOREIGN: u32;
+}
+
+fn main() {}
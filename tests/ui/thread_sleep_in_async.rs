@@ -0,0 +1,28 @@
+// edition:2018
+#![warn(clippy::thread_sleep_in_async)]
+
+use std::thread;
+use std::time::Duration;
+
+async fn bad() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+async fn bad_full_path() {
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+async fn closure() {
+    // the closure may be called from outside of the async context
+    let _ = || thread::sleep(Duration::from_millis(10));
+}
+
+fn not_async() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+fn main() {
+    let _fut = async {
+        thread::sleep(Duration::from_millis(10));
+    };
+}
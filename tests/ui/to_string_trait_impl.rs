@@ -0,0 +1,37 @@
+#![warn(clippy::to_string_trait_impl)]
+
+use std::fmt::{self, Display};
+
+struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl ToString for Point {
+    fn to_string(&self) -> String {
+        format!("({}, {})", self.x, self.y)
+    }
+}
+
+struct Wrapper<T>(T);
+
+impl<T: ToString> ToString for Wrapper<T> {
+    fn to_string(&self) -> String {
+        let inner = self.0.to_string();
+        inner.to_uppercase()
+    }
+}
+
+struct Foo;
+
+impl Display for Foo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Foo")
+    }
+}
+
+fn main() {
+    let _ = Point { x: 1, y: 2 }.to_string();
+    let _ = Wrapper(1).to_string();
+    let _ = Foo.to_string();
+}
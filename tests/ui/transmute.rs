@@ -39,14 +39,6 @@ fn useless() {
         let _: Vec<i32> = std::mem::transmute(my_vec());
 
         let _: Vec<i32> = my_transmute(my_vec());
-
-        let _: *const usize = std::mem::transmute(5_isize);
-
-        let _ = 5_isize as *const usize;
-
-        let _: *const usize = std::mem::transmute(1 + 1usize);
-
-        let _ = (1 + 1_usize) as *const usize;
     }
 }
 
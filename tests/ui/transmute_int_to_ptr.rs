@@ -0,0 +1,18 @@
+#![warn(clippy::transmute_int_to_ptr, clippy::transmute_ptr_to_int)]
+
+fn int_to_ptr(addr: usize) {
+    let _: *const u8 = unsafe { std::mem::transmute(addr) };
+    let _: *mut u32 = unsafe { std::mem::transmute(addr) };
+    let _: *const i64 = unsafe { std::mem::transmute(addr as isize) };
+    let _: *const [u8; 4] = unsafe { std::mem::transmute(0x1000_usize) };
+    let _: *const usize = unsafe { std::mem::transmute(5_isize) };
+    let _: *const usize = unsafe { std::mem::transmute(1 + 1usize) };
+}
+
+fn ptr_to_int(ptr: *const u8, ptr_mut: *mut u32) {
+    let _: usize = unsafe { std::mem::transmute(ptr) };
+    let _: isize = unsafe { std::mem::transmute(ptr_mut) };
+    let _: usize = unsafe { std::mem::transmute(&1_u8 as *const u8) };
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#![warn(clippy::type_name_comparison)]
+
+use std::any::{type_name, TypeId};
+
+fn is_string<T>() -> bool {
+    type_name::<T>() == type_name::<String>()
+}
+
+fn is_not_vec<T>() -> bool {
+    std::any::type_name::<T>() != std::any::type_name::<Vec<u8>>()
+}
+
+fn is_i32<T>() -> bool {
+    type_name::<T>() == "i32"
+}
+
+fn same_type<T: 'static, U: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<U>()
+}
+
+fn main() {
+    let _ = is_string::<u8>();
+    let _ = is_not_vec::<u8>();
+    let _ = is_i32::<u8>();
+    let _ = same_type::<u8, u16>();
+    println!("{}", type_name::<u8>());
+    let _ = type_name::<u8>().len() == 2;
+}
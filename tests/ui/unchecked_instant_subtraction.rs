@@ -0,0 +1,20 @@
+#![warn(clippy::unchecked_instant_subtraction)]
+
+use std::time::{Duration, Instant};
+
+fn main() {
+    let start = Instant::now();
+    let end = Instant::now();
+
+    // lint
+    let _ = end - start;
+    let _ = end.duration_since(start);
+    let _ = end.checked_add(Duration::from_secs(1)).unwrap() - start;
+
+    // ok
+    let _ = Instant::now() - start;
+    let _ = Instant::now().duration_since(start);
+    let _ = start.elapsed();
+    let _ = end.saturating_duration_since(start);
+    let _ = end - Duration::from_secs(1);
+}
@@ -0,0 +1,24 @@
+#![warn(clippy::unchecked_integer_abs)]
+
+fn main() {
+    let a: i32 = -5;
+    let b: i64 = -5;
+    let small: i16 = -5;
+    let unsigned: u32 = 5;
+
+    // lint
+    let _ = a.abs();
+    let _ = b.abs();
+    let _ = (a - 1).abs();
+    let _ = (unsigned as i32).abs();
+    let _ = i32::MIN.abs();
+
+    // ok
+    let _ = (-5_i32).abs();
+    let _ = i32::MAX.abs();
+    let _ = (small as i32).abs();
+    let _ = (a as i64).abs();
+    let _ = a.checked_abs();
+    let _ = a.wrapping_abs();
+    let _ = (-5.0_f64).abs();
+}
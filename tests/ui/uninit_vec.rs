@@ -0,0 +1,64 @@
+#![warn(clippy::uninit_vec)]
+
+use std::mem::MaybeUninit;
+
+fn main() {
+    // with_capacity() -> set_len() should be detected
+    let mut vec: Vec<u8> = Vec::with_capacity(1000);
+    unsafe {
+        vec.set_len(200);
+    }
+
+    // new() -> set_len() should be detected
+    let mut vec: Vec<u8> = Vec::new();
+    unsafe {
+        vec.set_len(200);
+    }
+
+    // the length may be computed from the vector
+    let mut vec: Vec<u8> = Vec::with_capacity(1000);
+    unsafe {
+        vec.set_len(vec.capacity());
+    }
+
+    // no lint: the elements are written before set_len()
+    let mut vec: Vec<u8> = Vec::with_capacity(1000);
+    unsafe {
+        vec.as_mut_ptr().write_bytes(0, 200);
+        vec.set_len(200);
+    }
+
+    // no lint: the vector is used in between
+    let mut vec: Vec<u8> = Vec::with_capacity(1000);
+    vec.extend_from_slice(&[1, 2, 3]);
+    unsafe {
+        vec.set_len(3);
+    }
+
+    // no lint: MaybeUninit elements don't need to be initialized
+    let mut vec: Vec<MaybeUninit<u8>> = Vec::with_capacity(1000);
+    unsafe {
+        vec.set_len(200);
+    }
+
+    // no lint: setting the length to zero is fine
+    let mut vec: Vec<u8> = Vec::new();
+    unsafe {
+        vec.set_len(0);
+    }
+
+    // no lint: the vector is pushed to later in the loop, before set_len() in the next iteration
+    let mut vec: Vec<u8> = Vec::with_capacity(1000);
+    for i in 0..10 {
+        if i > 0 {
+            unsafe { vec.set_len(i - 1) };
+        }
+        vec.push(0);
+    }
+
+    // with_capacity() -> set_len() in a loop should be detected
+    let mut vec: Vec<u8> = Vec::with_capacity(1000);
+    for i in 1..10 {
+        unsafe { vec.set_len(i) };
+    }
+}
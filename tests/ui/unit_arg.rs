@@ -1,5 +1,5 @@
 #![warn(clippy::unit_arg)]
-#![allow(clippy::no_effect, unused_must_use, unused_variables)]
+#![allow(clippy::no_effect, clippy::needless_question_mark, unused_must_use, unused_variables)]
 
 use std::fmt::Debug;
 
@@ -1,3 +1,5 @@
+#![allow(clippy::if_then_some_else_none)]
+
 fn main() {
     let _ = (0..4).filter_map(|x| if x > 1 { Some(x) } else { None });
     let _ = (0..4).filter_map(|x| {
@@ -0,0 +1,39 @@
+// edition:2018
+#![warn(clippy::unused_async)]
+
+async fn foo() -> i32 {
+    4
+}
+
+async fn bar() -> i32 {
+    foo().await
+}
+
+async fn nested_block() -> i32 {
+    let fut = async { 4 };
+    fut.await
+}
+
+struct S;
+
+impl S {
+    async fn unused(&self) -> i32 {
+        1
+    }
+
+    async fn used(&self) -> i32 {
+        self.unused().await
+    }
+}
+
+fn not_async() -> i32 {
+    4
+}
+
+fn main() {
+    foo();
+    bar();
+    nested_block();
+    S.unused();
+    S.used();
+}
@@ -0,0 +1,27 @@
+// run-rustfix
+#![warn(clippy::unused_enumerate_index)]
+#![allow(unused_variables)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    for (_, x) in v.iter().enumerate() {
+        println!("{}", x);
+    }
+
+    for (i, x) in v.iter().enumerate() {
+        println!("{}", x);
+    }
+
+    let _: Vec<i32> = v.iter().enumerate().map(|(_, x)| x * 2).collect();
+    v.iter().enumerate().for_each(|(_i, x)| println!("{}", x));
+
+    // the index is used
+    for (i, x) in v.iter().enumerate() {
+        println!("{}: {}", i, x);
+    }
+    let _: Vec<usize> = v.iter().enumerate().map(|(i, x)| i * 2).collect();
+
+    // the tuples are still yielded
+    let _: Vec<(usize, &i32)> = v.iter().enumerate().filter(|(_, x)| **x > 1).collect();
+}
@@ -22,4 +22,14 @@ fn vectored<T: io::Read + io::Write>(s: &mut T) -> io::Result<()> {
     Ok(())
 }
 
+fn let_wild<T: io::Read + io::Write>(s: &mut T) -> io::Result<usize> {
+    let _ = s.write(b"test")?;
+    let mut buf = [0u8; 4];
+    let _ = s.read(&mut buf).unwrap();
+    let _ = s.write(b"test");
+    // no lint: the amount is bound
+    let n = s.read(&mut buf)?;
+    Ok(n)
+}
+
 fn main() {}
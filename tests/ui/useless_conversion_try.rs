@@ -1,4 +1,5 @@
 #![deny(clippy::useless_conversion)]
+#![allow(clippy::manual_unwrap_or)]
 
 use std::convert::{TryFrom, TryInto};
 
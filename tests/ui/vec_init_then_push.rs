@@ -0,0 +1,35 @@
+#![allow(unused_variables)]
+#![warn(clippy::vec_init_then_push)]
+
+fn main() {
+    let mut def_err: Vec<u32> = Default::default();
+
+    let mut new_err = Vec::<u32>::new();
+    new_err.push(1);
+    new_err.push(2);
+
+    let mut cap_err = Vec::with_capacity(2);
+    cap_err.push(0);
+    cap_err.push(1);
+
+    let mut typed: Vec<u64> = Vec::new();
+    typed.push(1);
+
+    // the `Vec` is mutated afterwards
+    let mut new_mut = Vec::new();
+    new_mut.push(1);
+    new_mut.push(2);
+    new_mut.extend(vec![3, 4]);
+
+    // the capacity may be needed
+    let mut cap_ok = Vec::with_capacity(10);
+    cap_ok.push(0);
+    cap_ok.extend(vec![1, 2]);
+
+    // the pushed value depends on the `Vec`
+    let mut len_ok = Vec::new();
+    len_ok.push(len_ok.len());
+
+    new_err = Vec::new();
+    new_err.push(0);
+}
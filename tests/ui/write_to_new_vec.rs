@@ -0,0 +1,47 @@
+#![warn(clippy::write_to_new_vec)]
+#![allow(clippy::needless_range_loop)]
+
+fn main() {
+    let n = 10;
+    let src = [1, 2, 3];
+
+    let mut vec = Vec::with_capacity(n);
+    for i in 0..n {
+        vec[i] = i;
+    }
+
+    let mut vec: Vec<u8> = Vec::new();
+    vec[0] += 1;
+
+    let mut vec = Vec::with_capacity(n);
+    vec.copy_from_slice(&src);
+
+    let mut vec = Vec::with_capacity(n);
+    vec[..3].copy_from_slice(&src);
+
+    // no lint: the vector is resized first
+    let mut vec = Vec::with_capacity(n);
+    vec.resize(n, 0);
+    for i in 0..n {
+        vec[i] = i;
+    }
+
+    // no lint: the vector is used in a closure first
+    let mut vec = Vec::with_capacity(n);
+    let mut fill = || vec.extend_from_slice(&src);
+    fill();
+    vec[0] = 0;
+
+    // no lint: the vector is created with a length
+    let mut vec = vec![0; n];
+    vec[0] = 1;
+
+    // no lint: the vector is pushed to later in the loop, before the write in the next iteration
+    let mut vec = Vec::with_capacity(n);
+    for i in 0..n {
+        if i > 0 {
+            vec[i - 1] += 1;
+        }
+        vec.push(i);
+    }
+}
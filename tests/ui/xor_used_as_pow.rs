@@ -0,0 +1,21 @@
+#![warn(clippy::xor_used_as_pow)]
+
+fn main() {
+    // lint
+    let _ = 2 ^ 10;
+    let _ = 10 ^ 3;
+    let _ = 2_u8 ^ 7;
+
+    // no lint: the power doesn't fit into the type
+    let _ = 2_i8 ^ 7;
+    let _ = 2 ^ 40;
+
+    // no lint: not written in decimal
+    let _ = 0x2 ^ 10;
+    let _ = 2 ^ 0b1010;
+
+    // no lint: other operands
+    let _ = 3 ^ 2;
+    let x = 2;
+    let _ = x ^ 10;
+}